@@ -23,6 +23,12 @@ where
     type BindCollector: BindCollector<Self>;
     type RawValue: ?Sized;
     type ByteOrder: ByteOrder;
+
+    /// SQL which is rendered in place of the `LIMIT` clause of a query which
+    /// has an `OFFSET` but no `LIMIT`. Backends which don't accept `OFFSET`
+    /// on its own use a `LIMIT` which doesn't restrict the number of rows.
+    #[doc(hidden)]
+    const LIMIT_FOR_OFFSET_WITHOUT_LIMIT: &'static str = "";
 }
 
 pub trait TypeMetadata {
@@ -32,4 +38,6 @@ pub trait TypeMetadata {
 
 pub trait SupportsReturningClause {}
 pub trait SupportsDefaultKeyword {}
+/// Indicates that a backend supports `INSERT ... ON CONFLICT`
+pub trait SupportsOnConflictClause {}
 pub trait UsesAnsiSavepointSyntax {}
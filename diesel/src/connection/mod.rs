@@ -1,16 +1,61 @@
+mod prepare;
 mod statement_cache;
 mod transaction_manager;
 
+use std::iter::FromIterator;
+
 use backend::Backend;
 use query_builder::{AsQuery, QueryFragment, QueryId};
 use query_source::Queryable;
 use result::*;
 use types::HasSqlType;
 
+pub use self::prepare::{AsPrepared, PrepareQuery};
 pub use self::transaction_manager::{AnsiTransactionManager, TransactionManager};
 #[doc(hidden)]
 pub use self::statement_cache::{MaybeCached, StatementCache, StatementCacheKey};
 
+/// Attaches the SQL of `query` to a deserialization error returned while
+/// loading it. See `DeserializationErrorWithSql` for details.
+#[cfg(all(feature = "sql-in-deserialization-errors", debug_assertions))]
+pub(crate) fn attach_sql_to_deserialization_error<T, Q, DB>(
+    result: QueryResult<T>,
+    query: &Q,
+) -> QueryResult<T>
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    Q: QueryFragment<DB> + ?Sized,
+{
+    use query_builder::QueryBuilder;
+
+    result.map_err(|e| match e {
+        Error::DeserializationError(error) => {
+            let mut query_builder = DB::QueryBuilder::default();
+            if query.to_sql(&mut query_builder).is_err() {
+                return Error::DeserializationError(error);
+            }
+            let error = DeserializationErrorWithSql::new(query_builder.finish(), error);
+            Error::DeserializationError(Box::new(error))
+        }
+        e => e,
+    })
+}
+
+#[cfg(not(all(feature = "sql-in-deserialization-errors", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn attach_sql_to_deserialization_error<T, Q, DB>(
+    result: QueryResult<T>,
+    _query: &Q,
+) -> QueryResult<T>
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    Q: QueryFragment<DB> + ?Sized,
+{
+    result
+}
+
 /// Perform simple operations on a backend.
 pub trait SimpleConnection {
     /// Execute multiple SQL statements within the same string.
@@ -134,6 +179,83 @@ pub trait Connection: SimpleConnection + Sized + Send {
         user_result.expect("Transaction did not succeed")
     }
 
+    /// Runs the given query, discarding the rows it returns, and returns the
+    /// number of rows which were returned.
+    ///
+    /// The rows are never deserialized, so the query can be run without
+    /// picking a Rust type to load it into, and no Rust values are allocated
+    /// for each row. This is useful for queries which are only run for their
+    /// side effects, such as warming up a cache or checking that the database
+    /// is reachable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let row_count = connection.execute_query(users.filter(name.ne("Sean")));
+    /// assert_eq!(Ok(1), row_count);
+    /// # }
+    /// ```
+    fn execute_query<T>(&self, source: T) -> QueryResult<usize>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+    {
+        // This relies on the affected row count of a `SELECT` being the
+        // number of rows it returned. Connections for which that isn't the
+        // case need to override this.
+        self.execute_returning_count(&source.as_query())
+    }
+
+    /// Prepares each of the given queries on this connection without
+    /// executing them.
+    ///
+    /// The prepared statements are stored in the connection's statement
+    /// cache, so the first real execution of each query doesn't have to pay
+    /// the cost of preparing it. This is most useful right after a
+    /// connection is established, such as when a connection pool grows.
+    ///
+    /// Queries are boxed with [`as_prepared`](trait.AsPrepared.html) so
+    /// that queries of different types can be passed together. Queries
+    /// which can't be cached, such as those containing raw SQL, are prepared
+    /// and immediately discarded. Bind parameters are not sent, so the
+    /// values used when warming up don't matter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let all_names = users.select(name);
+    /// let by_id = users.select(name).filter(id.eq(0));
+    /// connection.warmup(&[all_names.as_prepared(), by_id.as_prepared()]).unwrap();
+    ///
+    /// assert_eq!(Ok("Tess".to_string()), users.select(name).filter(id.eq(2)).first(&connection));
+    /// # }
+    /// ```
+    fn warmup<'a>(&self, queries: &[Box<PrepareQuery<Self> + 'a>]) -> QueryResult<()> {
+        for query in queries {
+            query.prepare(self)?;
+        }
+        Ok(())
+    }
+
     #[doc(hidden)]
     fn execute(&self, query: &str) -> QueryResult<usize>;
 
@@ -145,11 +267,38 @@ pub trait Connection: SimpleConnection + Sized + Send {
         Self::Backend: HasSqlType<T::SqlType>,
         U: Queryable<T::SqlType, Self::Backend>;
 
+    // Connections should override this to call `f` on each row as it is
+    // deserialized, rather than loading every row first.
+    #[doc(hidden)]
+    fn query_by_index_map<T, U, F, R, C>(&self, source: T, f: F) -> QueryResult<C>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        Self::Backend: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Self::Backend>,
+        F: FnMut(U) -> R,
+        C: FromIterator<R>,
+    {
+        self.query_by_index(source)
+            .map(|rows: Vec<U>| rows.into_iter().map(f).collect())
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
         T: QueryFragment<Self::Backend> + QueryId;
 
+    // Preparing statements ahead of time is only an optimization, so
+    // connections which don't cache prepared statements can rely on this
+    // default, which does nothing.
+    #[doc(hidden)]
+    fn prepare_statement<T>(&self, _source: &T) -> QueryResult<()>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        Ok(())
+    }
+
     #[doc(hidden)]
     fn silence_notices<F: FnOnce() -> T, T>(&self, f: F) -> T;
     #[doc(hidden)]
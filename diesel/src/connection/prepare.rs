@@ -0,0 +1,39 @@
+use query_builder::{QueryFragment, QueryId};
+use result::QueryResult;
+use super::Connection;
+
+/// A query which can be prepared on a connection ahead of time, without
+/// being executed.
+///
+/// This is implemented for every complete query of the connection's backend.
+/// See [`Connection::warmup`](trait.Connection.html#method.warmup) for
+/// details.
+pub trait PrepareQuery<Conn> {
+    /// Prepares this query on `conn`, storing the prepared statement in the
+    /// connection's statement cache.
+    fn prepare(&self, conn: &Conn) -> QueryResult<()>;
+}
+
+impl<T, Conn> PrepareQuery<Conn> for T
+where
+    Conn: Connection,
+    T: QueryFragment<Conn::Backend> + QueryId,
+{
+    fn prepare(&self, conn: &Conn) -> QueryResult<()> {
+        conn.prepare_statement(self)
+    }
+}
+
+/// Boxes a query so that queries of different types can be passed to
+/// [`Connection::warmup`](trait.Connection.html#method.warmup) together.
+pub trait AsPrepared: Sized {
+    /// Boxes this query as a [`PrepareQuery`](trait.PrepareQuery.html).
+    fn as_prepared<'a, Conn>(self) -> Box<PrepareQuery<Conn> + 'a>
+    where
+        Self: PrepareQuery<Conn> + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+impl<T: QueryId> AsPrepared for T {}
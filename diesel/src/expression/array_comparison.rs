@@ -220,7 +220,12 @@ pub struct Subselect<T, ST> {
     _sql_type: PhantomData<ST>,
 }
 
-impl<T: Expression, ST> Expression for Subselect<T, ST> {
+// `SelectQuery` rather than `Query`, so that correlated subqueries (which
+// aren't valid queries on their own) can be used as well.
+impl<T, ST> Expression for Subselect<T, ST>
+where
+    T: Expression + SelectQuery<SqlType = ST>,
+{
     type SqlType = ST;
 }
 
@@ -0,0 +1,277 @@
+use backend::Backend;
+use expression::{AsExpression, Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types::{NotNull, Nullable};
+
+/// Creates a SQL `COALESCE` expression, which evaluates to `expr` unless it
+/// is `NULL`, in which case it evaluates to `default`.
+///
+/// `expr` must be nullable, and `default` must be of the same type but not
+/// nullable, so the result of this expression is never `NULL`. The default
+/// can be a column, any other expression, or a Rust value. To fall back to a
+/// default which is nullable as well, use
+/// [`coalesce_nullable`](fn.coalesce_nullable.html).
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     animals {
+/// #         id -> Integer,
+/// #         species -> VarChar,
+/// #         legs -> Integer,
+/// #         name -> Nullable<VarChar>,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::animals::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::dsl::coalesce;
+///
+/// let names = animals
+///     .select(coalesce(name, species))
+///     .order(id)
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Jack".to_string(), "spider".to_string()]), names);
+///
+/// let unnamed = animals
+///     .select(species)
+///     .filter(coalesce(name, "").eq(""))
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["spider".to_string()]), unnamed);
+/// # }
+/// ```
+pub fn coalesce<ST, T, U>(expr: T, default: U) -> Coalesce<T, U::Expression>
+where
+    ST: NotNull,
+    T: Expression<SqlType = Nullable<ST>>,
+    U: AsExpression<ST>,
+{
+    Coalesce {
+        expr: expr,
+        default: default.as_expression(),
+    }
+}
+
+/// Creates a SQL `COALESCE` expression with a nullable default, which
+/// evaluates to `expr` unless it is `NULL`, in which case it evaluates to
+/// `default`.
+///
+/// Both arguments must be nullable and of the same type, and the result is
+/// nullable as well. Use [`coalesce`](fn.coalesce.html) if the default is
+/// never `NULL`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     animals {
+/// #         id -> Integer,
+/// #         species -> VarChar,
+/// #         legs -> Integer,
+/// #         name -> Nullable<VarChar>,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::animals::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::dsl::coalesce_nullable;
+///
+/// let names = animals
+///     .select(coalesce_nullable(name, None::<String>))
+///     .order(id)
+///     .load::<Option<String>>(&connection);
+/// assert_eq!(Ok(vec![Some("Jack".to_string()), None]), names);
+/// # }
+/// ```
+pub fn coalesce_nullable<ST, T, U>(expr: T, default: U) -> Coalesce<T, U::Expression>
+where
+    ST: NotNull,
+    T: Expression<SqlType = Nullable<ST>>,
+    U: AsExpression<Nullable<ST>>,
+{
+    Coalesce {
+        expr: expr,
+        default: default.as_expression(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Coalesce<T, U> {
+    expr: T,
+    default: U,
+}
+
+impl<T, U> Expression for Coalesce<T, U>
+where
+    T: Expression,
+    U: Expression,
+{
+    type SqlType = U::SqlType;
+}
+
+impl<T, U> NonAggregate for Coalesce<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+    Coalesce<T, U>: Expression,
+{
+}
+
+impl<T, U, DB> QueryFragment<DB> for Coalesce<T, U>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("COALESCE(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.default.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(Coalesce<T, U>);
+impl_selectable_expression!(Coalesce<T, U>);
+
+/// Creates a SQL `COALESCE` expression with any number of arguments, which
+/// evaluates to the first of them that isn't `NULL`.
+///
+/// The arguments are passed as a tuple of up to 8 expressions. They must all
+/// have the same SQL type, but each of them may or may not be nullable. The
+/// result is only nullable if every argument is nullable, so adding a
+/// non-nullable default anywhere in the list makes the result non-nullable.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     animals {
+/// #         id -> Integer,
+/// #         species -> VarChar,
+/// #         legs -> Integer,
+/// #         name -> Nullable<VarChar>,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::animals::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::dsl::coalesce_many;
+///
+/// let names = animals
+///     .select(coalesce_many((name, name, species)))
+///     .order(id)
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Jack".to_string(), "spider".to_string()]), names);
+///
+/// let maybe_names = animals
+///     .select(coalesce_many((name, name)))
+///     .order(id)
+///     .load::<Option<String>>(&connection);
+/// assert_eq!(Ok(vec![Some("Jack".to_string()), None]), maybe_names);
+/// # }
+/// ```
+pub fn coalesce_many<T>(args: T) -> CoalesceMany<T>
+where
+    CoalesceMany<T>: Expression,
+{
+    CoalesceMany { args: args }
+}
+
+/// The SQL type of `COALESCE(Self, Other)`, which is only nullable if both
+/// sides are.
+#[doc(hidden)]
+pub trait CoalesceSqlType<Other> {
+    type Output;
+}
+
+impl<ST: NotNull> CoalesceSqlType<Nullable<ST>> for Nullable<ST> {
+    type Output = Nullable<ST>;
+}
+
+impl<ST: NotNull> CoalesceSqlType<ST> for Nullable<ST> {
+    type Output = ST;
+}
+
+impl<ST: NotNull> CoalesceSqlType<Nullable<ST>> for ST {
+    type Output = ST;
+}
+
+impl<ST: NotNull> CoalesceSqlType<ST> for ST {
+    type Output = ST;
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CoalesceMany<T> {
+    args: T,
+}
+
+macro_rules! coalesce_many_impls {
+    ($T1:ident, $T2:ident) => {
+        impl<$T1, $T2> Expression for CoalesceMany<($T1, $T2)>
+        where
+            $T1: Expression,
+            $T2: Expression,
+            $T1::SqlType: CoalesceSqlType<$T2::SqlType>,
+        {
+            type SqlType = <$T1::SqlType as CoalesceSqlType<$T2::SqlType>>::Output;
+        }
+    };
+
+    ($T1:ident, $($T:ident),+) => {
+        impl<$T1, $($T),+> Expression for CoalesceMany<($T1, $($T),+)>
+        where
+            $T1: Expression,
+            CoalesceMany<($($T,)+)>: Expression,
+            $T1::SqlType: CoalesceSqlType<<CoalesceMany<($($T,)+)> as Expression>::SqlType>,
+        {
+            type SqlType =
+                <$T1::SqlType as CoalesceSqlType<<CoalesceMany<($($T,)+)> as Expression>::SqlType>>::Output;
+        }
+
+        coalesce_many_impls!($($T),+);
+    };
+}
+
+coalesce_many_impls!(A, B, C, D, E, F, G, H);
+
+impl<T> NonAggregate for CoalesceMany<T>
+where
+    T: NonAggregate,
+    CoalesceMany<T>: Expression,
+{
+}
+
+impl<T, DB> QueryFragment<DB> for CoalesceMany<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("COALESCE(");
+        self.args.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(CoalesceMany<T>);
+impl_selectable_expression!(CoalesceMany<T>);
@@ -1,7 +1,7 @@
 use backend::Backend;
 use query_builder::*;
 use result::QueryResult;
-use super::Expression;
+use super::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
 use types::BigInt;
 
 /// Creates a SQL `COUNT` expression
@@ -30,10 +30,59 @@ use types::BigInt;
 /// assert_eq!(Ok(1), animals.select(count(name)).first(&connection));
 /// # }
 /// ```
-pub fn count<T: Expression>(t: T) -> Count<T> {
+pub fn count<T: CountArgument>(t: T) -> Count<T> {
     Count { target: t }
 }
 
+/// Types which can be passed to [`count`](fn.count.html)
+///
+/// This is implemented for every expression, and for the result of
+/// [`distinct`](fn.distinct.html), which can't be used anywhere else.
+pub trait CountArgument {
+    /// The expression which is counted
+    type Expression: Expression;
+}
+
+impl<T: Expression> CountArgument for T {
+    type Expression = T;
+}
+
+impl<T: Expression> CountArgument for Distinct<T> {
+    type Expression = T;
+}
+
+/// Creates a SQL `DISTINCT` modifier for the argument of an aggregate
+///
+/// This can only be passed to [`count`](fn.count.html), producing
+/// `COUNT(DISTINCT expr)`. The result of the count is still a `BigInt`. The
+/// argument must be an expression which isn't an aggregate, so
+/// `count(distinct(distinct(x)))` will not compile.
+///
+/// As with most bare functions, this is not exported by default. You can import
+/// it specifically as `diesel::expression::count::distinct`, or glob import
+/// `diesel::dsl::*`
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # fn main() {
+/// #     use schema::posts::dsl::*;
+/// #     let connection = establish_connection();
+/// assert_eq!(Ok(3), posts.select(count(user_id)).first(&connection));
+/// assert_eq!(Ok(2), posts.select(count(distinct(user_id))).first(&connection));
+/// # }
+/// ```
+pub fn distinct<T>(t: T) -> Distinct<T>
+where
+    T: Expression + NonAggregate,
+{
+    Distinct { target: t }
+}
+
 /// Creates a SQL `COUNT(*)` expression
 ///
 /// For selecting the count of a query, and nothing else, you can just call
@@ -64,6 +113,42 @@ pub fn count<T: Expression>(t: T) -> Count<T> {
 /// assert_eq!(Ok(2), users.select(count_star()).first(&connection));
 /// # }
 /// ```
+///
+/// ### Counting every row alongside each row
+///
+/// Calling `.over()` on the result creates `COUNT(*) OVER ()`, which is the
+/// number of rows the query matches before `LIMIT` and `OFFSET` are applied.
+/// Unlike `count_star()` it isn't an aggregate, so it can be selected along
+/// with other columns. This is useful for showing the total number of
+/// results next to a single page of them. Window functions require
+/// PostgreSQL, SQLite 3.25 or MySQL 8.
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let first_page = users
+///     .select((name, count_star().over()))
+///     .order(id)
+///     .limit(1)
+///     .load::<(String, i64)>(&connection);
+/// assert_eq!(Ok(vec![("Sean".to_string(), 2)]), first_page);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
 pub fn count_star() -> CountStar {
     CountStar
 }
@@ -74,7 +159,7 @@ pub struct Count<T> {
     target: T,
 }
 
-impl<T: Expression> Expression for Count<T> {
+impl<T: CountArgument> Expression for Count<T> {
     type SqlType = BigInt;
 }
 
@@ -88,7 +173,38 @@ impl<T: QueryFragment<DB>, DB: Backend> QueryFragment<DB> for Count<T> {
 }
 
 impl_query_id!(Count<T>);
-impl_selectable_expression!(Count<T>);
+
+impl<T, QS> AppearsOnTable<QS> for Count<T>
+where
+    T: CountArgument,
+    T::Expression: AppearsOnTable<QS>,
+{
+}
+
+impl<T, QS> SelectableExpression<QS> for Count<T>
+where
+    T: CountArgument,
+    T::Expression: SelectableExpression<QS>,
+{
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Distinct<T> {
+    target: T,
+}
+
+// Deliberately not an `Expression`, so that it can only be used as the
+// argument of `count`.
+impl<T: QueryFragment<DB>, DB: Backend> QueryFragment<DB> for Distinct<T> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("DISTINCT ");
+        self.target.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(Distinct<T>);
 
 #[derive(Debug, Clone, Copy)]
 #[doc(hidden)]
@@ -107,3 +223,31 @@ impl<DB: Backend> QueryFragment<DB> for CountStar {
 
 impl_query_id!(CountStar);
 impl_selectable_expression!(CountStar);
+
+impl CountStar {
+    /// Creates `COUNT(*) OVER ()`. See [`count_star`](fn.count_star.html)
+    /// for details.
+    pub fn over(self) -> CountStarOver {
+        CountStarOver
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct CountStarOver;
+
+impl Expression for CountStarOver {
+    type SqlType = BigInt;
+}
+
+impl NonAggregate for CountStarOver {}
+
+impl<DB: Backend> QueryFragment<DB> for CountStarOver {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("COUNT(*) OVER ()");
+        Ok(())
+    }
+}
+
+impl_query_id!(CountStarOver);
+impl_selectable_expression!(CountStarOver);
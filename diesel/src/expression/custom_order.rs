@@ -0,0 +1,123 @@
+use backend::Backend;
+use expression::*;
+use query_builder::*;
+use result::QueryResult;
+use types::Integer;
+
+/// Creates a `CASE` expression which sorts rows by the position of `expr` in
+/// `values`.
+///
+/// The expression evaluates to the index of the first value equal to `expr`.
+/// Rows which don't match any of the values (including rows where `expr` is
+/// `NULL`) evaluate to `values.len()`, so they are sorted after all of the
+/// listed values. Each value is sent as a bind parameter.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     use diesel::dsl::custom_order;
+/// #     let connection = establish_connection();
+/// let names = users
+///     .select(name)
+///     .order(custom_order(name, &["Tess", "Sean"]))
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Tess".to_string(), "Sean".to_string()]), names);
+/// # }
+/// ```
+pub fn custom_order<Expr, I>(
+    expr: Expr,
+    values: I,
+) -> CustomOrder<Expr, <I::Item as AsExpression<Expr::SqlType>>::Expression>
+where
+    Expr: Expression,
+    I: IntoIterator,
+    I::Item: AsExpression<Expr::SqlType>,
+{
+    CustomOrder {
+        expr: expr,
+        values: values.into_iter().map(AsExpression::as_expression).collect(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CustomOrder<Expr, T> {
+    expr: Expr,
+    values: Vec<T>,
+}
+
+impl<Expr, T> Expression for CustomOrder<Expr, T>
+where
+    Expr: Expression,
+    T: Expression<SqlType = Expr::SqlType>,
+{
+    type SqlType = Integer;
+}
+
+impl<Expr, T> NonAggregate for CustomOrder<Expr, T>
+where
+    Expr: NonAggregate,
+    CustomOrder<Expr, T>: Expression,
+{
+}
+
+impl<Expr, T, QS> SelectableExpression<QS> for CustomOrder<Expr, T>
+where
+    CustomOrder<Expr, T>: AppearsOnTable<QS>,
+    Expr: SelectableExpression<QS>,
+    T: SelectableExpression<QS>,
+{
+}
+
+impl<Expr, T, QS> AppearsOnTable<QS> for CustomOrder<Expr, T>
+where
+    CustomOrder<Expr, T>: Expression,
+    Expr: AppearsOnTable<QS>,
+    T: AppearsOnTable<QS>,
+{
+}
+
+impl<Expr, T, DB> QueryFragment<DB> for CustomOrder<Expr, T>
+where
+    DB: Backend,
+    Expr: QueryFragment<DB>,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        // The SQL depends on the number of values
+        out.unsafe_to_cache_prepared();
+        if self.values.is_empty() {
+            // `CASE` requires at least one `WHEN`, and a bare `0` would be
+            // read as a column position in an `ORDER BY` clause.
+            out.push_sql("CASE WHEN 1=0 THEN 0 ELSE 0 END");
+            return Ok(());
+        }
+
+        out.push_sql("CASE");
+        for (position, value) in self.values.iter().enumerate() {
+            out.push_sql(" WHEN ");
+            self.expr.walk_ast(out.reborrow())?;
+            out.push_sql(" = ");
+            value.walk_ast(out.reborrow())?;
+            out.push_sql(" THEN ");
+            out.push_sql(&position.to_string());
+        }
+        out.push_sql(" ELSE ");
+        out.push_sql(&self.values.len().to_string());
+        out.push_sql(" END");
+        Ok(())
+    }
+}
+
+impl_query_id!(noop: CustomOrder<Expr, T>);
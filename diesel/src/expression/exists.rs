@@ -1,14 +1,14 @@
 use backend::Backend;
-use expression::{Expression, NonAggregate};
+use expression::{operators, Expression, NonAggregate};
 use query_builder::*;
 use result::QueryResult;
 use types::Bool;
 
 /// Creates a SQL `EXISTS` expression.
 ///
-/// The argument must be a complete SQL query. The result of this could in
-/// theory be passed to `.filter`, but since the query cannot reference columns
-/// from the outer query, this is of limited usefulness.
+/// The argument is a select statement, which may reference columns of the
+/// outer query. This makes it possible to write correlated subqueries, such as
+/// finding all users who have at least one post.
 ///
 /// # Example
 ///
@@ -36,10 +36,87 @@ use types::Bool;
 /// assert_eq!(Ok(false), jim_exists);
 /// # }
 /// ```
+///
+/// ### Correlated subqueries
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # enable_multi_table_joins!(users, posts);
+/// #
+/// # fn main() {
+/// #     use diesel::dsl::exists;
+/// #     let connection = establish_connection();
+/// #     connection.execute("INSERT INTO users (name) VALUES ('Jim')").unwrap();
+/// let authors = users::table
+///     .select(users::name)
+///     .filter(exists(posts::table.filter(posts::user_id.eq(users::id))))
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec![String::from("Sean"), String::from("Tess")]), authors);
+/// # }
+/// ```
 pub fn exists<T>(query: T) -> Exists<T> {
     Exists(query)
 }
 
+/// Creates a SQL `NOT EXISTS` expression.
+///
+/// See [`exists`](fn.exists.html) for details.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # enable_multi_table_joins!(users, posts);
+/// #
+/// # fn main() {
+/// #     use diesel::dsl::not_exists;
+/// #     let connection = establish_connection();
+/// #     connection.execute("INSERT INTO users (name) VALUES ('Jim')").unwrap();
+/// let users_without_posts = users::table
+///     .select(users::name)
+///     .filter(not_exists(posts::table.filter(posts::user_id.eq(users::id))))
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec![String::from("Jim")]), users_without_posts);
+/// # }
+/// ```
+pub fn not_exists<T>(query: T) -> NotExists<T> {
+    operators::Not::new(exists(query))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Exists<T>(T);
 
@@ -67,3 +144,6 @@ where
 
 impl_query_id!(Exists<T>);
 impl_selectable_expression!(Exists<T>);
+
+/// The return type of [`not_exists`](fn.not_exists.html)
+pub type NotExists<T> = operators::Not<Exists<T>>;
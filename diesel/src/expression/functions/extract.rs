@@ -0,0 +1,297 @@
+use std::marker::PhantomData;
+
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use query_builder::*;
+use result::QueryResult;
+use types::{Date, Double, Nullable, Time, Timestamp};
+
+/// The fields which can be extracted from a date or time with
+/// [`extract`](../fn.extract.html), or which a timestamp can be truncated to
+/// with `date_trunc` on PostgreSQL.
+///
+/// Each field is a separate type, so the field is always part of the SQL
+/// itself and is never sent as a bind parameter. The traits in this module
+/// are not meant to be implemented outside of Diesel.
+pub mod date_part {
+    /// A field which can be passed to [`extract`](../fn.extract.html).
+    pub trait DatePart: Copy {
+        #[doc(hidden)]
+        const KEYWORD: &'static str;
+        #[doc(hidden)]
+        const STRFTIME_FORMAT: &'static str;
+    }
+
+    /// A precision which can be passed to `date_trunc`.
+    #[cfg(feature = "postgres")]
+    pub trait DateTruncPrecision: Copy {
+        #[doc(hidden)]
+        const NAME: &'static str;
+    }
+
+    macro_rules! date_parts {
+        ($($name:ident => ($precision_name:expr, $docs:expr),)+) => {
+            $(
+                #[doc=$docs]
+                #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+                pub struct $name;
+
+                #[cfg(feature = "postgres")]
+                impl DateTruncPrecision for $name {
+                    const NAME: &'static str = $precision_name;
+                }
+
+                impl_query_id!($name);
+            )+
+        }
+    }
+
+    macro_rules! extractable_date_parts {
+        ($($name:ident => ($keyword:expr, $format:expr),)+) => {
+            $(
+                impl DatePart for $name {
+                    const KEYWORD: &'static str = $keyword;
+                    const STRFTIME_FORMAT: &'static str = $format;
+                }
+            )+
+        }
+    }
+
+    date_parts! {
+        Year => ("year", "The year of a date or timestamp"),
+        Month => ("month", "The month of a date or timestamp, from 1 to 12"),
+        Day => ("day", "The day of the month of a date or timestamp"),
+        Hour => ("hour", "The hour of a time or timestamp, from 0 to 23"),
+        Minute => ("minute", "The minute of a time or timestamp"),
+        Second => ("second", "The second of a time or timestamp"),
+    }
+
+    extractable_date_parts! {
+        Year => ("YEAR", "%Y"),
+        Month => ("MONTH", "%m"),
+        Day => ("DAY", "%d"),
+        Hour => ("HOUR", "%H"),
+        Minute => ("MINUTE", "%M"),
+        Second => ("SECOND", "%S"),
+    }
+
+    #[cfg(feature = "postgres")]
+    date_parts! {
+        Microseconds => ("microseconds", "Microseconds, only used with `date_trunc`"),
+        Milliseconds => ("milliseconds", "Milliseconds, only used with `date_trunc`"),
+        Week => ("week", "The ISO week of a timestamp, only used with `date_trunc`"),
+        Quarter => ("quarter", "The quarter of a timestamp, only used with `date_trunc`"),
+        Decade => ("decade", "The decade of a timestamp, only used with `date_trunc`"),
+        Century => ("century", "The century of a timestamp, only used with `date_trunc`"),
+        Millennium => ("millennium", "The millennium of a timestamp, only used with `date_trunc`"),
+    }
+}
+
+use self::date_part::*;
+
+/// The SQL types which fields can be extracted from.
+///
+/// The nullable version of each type is accepted as well, in which case the
+/// extracted field is nullable too.
+#[doc(hidden)]
+pub trait DateTimeType {
+    type Extracted;
+}
+
+impl DateTimeType for Timestamp {
+    type Extracted = Double;
+}
+
+impl DateTimeType for Date {
+    type Extracted = Double;
+}
+
+impl DateTimeType for Time {
+    type Extracted = Double;
+}
+
+#[cfg(feature = "postgres")]
+impl DateTimeType for ::types::Timestamptz {
+    type Extracted = Double;
+}
+
+impl<T> DateTimeType for Nullable<T>
+where
+    T: DateTimeType + ::types::NotNull,
+{
+    type Extracted = Nullable<Double>;
+}
+
+/// Represents the SQL `EXTRACT` function, which returns a single field of a
+/// date or time as a `Double`.
+///
+/// The field is one of the types in [`date_part`](date_part/index.html), and
+/// is rendered as a keyword rather than a bind parameter. The helpers
+/// [`year`](fn.year.html), [`month`](fn.month.html), [`day`](fn.day.html),
+/// [`hour`](fn.hour.html), [`minute`](fn.minute.html) and
+/// [`second`](fn.second.html) are shorthands for each field.
+///
+/// PostgreSQL's `EXTRACT` doesn't return a `Double` on every version, so
+/// `date_part` is used instead. SQLite has no `EXTRACT` function, so
+/// `strftime` is used instead. On MySQL and SQLite the seconds are always a
+/// whole number.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use diesel::dsl::*;
+/// use diesel::dsl::date_part::Month;
+/// use diesel::types::Timestamp;
+///
+/// # #[cfg(not(feature = "sqlite"))]
+/// let timestamp = sql::<Timestamp>("TIMESTAMP '2017-08-21 12:34:56'");
+/// # #[cfg(feature = "sqlite")]
+/// # let timestamp = sql::<Timestamp>("'2017-08-21 12:34:56'");
+/// let extracted = diesel::select((extract(Month, timestamp.clone()), year(timestamp)))
+///     .get_result::<(f64, f64)>(&connection);
+/// assert_eq!(Ok((8.0, 2017.0)), extracted);
+/// # }
+/// ```
+pub fn extract<F, T>(_field: F, expr: T) -> Extract<F, T>
+where
+    F: DatePart,
+    T: Expression,
+    T::SqlType: DateTimeType,
+{
+    Extract {
+        field: PhantomData,
+        expr: expr,
+    }
+}
+
+macro_rules! extract_helpers {
+    ($($fn_name:ident => $field:ident, $docs:expr,)+) => {
+        $(
+            #[doc=$docs]
+            pub fn $fn_name<T>(expr: T) -> Extract<$field, T>
+            where
+                T: Expression,
+                T::SqlType: DateTimeType,
+            {
+                extract($field, expr)
+            }
+        )+
+    }
+}
+
+extract_helpers! {
+    year => Year, "Extracts the year of a date or timestamp. Shorthand for
+`extract(date_part::Year, expr)`, see [`extract`](fn.extract.html).",
+    month => Month, "Extracts the month of a date or timestamp. Shorthand for
+`extract(date_part::Month, expr)`, see [`extract`](fn.extract.html).",
+    day => Day, "Extracts the day of the month of a date or timestamp. Shorthand
+for `extract(date_part::Day, expr)`, see [`extract`](fn.extract.html).",
+    hour => Hour, "Extracts the hour of a time or timestamp. Shorthand for
+`extract(date_part::Hour, expr)`, see [`extract`](fn.extract.html).",
+    minute => Minute, "Extracts the minute of a time or timestamp. Shorthand for
+`extract(date_part::Minute, expr)`, see [`extract`](fn.extract.html).",
+    second => Second, "Extracts the second of a time or timestamp. Shorthand for
+`extract(date_part::Second, expr)`, see [`extract`](fn.extract.html).",
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Extract<F, T> {
+    field: PhantomData<F>,
+    expr: T,
+}
+
+impl<F, T> Expression for Extract<F, T>
+where
+    T: Expression,
+    T::SqlType: DateTimeType,
+{
+    type SqlType = <T::SqlType as DateTimeType>::Extracted;
+}
+
+impl<F, T> NonAggregate for Extract<F, T>
+where
+    T: NonAggregate,
+    Extract<F, T>: Expression,
+{
+}
+
+#[cfg(feature = "postgres")]
+impl<F, T> QueryFragment<::pg::Pg> for Extract<F, T>
+where
+    F: DatePart,
+    T: QueryFragment<::pg::Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+        // `EXTRACT` returns `numeric` since PostgreSQL 14, while `date_part`
+        // still returns `double precision`.
+        out.push_sql("date_part('");
+        out.push_sql(F::KEYWORD);
+        out.push_sql("', ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<F, T> QueryFragment<::mysql::Mysql> for Extract<F, T>
+where
+    F: DatePart,
+    T: QueryFragment<::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<::mysql::Mysql>) -> QueryResult<()> {
+        // `EXTRACT` returns an integer on MySQL. Adding a floating point zero
+        // turns it into a `DOUBLE`, since `CAST(.. AS DOUBLE)` requires
+        // MySQL 8.0.17.
+        out.push_sql("(EXTRACT(");
+        out.push_sql(F::KEYWORD);
+        out.push_sql(" FROM ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(") + 0E0)");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<F, T> QueryFragment<::sqlite::Sqlite> for Extract<F, T>
+where
+    F: DatePart,
+    T: QueryFragment<::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        out.push_sql("CAST(strftime('");
+        out.push_sql(F::STRFTIME_FORMAT);
+        out.push_sql("', ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(") AS REAL)");
+        Ok(())
+    }
+}
+
+impl_query_id!(Extract<F, T>);
+
+impl<F, T, QS> SelectableExpression<QS> for Extract<F, T>
+where
+    T: SelectableExpression<QS>,
+    Extract<F, T>: AppearsOnTable<QS>,
+{
+}
+
+impl<F, T, QS> AppearsOnTable<QS> for Extract<F, T>
+where
+    T: AppearsOnTable<QS>,
+    Extract<F, T>: Expression,
+{
+}
@@ -1,6 +1,23 @@
 #[macro_export]
 #[doc(hidden)]
 macro_rules! sql_function_body {
+    ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty,
+    $docs: expr) => {
+        sql_function_body_except_non_aggregate!(
+            $fn_name, $struct_name, ($($arg_name: $arg_type),*) -> $return_type, $docs);
+
+        #[allow(non_camel_case_types)]
+        impl<$($arg_name),*> $crate::expression::NonAggregate for $struct_name<$($arg_name),*> where
+            $($arg_name: $crate::expression::NonAggregate,)*
+            $struct_name<$($arg_name),*>: $crate::expression::Expression,
+        {
+        }
+    }
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! sql_function_body_except_non_aggregate {
     ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty,
     $docs: expr) => {
         #[allow(non_camel_case_types)]
@@ -62,11 +79,85 @@ macro_rules! sql_function_body {
             $struct_name<$($arg_name),*>: $crate::expression::Expression,
         {
         }
+    }
+}
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! generic_sql_function_body {
+    ($fn_name:ident, $struct_name:ident, <$($type_param:ident),+>
+    ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty, $docs: expr) => {
         #[allow(non_camel_case_types)]
-        impl<$($arg_name),*> $crate::expression::NonAggregate for $struct_name<$($arg_name),*> where
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $struct_name<$($type_param,)+ $($arg_name),*> {
+            $($arg_name: $arg_name,)*
+            _marker: ::std::marker::PhantomData<($($type_param,)+)>,
+        }
+
+        #[allow(non_camel_case_types)]
+        pub type $fn_name<$($type_param,)+ $($arg_name),*> = $struct_name<$($type_param,)+ $(
+            <$arg_name as $crate::expression::AsExpression<$arg_type>>::Expression
+        ),*>;
+
+        #[allow(non_camel_case_types)]
+        #[doc=$docs]
+        pub fn $fn_name<$($type_param,)+ $($arg_name),*>($($arg_name: $arg_name),*)
+            -> $fn_name<$($type_param,)+ $($arg_name),*>
+            where $($arg_name: $crate::expression::AsExpression<$arg_type>),+
+        {
+            $struct_name {
+                $($arg_name: $arg_name.as_expression(),)+
+                _marker: ::std::marker::PhantomData,
+            }
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<$($type_param,)+ $($arg_name),*> $crate::expression::Expression
+            for $struct_name<$($type_param,)+ $($arg_name),*> where
+            for <'a> ($(&'a $arg_name),*): $crate::expression::Expression,
+        {
+            type SqlType = $return_type;
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<$($type_param,)+ $($arg_name),*, DB> $crate::query_builder::QueryFragment<DB>
+            for $struct_name<$($type_param,)+ $($arg_name),*> where
+            DB: $crate::backend::Backend,
+            for <'a> ($(&'a $arg_name),*): $crate::query_builder::QueryFragment<DB>,
+        {
+            fn walk_ast(&self, mut out: $crate::query_builder::AstPass<DB>) -> $crate::result::QueryResult<()> {
+                out.push_sql(concat!(stringify!($fn_name), "("));
+                $crate::query_builder::QueryFragment::walk_ast(
+                    &($(&self.$arg_name),*), out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl_query_id!($struct_name<$($type_param,)+ $($arg_name),+>);
+
+        #[allow(non_camel_case_types)]
+        impl<$($type_param,)+ $($arg_name),*, QS> $crate::expression::SelectableExpression<QS>
+            for $struct_name<$($type_param,)+ $($arg_name),*> where
+            $($arg_name: $crate::expression::SelectableExpression<QS>,)*
+            $struct_name<$($type_param,)+ $($arg_name),*>: $crate::expression::AppearsOnTable<QS>,
+        {
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<$($type_param,)+ $($arg_name),*, QS> $crate::expression::AppearsOnTable<QS>
+            for $struct_name<$($type_param,)+ $($arg_name),*> where
+            $($arg_name: $crate::expression::AppearsOnTable<QS>,)*
+            $struct_name<$($type_param,)+ $($arg_name),*>: $crate::expression::Expression,
+        {
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<$($type_param,)+ $($arg_name),*> $crate::expression::NonAggregate
+            for $struct_name<$($type_param,)+ $($arg_name),*> where
             $($arg_name: $crate::expression::NonAggregate,)*
-            $struct_name<$($arg_name),*>: $crate::expression::Expression,
+            $struct_name<$($type_param,)+ $($arg_name),*>: $crate::expression::Expression,
         {
         }
     }
@@ -100,6 +191,30 @@ macro_rules! sql_function_body {
 /// // This will generate the following SQL
 /// // SELECT * FROM crates WHERE canon_crate_name(crates.name) = canon_crate_name($1)
 /// # }
+/// ```
+///
+/// # Generic functions
+///
+/// Functions such as `coalesce` or `greatest` return the same type as their
+/// arguments. These can be declared by listing type parameters before the
+/// arguments, which can then be used as argument and return types. The type
+/// parameters become the first type parameters of the generated function, and
+/// are usually inferred from the arguments.
+///
+/// ```no_run
+/// # #[macro_use] extern crate diesel;
+/// # use diesel::*;
+/// #
+/// # table! { crates { id -> Integer, name -> VarChar, downloads -> Integer, } }
+/// #
+/// sql_function!(greatest, greatest_t, <ST> (a: ST, b: ST) -> ST);
+///
+/// # fn main() {
+/// # use self::crates::dsl::*;
+/// crates.select(greatest(downloads, 100));
+/// // This will generate the following SQL
+/// // SELECT greatest(crates.downloads, $1) FROM crates
+/// # }
 macro_rules! sql_function {
     ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty) => {
         sql_function!($fn_name, $struct_name, ($($arg_name: $arg_type),*) -> $return_type, "");
@@ -113,6 +228,57 @@ macro_rules! sql_function {
     ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*)) => {
         sql_function!($fn_name, $struct_name, ($($arg_name: $arg_type),*) -> ());
     };
+
+    ($fn_name:ident, $struct_name:ident, <$($type_param:ident),+>
+    ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty) => {
+        sql_function!($fn_name, $struct_name, <$($type_param),+>
+            ($($arg_name: $arg_type),*) -> $return_type, "");
+    };
+
+    ($fn_name:ident, $struct_name:ident, <$($type_param:ident),+>
+    ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty, $docs: expr) => {
+        generic_sql_function_body!($fn_name, $struct_name, <$($type_param),+>
+            ($($arg_name: $arg_type),*) -> $return_type, $docs);
+    };
+}
+
+#[macro_export]
+/// Declare an aggregate sql function for use in your code. This takes the
+/// same arguments as [`sql_function!`](macro.sql_function.html), and is useful
+/// if you have defined your own aggregate functions on the database.
+///
+/// Unlike functions declared with `sql_function!`, the resulting expression is
+/// treated the same as built in aggregates such as `count` or `sum`. It can
+/// be selected from a grouped query, but cannot be passed to `filter` or
+/// combined with non-aggregate expressions in a select clause.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[macro_use] extern crate diesel;
+/// # use diesel::*;
+/// #
+/// # table! { crates { id -> Integer, downloads -> Integer, } }
+/// #
+/// sql_aggregate_function!(median, median_t, (a: types::Integer) -> types::Nullable<types::Integer>);
+///
+/// # fn main() {
+/// # use self::crates::dsl::*;
+/// crates.select(median(downloads));
+/// // This will generate the following SQL
+/// // SELECT median(crates.downloads) FROM crates
+/// # }
+/// ```
+macro_rules! sql_aggregate_function {
+    ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty) => {
+        sql_aggregate_function!($fn_name, $struct_name, ($($arg_name: $arg_type),*) -> $return_type, "");
+    };
+
+    ($fn_name:ident, $struct_name:ident, ($($arg_name:ident: $arg_type:ty),*) -> $return_type:ty,
+    $docs: expr) => {
+        sql_function_body_except_non_aggregate!(
+            $fn_name, $struct_name, ($($arg_name: $arg_type),*) -> $return_type, $docs);
+    };
 }
 
 #[macro_export]
@@ -120,6 +286,7 @@ macro_rules! sql_function {
 macro_rules! no_arg_sql_function_body_except_to_sql {
     ($type_name:ident, $return_type:ty, $docs:expr) => {
         #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy)]
         #[doc=$docs]
         pub struct $type_name;
 
@@ -199,6 +366,50 @@ macro_rules! no_arg_sql_function {
     };
 }
 
+// The struct and impls shared by the single argument functions in the
+// `numeric` and `string` modules. The caller provides the constructor.
+macro_rules! unary_function_body {
+    ($type_name:ident, $sql_name:expr, $bound:ident, $return_type:ty) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $type_name<T> {
+            target: T,
+        }
+
+        impl<T> Expression for $type_name<T> where
+            T: Expression,
+            T::SqlType: $bound,
+        {
+            type SqlType = $return_type;
+        }
+
+        impl<T> NonAggregate for $type_name<T> where
+            T: NonAggregate,
+            $type_name<T>: Expression,
+        {
+        }
+
+        impl<T, DB> QueryFragment<DB> for $type_name<T> where
+            T: QueryFragment<DB>,
+            DB: Backend,
+        {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql(concat!($sql_name, "("));
+                self.target.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl_query_id!($type_name<T>);
+        impl_selectable_expression!($type_name<T>);
+    }
+}
+
 pub mod aggregate_ordering;
 pub mod aggregate_folding;
 pub mod date_and_time;
+pub mod extract;
+pub mod numeric;
+pub mod random;
+pub mod string;
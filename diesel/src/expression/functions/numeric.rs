@@ -0,0 +1,220 @@
+use backend::Backend;
+use expression::{AsExpression, Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types::{BigInt, Double, Float, Integer, IntoNullable, NotNull, Nullable, Numeric, SmallInt};
+
+/// The SQL types which can be passed to `abs`.
+///
+/// The nullable version of each type is accepted as well, in which case the
+/// result is nullable too.
+#[doc(hidden)]
+pub trait SignedNumeric {}
+
+impl SignedNumeric for SmallInt {}
+impl SignedNumeric for Integer {}
+impl SignedNumeric for BigInt {}
+impl SignedNumeric for Float {}
+impl SignedNumeric for Double {}
+impl SignedNumeric for Numeric {}
+impl<T: SignedNumeric + NotNull> SignedNumeric for Nullable<T> {}
+
+/// The SQL types which can be passed to `round`, `ceil` and `floor`.
+///
+/// Integer types are not included, since PostgreSQL converts them to `Double`
+/// before rounding, so the result would not have the type of the argument.
+#[doc(hidden)]
+pub trait Fractional: SignedNumeric {}
+
+impl Fractional for Double {}
+impl Fractional for Numeric {}
+impl<T: Fractional + NotNull> Fractional for Nullable<T> {}
+
+macro_rules! numeric_function {
+    ($fn_name:ident, $type_name:ident, $sql_name:expr, $bound:ident, $docs:expr) => {
+        #[doc=$docs]
+        pub fn $fn_name<T>(t: T) -> $type_name<T> where
+            T: Expression,
+            T::SqlType: $bound,
+        {
+            $type_name {
+                target: t,
+            }
+        }
+
+        unary_function_body!($type_name, $sql_name, $bound, T::SqlType);
+    }
+}
+
+numeric_function!(
+    abs,
+    Abs,
+    "ABS",
+    SignedNumeric,
+    "Represents the SQL `ABS` function, which returns the absolute value of a
+number. The result has the same type as the argument, and is `NULL` if the
+argument is.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     animals {
+#         id -> Integer,
+#         species -> VarChar,
+#         legs -> Integer,
+#         name -> Nullable<VarChar>,
+#     }
+# }
+#
+# fn main() {
+#     use self::animals::dsl::*;
+#     let connection = establish_connection();
+let differences = animals.select(abs(legs - 6))
+    .order(id)
+    .load::<i32>(&connection);
+assert_eq!(Ok(vec![2, 2]), differences);
+# }
+```"
+);
+
+numeric_function!(
+    round,
+    Round,
+    "ROUND",
+    Fractional,
+    "Represents the SQL `ROUND` function, which rounds a number to the nearest
+integer. The result has the same type as the argument, and is `NULL` if the
+argument is.
+
+The argument must be a `Double` or `Numeric` expression. This can be the
+result of an aggregate function, as in `round(avg(price))`. To round to a
+given number of decimal places, use [`round_to`](fn.round_to.html)."
+);
+
+numeric_function!(
+    ceil,
+    Ceil,
+    "CEIL",
+    Fractional,
+    "Represents the SQL `CEIL` function, which returns the smallest integer
+that is greater than or equal to its argument. The result has the same type as
+the argument, and is `NULL` if the argument is.
+
+The argument must be a `Double` or `Numeric` expression. SQLite only provides
+this function from version 3.35, and only if it was built with
+`SQLITE_ENABLE_MATH_FUNCTIONS`."
+);
+
+numeric_function!(
+    floor,
+    Floor,
+    "FLOOR",
+    Fractional,
+    "Represents the SQL `FLOOR` function, which returns the largest integer that
+is less than or equal to its argument. The result has the same type as the
+argument, and is `NULL` if the argument is.
+
+The argument must be a `Double` or `Numeric` expression. SQLite only provides
+this function from version 3.35, and only if it was built with
+`SQLITE_ENABLE_MATH_FUNCTIONS`."
+);
+
+/// Represents the SQL `ROUND` function with a precision, which rounds a number
+/// to the given number of decimal places.
+///
+/// The result has the same type as the first argument, and is `NULL` if it
+/// is. PostgreSQL only supports a precision when rounding `Numeric`
+/// expressions, so on PostgreSQL a query which rounds a `Double` to a
+/// precision doesn't compile. The sum of a `Numeric` column can be rounded on
+/// every backend: `round_to(sum(amount), 2)`.
+pub fn round_to<T, U>(t: T, decimal_places: U) -> RoundTo<T, U::Expression>
+where
+    T: Expression,
+    T::SqlType: Fractional,
+    U: AsExpression<Integer>,
+{
+    RoundTo {
+        target: t,
+        decimal_places: decimal_places.as_expression(),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct RoundTo<T, U> {
+    target: T,
+    decimal_places: U,
+}
+
+impl<T, U> Expression for RoundTo<T, U>
+where
+    T: Expression,
+    T::SqlType: Fractional,
+    U: Expression<SqlType = Integer>,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<T, U> NonAggregate for RoundTo<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+    RoundTo<T, U>: Expression,
+{
+}
+
+#[cfg(feature = "postgres")]
+impl<T, U> QueryFragment<::pg::Pg> for RoundTo<T, U>
+where
+    T: Expression + QueryFragment<::pg::Pg>,
+    T::SqlType: IntoNullable<Nullable = Nullable<Numeric>>,
+    U: QueryFragment<::pg::Pg>,
+{
+    fn walk_ast(&self, out: AstPass<::pg::Pg>) -> QueryResult<()> {
+        walk_round_to(&self.target, &self.decimal_places, out)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T, U> QueryFragment<::mysql::Mysql> for RoundTo<T, U>
+where
+    T: QueryFragment<::mysql::Mysql>,
+    U: QueryFragment<::mysql::Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<::mysql::Mysql>) -> QueryResult<()> {
+        walk_round_to(&self.target, &self.decimal_places, out)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, U> QueryFragment<::sqlite::Sqlite> for RoundTo<T, U>
+where
+    T: QueryFragment<::sqlite::Sqlite>,
+    U: QueryFragment<::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        walk_round_to(&self.target, &self.decimal_places, out)
+    }
+}
+
+fn walk_round_to<T, U, DB>(target: &T, decimal_places: &U, mut out: AstPass<DB>) -> QueryResult<()>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    out.push_sql("ROUND(");
+    target.walk_ast(out.reborrow())?;
+    out.push_sql(", ");
+    decimal_places.walk_ast(out.reborrow())?;
+    out.push_sql(")");
+    Ok(())
+}
+
+impl_query_id!(RoundTo<T, U>);
+impl_selectable_expression!(RoundTo<T, U>);
@@ -0,0 +1,172 @@
+use expression::{AsExpression, Expression, NonAggregate};
+#[cfg(feature = "mysql")]
+use mysql::Mysql;
+#[cfg(feature = "postgres")]
+use pg::Pg;
+use query_builder::*;
+use result::QueryResult;
+#[cfg(feature = "sqlite")]
+use sqlite::Sqlite;
+use types::Double;
+
+/// Represents the backend specific function which returns a random number
+/// between 0 and 1. This is `RANDOM()` on PostgreSQL and `RAND()` on MySQL.
+/// SQLite's `RANDOM()` returns a 64-bit integer instead, so it is scaled to
+/// the same range on SQLite.
+///
+/// The most common use of this function is to select a small random sample of
+/// rows with `.order(random()).limit(n)`. Be aware that this requires the
+/// database to generate a value for and sort every row in the table, which is
+/// very slow for large tables. On PostgreSQL, `TABLESAMPLE` is a much more
+/// efficient alternative for sampling large tables.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let random_user = users.select(name).order(random()).first::<String>(&connection);
+/// assert!(random_user == Ok("Sean".into()) || random_user == Ok("Tess".into()));
+/// # }
+/// ```
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub struct random;
+
+impl Expression for random {
+    type SqlType = Double;
+}
+
+impl NonAggregate for random {}
+
+#[cfg(feature = "postgres")]
+impl QueryFragment<Pg> for random {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("RANDOM()");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl QueryFragment<Sqlite> for random {
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        // Maps the range of a signed 64-bit integer onto 0..1
+        out.push_sql("(RANDOM() / 18446744073709551616.0 + 0.5)");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl QueryFragment<Mysql> for random {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("RAND()");
+        Ok(())
+    }
+}
+
+impl_query_id!(random);
+impl_selectable_expression!(random);
+
+/// Represents a call to `RANDOM()` which is seeded with `setseed` first, so
+/// that ordering by it produces the same order every time the query is run
+/// with the same seed. The seed must be between -1.0 and 1.0. This is only
+/// available on PostgreSQL.
+///
+/// PostgreSQL seeds its random number generator per session, so calling
+/// `setseed` in a separate statement would be affected by anything else which
+/// calls `random()` on that connection in between. Instead the seed is set by
+/// the query itself, right before it is used. This function generates
+/// `CASE WHEN (SELECT setseed($1)) IS NULL THEN NULL ELSE RANDOM() END`. The
+/// uncorrelated subquery is evaluated exactly once, the first time a row is
+/// sorted, which is before the first call to `RANDOM()`. Since `setseed`
+/// returns a non-null value of type `void`, every row gets the result of
+/// `RANDOM()`.
+///
+/// The order is only reproducible as long as the rows are visited in the same
+/// order, so a table which has been modified in between may be ordered
+/// differently even with the same seed.
+///
+/// Note that `setseed` changes the state of the session's random number
+/// generator. Calls to `random()` in later queries on the same connection
+/// will continue the seeded sequence, and will therefore be predictable.
+/// Don't use this on a connection which also uses `random()` for anything
+/// which shouldn't be guessable.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let query = users.select(name).order(random_seeded(0.42));
+/// let first_run = query.load::<String>(&connection);
+/// let second_run = query.load::<String>(&connection);
+/// assert_eq!(first_run, second_run);
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+pub fn random_seeded<T>(seed: T) -> RandomSeeded<T::Expression>
+where
+    T: AsExpression<Double>,
+{
+    RandomSeeded {
+        seed: seed.as_expression(),
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+#[doc(hidden)]
+pub struct RandomSeeded<T> {
+    seed: T,
+}
+
+impl<T> Expression for RandomSeeded<T>
+where
+    T: Expression<SqlType = Double>,
+{
+    type SqlType = Double;
+}
+
+impl<T: NonAggregate> NonAggregate for RandomSeeded<T> where RandomSeeded<T>: Expression {}
+
+#[cfg(feature = "postgres")]
+impl<T> QueryFragment<Pg> for RandomSeeded<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("CASE WHEN (SELECT setseed(");
+        self.seed.walk_ast(out.reborrow())?;
+        out.push_sql(")) IS NULL THEN NULL ELSE RANDOM() END");
+        Ok(())
+    }
+}
+
+impl_query_id!(RandomSeeded<T>);
+impl_selectable_expression!(RandomSeeded<T>);
@@ -0,0 +1,198 @@
+use backend::Backend;
+use expression::{Expression, NonAggregate};
+use expression::bound::Bound;
+use query_builder::*;
+use result::QueryResult;
+use types::{Integer, Nullable, Text};
+
+/// The SQL types which can be passed to the string functions in this module.
+///
+/// `Nullable<Text>` is accepted as well, in which case the result of the
+/// function is nullable too.
+#[doc(hidden)]
+pub trait StringType {
+    /// The type returned by `LENGTH` for this type
+    type Length;
+}
+
+impl StringType for Text {
+    type Length = Integer;
+}
+
+impl StringType for Nullable<Text> {
+    type Length = Nullable<Integer>;
+}
+
+/// Converts the argument of a string function into an expression.
+///
+/// This is implemented for any text expression, as well as for Rust strings.
+/// `AsExpression` can't be used here, since Rust strings can be used as
+/// either `Text` or `Nullable<Text>`, so the type of `lower("Sean")` could not
+/// be inferred.
+#[doc(hidden)]
+pub trait AsStringExpression {
+    type Expression: Expression;
+
+    fn as_string_expression(self) -> Self::Expression;
+}
+
+impl<T> AsStringExpression for T
+where
+    T: Expression,
+    T::SqlType: StringType,
+{
+    type Expression = Self;
+
+    fn as_string_expression(self) -> Self {
+        self
+    }
+}
+
+impl<'a> AsStringExpression for &'a str {
+    type Expression = Bound<Text, Self>;
+
+    fn as_string_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl AsStringExpression for String {
+    type Expression = Bound<Text, Self>;
+
+    fn as_string_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+macro_rules! string_function {
+    ($fn_name:ident, $type_name:ident, $sql_name:expr, $return_type:ty, $docs:expr) => {
+        #[doc=$docs]
+        pub fn $fn_name<T: AsStringExpression>(t: T) -> $type_name<T::Expression> {
+            $type_name {
+                target: t.as_string_expression(),
+            }
+        }
+
+        unary_function_body!($type_name, $sql_name, StringType, $return_type);
+    }
+}
+
+string_function!(
+    lower,
+    Lower,
+    "LOWER",
+    T::SqlType,
+    "Represents the SQL `LOWER` function, which converts a string to lower case.
+
+The argument can be a column, any other text expression, or a Rust string. If
+the argument is nullable, so is the result.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     use self::users::dsl::*;
+#     let connection = establish_connection();
+let sean = users.select(id)
+    .filter(lower(name).eq(\"sean\"))
+    .first(&connection);
+assert_eq!(Ok(1), sean);
+
+let names = users.select(lower(name)).order(id).load::<String>(&connection);
+assert_eq!(Ok(vec![\"sean\".to_string(), \"tess\".to_string()]), names);
+
+let lowered = diesel::select(lower(\"SEAN\")).get_result::<String>(&connection);
+assert_eq!(Ok(\"sean\".to_string()), lowered);
+# }
+```"
+);
+
+string_function!(
+    upper,
+    Upper,
+    "UPPER",
+    T::SqlType,
+    "Represents the SQL `UPPER` function, which converts a string to upper case.
+
+The argument can be a column, any other text expression, or a Rust string. If
+the argument is nullable, so is the result.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     use self::users::dsl::*;
+#     let connection = establish_connection();
+let tess = users.select(upper(name))
+    .filter(upper(name).eq(upper(\"tess\")))
+    .first::<String>(&connection);
+assert_eq!(Ok(\"TESS\".to_string()), tess);
+# }
+```"
+);
+
+string_function!(
+    length,
+    Length,
+    "LENGTH",
+    <T::SqlType as StringType>::Length,
+    "Represents the SQL `LENGTH` function, which returns the length of a string
+as an `Integer`.
+
+The argument can be a column, any other text expression, or a Rust string. If
+the argument is nullable, so is the result. On MySQL this is the length in
+bytes rather than characters.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     animals {
+#         id -> Integer,
+#         species -> VarChar,
+#         legs -> Integer,
+#         name -> Nullable<VarChar>,
+#     }
+# }
+#
+# fn main() {
+#     use self::animals::dsl::*;
+#     let connection = establish_connection();
+let name_lengths = animals.select(length(name))
+    .order(id)
+    .load::<Option<i32>>(&connection);
+assert_eq!(Ok(vec![Some(4), None]), name_lengths);
+
+let short_species = animals.select(species)
+    .filter(length(species).lt(4))
+    .load::<String>(&connection);
+assert_eq!(Ok(vec![\"dog\".to_string()]), short_species);
+# }
+```"
+);
@@ -20,6 +20,9 @@ pub type Eq<Lhs, Rhs> = super::operators::Eq<Lhs, AsExpr<Rhs, Lhs>>;
 /// The return type of `lhs.ne(rhs)`
 pub type NotEq<Lhs, Rhs> = super::operators::NotEq<Lhs, AsExpr<Rhs, Lhs>>;
 
+/// The return type of `lhs.eq_nullable(rhs)`
+pub type EqNullable<Lhs, Rhs> = super::operators::EqNullable<Lhs, AsExpr<Rhs, Lhs>>;
+
 /// The return type of `lhs.gt(rhs)`
 pub type Gt<Lhs, Rhs> = super::operators::Gt<Lhs, AsExpr<Rhs, Lhs>>;
 
@@ -51,10 +54,25 @@ pub type NotBetween<Lhs, Rhs> = super::operators::NotBetween<
     Lhs,
     super::operators::And<AsExpr<Rhs, Lhs>, AsExpr<Rhs, Lhs>>,
 >;
+/// The return type of `lhs.in_range(range)`
+pub type InRange<Lhs, Range> = <Range as super::in_range::AsRangeBounds<Lhs>>::Output;
+/// The return type of `lhs.bit_and(rhs)`
+pub type BitAnd<Lhs, Rhs> = super::ops::BitAnd<Lhs, AsExpr<Rhs, Lhs>>;
+/// The return type of `lhs.bit_or(rhs)`
+pub type BitOr<Lhs, Rhs> = super::ops::BitOr<Lhs, AsExpr<Rhs, Lhs>>;
+/// The return type of `lhs.bit_xor(rhs)`
+pub type BitXor<Lhs, Rhs> = super::ops::BitXor<Lhs, AsExpr<Rhs, Lhs>>;
+/// The return type of `lhs.shift_left(rhs)`
+pub type ShiftLeft<Lhs, Rhs> = super::ops::ShiftLeft<Lhs, AsExprOf<Rhs, types::Integer>>;
+/// The return type of `lhs.shift_right(rhs)`
+pub type ShiftRight<Lhs, Rhs> = super::ops::ShiftRight<Lhs, AsExprOf<Rhs, types::Integer>>;
 /// The return type of `not(expr)`
 pub type Not<Expr> = super::operators::Not<Grouped<AsExprOf<Expr, types::Bool>>>;
+/// The return type of `expr.into_boxed_predicate()`
+pub type BoxedPredicate<'a, QS, DB> =
+    Box<super::BoxableExpression<QS, DB, SqlType = types::Bool> + 'a>;
 
 #[doc(inline)]
-pub use super::operators::{Asc, Desc, IsNotNull, IsNull};
+pub use super::operators::{Asc, Desc, IsFalse, IsNotFalse, IsNotNull, IsNotTrue, IsNull, IsTrue};
 #[doc(inline)]
 pub use super::array_comparison::EqAny;
@@ -0,0 +1,33 @@
+use std::ops::Range;
+
+use expression::{AsExpression, Expression};
+use expression::operators::{And, GtEq, Lt};
+
+/// Rust range types which can be passed to
+/// [`in_range`](../expression_methods/trait.ExpressionMethods.html#method.in_range).
+///
+/// A `Range` (`start..end`) includes its start but not its end, and becomes
+/// `expr >= start AND expr < end`.
+pub trait AsRangeBounds<Expr> {
+    /// The type of the generated expression
+    type Output: Expression;
+
+    /// Compares `expr` to both bounds of this range
+    fn as_range_bounds(self, expr: Expr) -> Self::Output;
+}
+
+impl<Expr, T> AsRangeBounds<Expr> for Range<T>
+where
+    Expr: Expression + Clone,
+    T: AsExpression<Expr::SqlType>,
+    And<GtEq<Expr, T::Expression>, Lt<Expr, T::Expression>>: Expression,
+{
+    type Output = And<GtEq<Expr, T::Expression>, Lt<Expr, T::Expression>>;
+
+    fn as_range_bounds(self, expr: Expr) -> Self::Output {
+        And::new(
+            GtEq::new(expr.clone(), self.start.as_expression()),
+            Lt::new(expr, self.end.as_expression()),
+        )
+    }
+}
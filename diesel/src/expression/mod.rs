@@ -13,6 +13,38 @@
 //! Any primitive which implements [`ToSql`](../types/trait.ToSql.html) will
 //! also implement [`AsExpression`](trait.AsExpression.html), allowing it to be
 //! used as an argument to any of the methods described here.
+//!
+//! Expressions implement `Clone`, and also `Copy` if all of their parts do
+//! (columns do, as do values bound from types such as `i32` or `&str`). An
+//! expression can therefore be built once and used in several clauses of a
+//! query. It will still be computed by the database for each of them.
+//!
+//! ```rust
+//! # #[macro_use] extern crate diesel;
+//! # include!("../doctest_setup.rs");
+//! #
+//! # table! {
+//! #     users {
+//! #         id -> Integer,
+//! #         name -> VarChar,
+//! #     }
+//! # }
+//! #
+//! # fn main() {
+//! #     use self::users::dsl::*;
+//! #     let connection = establish_connection();
+//! let is_sean = name.eq("Sean");
+//! let data = users
+//!     .select((name, is_sean))
+//!     .order(is_sean.desc())
+//!     .load(&connection);
+//! let expected = vec![(String::from("Sean"), true), (String::from("Tess"), false)];
+//! assert_eq!(Ok(expected), data);
+//!
+//! let seans = users.select(id).filter(is_sean).load(&connection);
+//! assert_eq!(Ok(vec![1]), seans);
+//! # }
+//! ```
 #[macro_use]
 #[doc(hidden)]
 pub mod ops;
@@ -22,10 +54,14 @@ pub mod array_comparison;
 #[doc(hidden)]
 pub mod bound;
 #[doc(hidden)]
+pub mod coalesce;
+#[doc(hidden)]
 pub mod coerce;
 #[doc(hidden)]
 pub mod count;
 #[doc(hidden)]
+pub mod custom_order;
+#[doc(hidden)]
 pub mod exists;
 #[doc(hidden)]
 #[macro_use]
@@ -34,6 +70,8 @@ pub mod functions;
 pub mod grouped;
 #[macro_use]
 pub mod helper_types;
+#[doc(hidden)]
+pub mod in_range;
 mod not;
 #[doc(hidden)]
 pub mod nullable;
@@ -41,15 +79,21 @@ pub mod nullable;
 #[macro_use]
 pub mod operators;
 #[doc(hidden)]
+pub mod scalar_subquery;
+#[doc(hidden)]
 pub mod sql_literal;
 mod unchecked_bind;
 
 #[doc(hidden)]
 pub mod dsl {
     #[doc(inline)]
-    pub use super::count::{count, count_star};
+    pub use super::coalesce::{coalesce, coalesce_many, coalesce_nullable};
     #[doc(inline)]
-    pub use super::exists::exists;
+    pub use super::count::{count, count_star, distinct};
+    #[doc(inline)]
+    pub use super::custom_order::custom_order;
+    #[doc(inline)]
+    pub use super::exists::{exists, not_exists};
     #[doc(inline)]
     pub use super::functions::aggregate_folding::*;
     #[doc(inline)]
@@ -57,6 +101,15 @@ pub mod dsl {
     #[doc(inline)]
     pub use super::functions::date_and_time::*;
     #[doc(inline)]
+    pub use super::functions::extract::{date_part, day, extract, hour, minute, month, second,
+                                        year};
+    #[doc(inline)]
+    pub use super::functions::numeric::{abs, ceil, floor, round, round_to};
+    #[doc(inline)]
+    pub use super::functions::random::{random, random_seeded};
+    #[doc(inline)]
+    pub use super::functions::string::{length, lower, upper};
+    #[doc(inline)]
     pub use super::not::not;
     #[doc(inline)]
     pub use super::sql_literal::sql;
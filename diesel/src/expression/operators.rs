@@ -314,17 +314,154 @@ diesel_postfix_operator!(Desc, " DESC", ());
 
 diesel_prefix_operator!(Not, "NOT ");
 
+/// NULL-safe equality, as returned by
+/// [`eq_nullable`](../expression_methods/trait.ExpressionMethods.html#method.eq_nullable).
+///
+/// Each backend spells this operator differently, so the SQL is chosen by the
+/// backend the query is executed on.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct EqNullable<T, U> {
+    left: T,
+    right: U,
+}
+
+impl<T, U> EqNullable<T, U> {
+    pub fn new(left: T, right: U) -> Self {
+        EqNullable {
+            left: left,
+            right: right,
+        }
+    }
+}
+
+impl_query_id!(EqNullable<T, U>);
+impl_selectable_expression!(EqNullable<T, U>);
+
+impl<T, U> Expression for EqNullable<T, U>
+where
+    T: Expression,
+    U: Expression,
+{
+    type SqlType = ::types::Bool;
+}
+
+impl<T, U> NonAggregate for EqNullable<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+{
+}
+
+#[cfg(feature = "postgres")]
+impl<T, U> QueryFragment<::pg::Pg> for EqNullable<T, U>
+where
+    T: QueryFragment<::pg::Pg>,
+    U: QueryFragment<::pg::Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" IS NOT DISTINCT FROM ");
+        self.right.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, U> QueryFragment<::sqlite::Sqlite> for EqNullable<T, U>
+where
+    T: QueryFragment<::sqlite::Sqlite>,
+    U: QueryFragment<::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        // `IS NOT DISTINCT FROM` was only added in SQLite 3.39, but `IS` has
+        // always had the same semantics.
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" IS ");
+        self.right.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<T, U> QueryFragment<::mysql::Mysql> for EqNullable<T, U>
+where
+    T: QueryFragment<::mysql::Mysql>,
+    U: QueryFragment<::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<::mysql::Mysql>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" <=> ");
+        self.right.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+macro_rules! nulls_ordering {
+    ($name:ident, $operator:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $name<T> {
+            expr: T,
+        }
+
+        impl<T> $name<T> {
+            pub fn new(expr: T) -> Self {
+                $name { expr: expr }
+            }
+        }
+
+        impl_query_id!($name<T>);
+        impl_selectable_expression!($name<T>);
+
+        impl<T: Expression> Expression for $name<T> {
+            type SqlType = ();
+        }
+
+        impl<T: NonAggregate> NonAggregate for $name<T> {}
+
+        #[cfg(feature = "postgres")]
+        impl<T> QueryFragment<::pg::Pg> for $name<T>
+        where
+            T: QueryFragment<::pg::Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                Ok(())
+            }
+        }
+
+        // Supported since SQLite 3.30. MySQL has no syntax for this at all,
+        // so there is deliberately no implementation for it.
+        #[cfg(feature = "sqlite")]
+        impl<T> QueryFragment<::sqlite::Sqlite> for $name<T>
+        where
+            T: QueryFragment<::sqlite::Sqlite>,
+        {
+            fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                Ok(())
+            }
+        }
+    }
+}
+
+nulls_ordering!(NullsFirst, " NULLS FIRST");
+nulls_ordering!(NullsLast, " NULLS LAST");
+
 use backend::Backend;
 use insertable::{ColumnInsertValue, Insertable};
-use query_source::Column;
+use query_source::{Column, WritableColumn};
 use query_builder::*;
 use result::QueryResult;
-use super::AppearsOnTable;
+use super::{AppearsOnTable, Expression, NonAggregate};
 
 impl<T, U, DB> Changeset<DB> for Eq<T, U>
 where
     DB: Backend,
-    T: Column,
+    T: WritableColumn,
     U: AppearsOnTable<T::Table> + QueryFragment<DB>,
 {
     fn is_noop(&self) -> bool {
@@ -340,7 +477,7 @@ where
 
 impl<T, U> AsChangeset for Eq<T, U>
 where
-    T: Column,
+    T: WritableColumn,
     U: AppearsOnTable<T::Table>,
 {
     type Target = T::Table;
@@ -351,6 +488,24 @@ where
     }
 }
 
+impl<T, U> Eq<T, U> {
+    #[cfg(feature = "postgres")]
+    pub(crate) fn into_parts(self) -> (T, U) {
+        (self.left, self.right)
+    }
+}
+
+impl<T, U> Insertable<T::Table> for Eq<T, U>
+where
+    T: Column,
+{
+    type Values = ColumnInsertValue<T, U>;
+
+    fn values(self) -> Self::Values {
+        ColumnInsertValue::Expression(self.left, self.right)
+    }
+}
+
 impl<'a, T, U> Insertable<T::Table> for &'a Eq<T, U>
 where
     T: Column + Copy,
@@ -361,3 +516,74 @@ where
         ColumnInsertValue::Expression(self.left, &self.right)
     }
 }
+
+macro_rules! boolean_test_operator {
+    ($name:ident, $operator:expr, sqlite: $sqlite_operator:expr) => {
+        /// A postfix operator which tests a boolean expression without
+        /// returning `NULL`.
+        ///
+        /// SQLite doesn't have `TRUE` or `FALSE` keywords on older versions,
+        /// so it compares against `1` and `0` instead.
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $name<Expr> {
+            expr: Expr,
+        }
+
+        impl<Expr> $name<Expr> {
+            pub fn new(expr: Expr) -> Self {
+                $name { expr: expr }
+            }
+        }
+
+        impl_query_id!($name<Expr>);
+        impl_selectable_expression!($name<Expr>);
+
+        impl<Expr: Expression> Expression for $name<Expr> {
+            type SqlType = ::types::Bool;
+        }
+
+        impl<Expr: NonAggregate> NonAggregate for $name<Expr> {}
+
+        #[cfg(feature = "postgres")]
+        impl<Expr> QueryFragment<::pg::Pg> for $name<Expr>
+        where
+            Expr: QueryFragment<::pg::Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "mysql")]
+        impl<Expr> QueryFragment<::mysql::Mysql> for $name<Expr>
+        where
+            Expr: QueryFragment<::mysql::Mysql>,
+        {
+            fn walk_ast(&self, mut out: AstPass<::mysql::Mysql>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        impl<Expr> QueryFragment<::sqlite::Sqlite> for $name<Expr>
+        where
+            Expr: QueryFragment<::sqlite::Sqlite>,
+        {
+            fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($sqlite_operator);
+                Ok(())
+            }
+        }
+    };
+}
+
+boolean_test_operator!(IsTrue, " IS TRUE", sqlite: " IS 1");
+boolean_test_operator!(IsNotTrue, " IS NOT TRUE", sqlite: " IS NOT 1");
+boolean_test_operator!(IsFalse, " IS FALSE", sqlite: " IS 0");
+boolean_test_operator!(IsNotFalse, " IS NOT FALSE", sqlite: " IS NOT 0");
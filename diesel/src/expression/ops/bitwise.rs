@@ -0,0 +1,124 @@
+use backend::Backend;
+use expression::{Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types;
+
+macro_rules! bitwise_operation_struct {
+    ($name:ident) => {
+        #[derive(Debug, Copy, Clone)]
+        pub struct $name<Lhs, Rhs> {
+            lhs: Lhs,
+            rhs: Rhs,
+        }
+
+        impl<Lhs, Rhs> $name<Lhs, Rhs> {
+            pub fn new(left: Lhs, right: Rhs) -> Self {
+                $name {
+                    lhs: left,
+                    rhs: right,
+                }
+            }
+        }
+
+        impl<Lhs, Rhs> Expression for $name<Lhs, Rhs> where
+            Lhs: Expression,
+            Lhs::SqlType: types::ops::Bitwise,
+            Rhs: Expression,
+        {
+            type SqlType = Lhs::SqlType;
+        }
+
+        impl_query_id!($name<Lhs, Rhs>);
+        impl_selectable_expression!($name<Lhs, Rhs>);
+
+        impl<Lhs, Rhs> NonAggregate for $name<Lhs, Rhs> where
+            Lhs: NonAggregate,
+            Rhs: NonAggregate,
+            $name<Lhs, Rhs>: Expression,
+        {
+        }
+    }
+}
+
+macro_rules! bitwise_operation {
+    ($name:ident, $op:expr) => {
+        bitwise_operation_struct!($name);
+
+        impl<Lhs, Rhs, DB> QueryFragment<DB> for $name<Lhs, Rhs> where
+            DB: Backend,
+            Lhs: QueryFragment<DB>,
+            Rhs: QueryFragment<DB>,
+        {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql("(");
+                self.lhs.walk_ast(out.reborrow())?;
+                out.push_sql($op);
+                self.rhs.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+    }
+}
+
+bitwise_operation!(BitAnd, " & ");
+bitwise_operation!(BitOr, " | ");
+bitwise_operation!(ShiftLeft, " << ");
+bitwise_operation!(ShiftRight, " >> ");
+bitwise_operation_struct!(BitXor);
+
+#[cfg(feature = "postgres")]
+impl<Lhs, Rhs> QueryFragment<::pg::Pg> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<::pg::Pg>,
+    Rhs: QueryFragment<::pg::Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" # ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<Lhs, Rhs> QueryFragment<::mysql::Mysql> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<::mysql::Mysql>,
+    Rhs: QueryFragment<::mysql::Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<::mysql::Mysql>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" ^ ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<Lhs, Rhs> QueryFragment<::sqlite::Sqlite> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<::sqlite::Sqlite>,
+    Rhs: QueryFragment<::sqlite::Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<::sqlite::Sqlite>) -> QueryResult<()> {
+        // SQLite has no XOR operator, so `a XOR b` is written as
+        // `(a | b) - (a & b)`. Both sides appear twice, and any bind
+        // parameters they contain are sent twice.
+        out.push_sql("((");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" | ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(") - (");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" & ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql("))");
+        Ok(())
+    }
+}
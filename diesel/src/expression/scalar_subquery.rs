@@ -0,0 +1,48 @@
+use backend::Backend;
+use expression::{Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types::IntoNullable;
+
+/// A subquery used as a single scalar value, as returned by
+/// [`single_value`](../../query_dsl/trait.SingleValueDsl.html#method.single_value).
+///
+/// The SQL type is always nullable, since the subquery may return no rows.
+#[derive(Debug, Clone, Copy)]
+pub struct ScalarSubquery<T>(T);
+
+impl<T> ScalarSubquery<T> {
+    pub(crate) fn new(query: T) -> Self {
+        ScalarSubquery(query)
+    }
+}
+
+impl<T> Expression for ScalarSubquery<T>
+where
+    T: SelectQuery,
+    T::SqlType: IntoNullable,
+{
+    type SqlType = <T::SqlType as IntoNullable>::Nullable;
+}
+
+impl<T> NonAggregate for ScalarSubquery<T>
+where
+    ScalarSubquery<T>: Expression,
+{
+}
+
+impl<T, DB> QueryFragment<DB> for ScalarSubquery<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(ScalarSubquery<T>);
+impl_selectable_expression!(ScalarSubquery<T>);
@@ -1,6 +1,8 @@
+use backend::Backend;
 use expression::grouped::Grouped;
+use expression::helper_types::BoxedPredicate;
 use expression::operators::{And, Or};
-use expression::{AsExpression, Expression};
+use expression::{AsExpression, BoxableExpression, Expression};
 use types::Bool;
 
 pub trait BoolExpressionMethods: Expression<SqlType = Bool> + Sized {
@@ -17,6 +19,63 @@ pub trait BoolExpressionMethods: Expression<SqlType = Bool> + Sized {
     fn or<T: AsExpression<Bool>>(self, other: T) -> Grouped<Or<Self, T::Expression>> {
         Grouped(Or::new(self, other.as_expression()))
     }
+
+    /// Boxes this predicate, so that it can be built separately from the
+    /// query it will be used in.
+    ///
+    /// This is useful for helper functions which build part of a `WHERE`
+    /// clause, as the type of the predicate no longer depends on how it was
+    /// constructed. Boxed predicates can be passed to `.filter`, and combined
+    /// with `and` and `or` like any other predicate (the result can be boxed
+    /// again). The table and backend usually need to be given explicitly, by
+    /// annotating the type as [`BoxedPredicate`].
+    ///
+    /// [`BoxedPredicate`]: ../helper_types/type.BoxedPredicate.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// use diesel::dsl::BoxedPredicate;
+    ///
+    /// fn id_above(min_id: i32) -> BoxedPredicate<'static, users::table, DB> {
+    ///     users::id.gt(min_id).into_boxed_predicate()
+    /// }
+    ///
+    /// fn name_isnt<'a>(excluded_name: &'a str) -> BoxedPredicate<'a, users::table, DB> {
+    ///     users::name.ne(excluded_name).into_boxed_predicate()
+    /// }
+    ///
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// #     diesel::insert_into(users::table)
+    /// #         .values(&users::name.eq("Jim"))
+    /// #         .execute(&connection)
+    /// #         .unwrap();
+    /// let predicate = id_above(1).and(name_isnt("Jim")).into_boxed_predicate();
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .filter(predicate)
+    ///     .load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+    /// # }
+    /// ```
+    fn into_boxed_predicate<'a, QS, DB>(self) -> BoxedPredicate<'a, QS, DB>
+    where
+        DB: Backend,
+        Self: BoxableExpression<QS, DB> + 'a,
+    {
+        Box::new(self)
+    }
 }
 
 impl<T: Expression<SqlType = Bool>> BoolExpressionMethods for T {}
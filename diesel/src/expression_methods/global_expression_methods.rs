@@ -1,7 +1,8 @@
 use expression::{nullable, AsExpression, Expression};
 use expression::array_comparison::{AsInExpression, In, NotIn};
+use expression::in_range::AsRangeBounds;
 use expression::operators::*;
-use types::SingleValue;
+use types::{Bool, IntoNullable, Nullable, SingleValue};
 
 pub trait ExpressionMethods: Expression + Sized {
     /// Creates a SQL `=` expression.
@@ -87,6 +88,41 @@ pub trait ExpressionMethods: Expression + Sized {
     /// assert_eq!(Ok(vec![]), data.load::<i32>(&connection));
     /// # }
     /// ```
+    ///
+    /// A select statement which selects a single expression of the same SQL
+    /// type can be passed instead of a collection. This generates
+    /// `lhs IN (SELECT ...)`, and the values are never loaded into Rust. The
+    /// subquery may reference tables of the outer query.
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # table! {
+    /// #     posts {
+    /// #         id -> Integer,
+    /// #         user_id -> Integer,
+    /// #         title -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let authors = posts::table.select(posts::user_id);
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.eq_any(authors))
+    ///     .load::<String>(&connection);
+    /// assert_eq!(Ok(vec![String::from("Sean"), String::from("Tess")]), names);
+    /// # }
+    /// ```
     fn eq_any<T>(self, values: T) -> In<Self, T::InExpression>
     where
         T: AsInExpression<Self::SqlType>,
@@ -99,6 +135,9 @@ pub trait ExpressionMethods: Expression + Sized {
     /// `ne(any())` instead. This method may change in the future to
     /// automatically perform `!= ANY` on PostgreSQL.
     ///
+    /// Like [`eq_any`](#method.eq_any), this also accepts a select statement,
+    /// generating `lhs NOT IN (SELECT ...)`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -145,6 +184,100 @@ pub trait ExpressionMethods: Expression + Sized {
         IsNotNull::new(self)
     }
 
+    /// Creates a SQL `IS TRUE` expression.
+    ///
+    /// Unlike `.eq(true)`, this evaluates to `false` rather than `NULL` when
+    /// the value is `NULL`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::types::{Bool, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     let connection = establish_connection();
+    /// let is_true = diesel::select((
+    ///     sql::<Nullable<Bool>>("NULL").is_true(),
+    ///     sql::<Nullable<Bool>>("NULL").eq(true).is_null(),
+    /// ));
+    /// assert_eq!(Ok((false, true)), is_true.get_result(&connection));
+    /// # }
+    /// ```
+    fn is_true(self) -> IsTrue<Self>
+    where
+        Self::SqlType: IntoNullable<Nullable = Nullable<Bool>>,
+    {
+        IsTrue::new(self)
+    }
+
+    /// Creates a SQL `IS NOT TRUE` expression.
+    ///
+    /// This is `true` when the value is `false` or `NULL`.
+    fn is_not_true(self) -> IsNotTrue<Self>
+    where
+        Self::SqlType: IntoNullable<Nullable = Nullable<Bool>>,
+    {
+        IsNotTrue::new(self)
+    }
+
+    /// Creates a SQL `IS FALSE` expression.
+    ///
+    /// Unlike `.eq(false)`, this evaluates to `false` rather than `NULL` when
+    /// the value is `NULL`.
+    fn is_false(self) -> IsFalse<Self>
+    where
+        Self::SqlType: IntoNullable<Nullable = Nullable<Bool>>,
+    {
+        IsFalse::new(self)
+    }
+
+    /// Creates a SQL `IS NOT FALSE` expression.
+    ///
+    /// This is `true` when the value is `true` or `NULL`.
+    fn is_not_false(self) -> IsNotFalse<Self>
+    where
+        Self::SqlType: IntoNullable<Nullable = Nullable<Bool>>,
+    {
+        IsNotFalse::new(self)
+    }
+
+    /// Creates a NULL-safe equality expression.
+    ///
+    /// Unlike [`eq`](#method.eq), this returns `true` when both sides are
+    /// `NULL`, and `false` (never `NULL`) when only one side is. The SQL
+    /// generated depends on the backend: `IS NOT DISTINCT FROM` on PostgreSQL,
+    /// `IS` on SQLite, and `<=>` on MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// use diesel::dsl::sql;
+    /// use diesel::types::{Integer, Nullable};
+    ///
+    /// let null = || sql::<Nullable<Integer>>("NULL");
+    ///
+    /// let both_null = diesel::select(null().eq_nullable(None::<i32>));
+    /// assert_eq!(Ok(true), both_null.get_result(&connection));
+    ///
+    /// let one_null = diesel::select(null().eq_nullable(Some(1)));
+    /// assert_eq!(Ok(false), one_null.get_result(&connection));
+    /// # }
+    /// ```
+    fn eq_nullable<T: AsExpression<Self::SqlType>>(
+        self,
+        other: T,
+    ) -> EqNullable<Self, T::Expression> {
+        EqNullable::new(self, other.as_expression())
+    }
+
     /// Creates a SQL `>` expression.
     ///
     /// # Example
@@ -270,6 +403,47 @@ pub trait ExpressionMethods: Expression + Sized {
         )
     }
 
+    /// Creates an expression which checks that this value is within the given
+    /// Rust range.
+    ///
+    /// Unlike [`between`](#method.between), which is always inclusive, this
+    /// excludes the end of the range. `start..end` generates
+    /// `self >= start AND self < end`. Half-open ranges avoid off-by-one
+    /// errors when querying a range of dates or timestamps, such as
+    /// everything which happened on a given day.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("INSERT INTO users (name) VALUES ('Jim')").unwrap();
+    /// let data = users.select(name).filter(id.in_range(1..3)).order(id);
+    /// assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), data.load(&connection));
+    ///
+    /// let data = users.select(name).filter(id.in_range(2..4)).order(id);
+    /// assert_eq!(Ok(vec!["Tess".to_string(), "Jim".to_string()]), data.load(&connection));
+    /// # }
+    /// ```
+    fn in_range<R>(self, range: R) -> R::Output
+    where
+        Self: Clone,
+        R: AsRangeBounds<Self>,
+    {
+        range.as_range_bounds(self)
+    }
+
     /// Creates a SQL `DESC` expression, representing this expression in
     /// descending order.
     fn desc(self) -> Desc<Self> {
@@ -0,0 +1,85 @@
+use expression::{AsExpression, Expression};
+use expression::ops::{BitAnd, BitOr, BitXor, ShiftLeft, ShiftRight};
+use types::Integer;
+use types::ops::Bitwise;
+
+pub trait IntegerExpressionMethods: Expression + Sized {
+    /// Creates a SQL `&` (bitwise and) expression.
+    ///
+    /// The result has the same type as `self`, so it can be compared against
+    /// a value to test whether a bit is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let odd_ids = users.select(id).filter(id.bit_and(1).ne(0)).load(&connection);
+    /// assert_eq!(Ok(vec![1]), odd_ids);
+    /// # }
+    /// ```
+    fn bit_and<T>(self, other: T) -> BitAnd<Self, T::Expression>
+    where
+        Self::SqlType: Bitwise,
+        T: AsExpression<Self::SqlType>,
+    {
+        BitAnd::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `|` (bitwise or) expression.
+    fn bit_or<T>(self, other: T) -> BitOr<Self, T::Expression>
+    where
+        Self::SqlType: Bitwise,
+        T: AsExpression<Self::SqlType>,
+    {
+        BitOr::new(self, other.as_expression())
+    }
+
+    /// Creates a bitwise exclusive or expression.
+    ///
+    /// This is written as `#` on PostgreSQL and `^` on MySQL. SQLite has no
+    /// XOR operator, so it is written as `(a | b) - (a & b)` there.
+    fn bit_xor<T>(self, other: T) -> BitXor<Self, T::Expression>
+    where
+        Self::SqlType: Bitwise,
+        T: AsExpression<Self::SqlType>,
+    {
+        BitXor::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `<<` (bitwise shift left) expression.
+    fn shift_left<T>(self, bits: T) -> ShiftLeft<Self, T::Expression>
+    where
+        Self::SqlType: Bitwise,
+        T: AsExpression<Integer>,
+    {
+        ShiftLeft::new(self, bits.as_expression())
+    }
+
+    /// Creates a SQL `>>` (bitwise shift right) expression.
+    fn shift_right<T>(self, bits: T) -> ShiftRight<Self, T::Expression>
+    where
+        Self::SqlType: Bitwise,
+        T: AsExpression<Integer>,
+    {
+        ShiftRight::new(self, bits.as_expression())
+    }
+}
+
+impl<T> IntegerExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: Bitwise,
+{
+}
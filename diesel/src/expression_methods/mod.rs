@@ -7,6 +7,8 @@
 pub mod bool_expression_methods;
 pub mod escape_expression_methods;
 pub mod global_expression_methods;
+pub mod integer_expression_methods;
+pub mod sort_expression_methods;
 pub mod text_expression_methods;
 #[doc(hidden)]
 pub mod eq_all;
@@ -18,6 +20,10 @@ pub use self::escape_expression_methods::EscapeExpressionMethods;
 #[doc(inline)]
 pub use self::global_expression_methods::{ExpressionMethods, NullableExpressionMethods};
 #[doc(inline)]
+pub use self::integer_expression_methods::IntegerExpressionMethods;
+#[doc(inline)]
+pub use self::sort_expression_methods::SortExpressionMethods;
+#[doc(inline)]
 pub use self::text_expression_methods::TextExpressionMethods;
 #[doc(hidden)]
 pub use self::eq_all::EqAll;
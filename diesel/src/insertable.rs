@@ -3,7 +3,7 @@ use expression::{AppearsOnTable, Expression};
 use result::QueryResult;
 use query_builder::{AstPass, QueryBuilder, QueryFragment};
 use query_builder::insert_statement::UndecoratedInsertRecord;
-use query_source::{Column, Table};
+use query_source::{Table, WritableColumn};
 #[cfg(feature = "sqlite")]
 use sqlite::Sqlite;
 
@@ -77,6 +77,20 @@ pub trait InsertValues<T: Table, DB: Backend> {
     fn requires_parenthesis(&self) -> bool {
         true
     }
+
+    /// Whether the values are preceded by `VALUES`. Types which render a
+    /// query (such as `SELECT * FROM unnest(...)`) return `false`.
+    #[doc(hidden)]
+    fn requires_values_keyword(&self) -> bool {
+        true
+    }
+
+    /// SQL which is placed directly after `INSERT`, such as `OR IGNORE` on
+    /// SQLite.
+    #[doc(hidden)]
+    fn walk_insert_modifier(&self, _out: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -94,7 +108,7 @@ impl<Col, Expr> Default for ColumnInsertValue<Col, Expr> {
 impl<Col, Expr, DB> InsertValues<Col::Table, DB> for ColumnInsertValue<Col, Expr>
 where
     DB: Backend + SupportsDefaultKeyword,
-    Col: Column,
+    Col: WritableColumn,
     Expr: Expression<SqlType = Col::SqlType> + QueryFragment<DB> + AppearsOnTable<()>,
 {
     fn column_names(&self, out: &mut DB::QueryBuilder) -> QueryResult<()> {
@@ -119,7 +133,7 @@ where
 #[cfg(feature = "sqlite")]
 impl<Col, Expr> InsertValues<Col::Table, Sqlite> for ColumnInsertValue<Col, Expr>
 where
-    Col: Column,
+    Col: WritableColumn,
     Expr: Expression<SqlType = Col::SqlType> + QueryFragment<Sqlite> + AppearsOnTable<()>,
 {
     fn column_names(&self, out: &mut <Sqlite as Backend>::QueryBuilder) -> QueryResult<()> {
@@ -210,3 +224,59 @@ where
         false
     }
 }
+
+/// The values of several records which were collected from an iterator by
+/// [`values_from_iter`].
+///
+/// Since a query may be walked several times before it is executed, the
+/// values for each record are buffered here. The records themselves are not
+/// copied.
+///
+/// [`values_from_iter`]: ../query_builder/struct.IncompleteInsertStatement.html#method.values_from_iter
+#[derive(Debug, Clone)]
+pub struct BatchInsertValues<V> {
+    pub(crate) records: Vec<V>,
+}
+
+impl<V> BatchInsertValues<V> {
+    pub(crate) fn new(records: Vec<V>) -> Self {
+        BatchInsertValues { records }
+    }
+}
+
+impl<V, DB> CanInsertInSingleQuery<DB> for BatchInsertValues<V>
+where
+    DB: Backend + SupportsDefaultKeyword,
+{
+    fn rows_to_insert(&self) -> usize {
+        self.records.len()
+    }
+}
+
+impl<V, Tab, DB> InsertValues<Tab, DB> for BatchInsertValues<V>
+where
+    Tab: Table,
+    DB: Backend + SupportsDefaultKeyword,
+    V: InsertValues<Tab, DB>,
+{
+    fn column_names(&self, out: &mut DB::QueryBuilder) -> QueryResult<()> {
+        self.records
+            .get(0)
+            .expect("Tried to read column names from empty list of rows")
+            .column_names(out)
+    }
+
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        for (i, record) in self.records.iter().enumerate() {
+            if i != 0 {
+                out.push_sql("), (");
+            }
+            record.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+
+    fn is_noop(&self) -> bool {
+        false
+    }
+}
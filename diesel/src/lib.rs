@@ -85,6 +85,7 @@ pub mod helper_types {
     //! DslName<OtherTypes>>::Output`. So the return type of
     //! `users.filter(first_name.eq("John")).order(last_name.asc()).limit(10)` would
     //! be `Limit<Order<FindBy<users, first_name, &str>, Asc<last_name>>>`
+    use super::query_builder::{combination_clause, AsQuery, CombinationClause};
     use super::query_dsl::*;
     use super::query_source::joins;
 
@@ -103,18 +104,71 @@ pub mod helper_types {
     /// Represents the return type of `.for_update()`
     pub type ForUpdate<Source> = <Source as ForUpdateDsl>::Output;
 
+    /// Represents the return type of `.for_share()`
+    pub type ForShare<Source> = <Source as ForShareDsl>::Output;
+
+    /// Represents the return type of `.skip_locked()`
+    pub type SkipLocked<Source> = <Source as SkipLockedDsl>::Output;
+
+    /// Represents the return type of `.no_wait()`
+    pub type NoWait<Source> = <Source as NoWaitDsl>::Output;
+
     /// Represents the return type of `.find(pk)`
     pub type Find<Source, PK> = <Source as FindDsl<PK>>::Output;
 
     /// Represents the return type of `.order(ordering)`
     pub type Order<Source, Ordering> = <Source as OrderDsl<Ordering>>::Output;
 
+    /// Represents the return type of `.then_order_by(ordering)`
+    pub type ThenOrderBy<Source, Ordering> = <Source as ThenOrderDsl<Ordering>>::Output;
+
+    /// Represents the return type of `.distinct_on(expr)`
+    pub type DistinctOn<Source, Expr> = <Source as DistinctOnDsl<Expr>>::Output;
+
     /// Represents the return type of `.limit()`
     pub type Limit<Source> = <Source as LimitDsl>::Output;
 
+    /// Represents the return type of `.limit_with_ties()`
+    pub type LimitWithTies<Source> = <Source as LimitWithTiesDsl>::Output;
+
     /// Represents the return type of `.offset()`
     pub type Offset<Source> = <Source as OffsetDsl>::Output;
 
+    /// Represents the return type of `.positional_order_by(ordering)`
+    pub type PositionalOrderBy<Source, Ordering> =
+        <Source as PositionalOrderDsl<Ordering>>::Output;
+
+    /// Represents the return type of `.union(rhs)`
+    pub type Union<Source, Rhs> = CombinationClause<
+        combination_clause::Union,
+        <Source as CombineDsl>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.union_all(rhs)`
+    pub type UnionAll<Source, Rhs> = CombinationClause<
+        combination_clause::UnionAll,
+        <Source as CombineDsl>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.intersect(rhs)`
+    pub type Intersect<Source, Rhs> = CombinationClause<
+        combination_clause::Intersect,
+        <Source as CombineDsl>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.except(rhs)`
+    pub type Except<Source, Rhs> = CombinationClause<
+        combination_clause::Except,
+        <Source as CombineDsl>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.single_value()`
+    pub type SingleValue<Source> = <Source as SingleValueDsl>::Output;
+
     /// Represents the return type of `.inner_join(rhs)`
     pub type InnerJoin<Source, Rhs> = <Source as JoinWithImplicitOnClause<
         Rhs,
@@ -140,7 +194,7 @@ pub mod helper_types {
 pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using Diesel.
     pub use associations::{GroupedBy, Identifiable};
-    pub use connection::Connection;
+    pub use connection::{AsPrepared, Connection};
     pub use expression::{AppearsOnTable, BoxableExpression, Expression, SelectableExpression};
     pub use expression_methods::*;
     #[doc(inline)]
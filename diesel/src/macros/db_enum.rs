@@ -0,0 +1,165 @@
+/// Implements the traits needed to use a fieldless enum as a `SmallInt` or
+/// `Integer` value, stored as the enum's discriminant.
+///
+/// `repr` is the integer type matching `sql_type` (`i16` for `SmallInt`, and
+/// `i32` for `Integer`). Loading a value which doesn't match the discriminant
+/// of any variant returns an error which includes the value. The enum must
+/// also implement `Debug`, which is required by `ToSql`.
+///
+/// This macro is used by `#[derive(DbEnum)]` from `diesel_codegen`, which
+/// reads the integer type from a `#[diesel(repr = "i16")]` attribute on the
+/// enum, and rejects discriminants which don't fit in it.
+///
+/// # Example
+///
+/// ```no_run
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Status {
+///     Draft = 1,
+///     Published = 2,
+/// }
+///
+/// impl_DbEnum! {
+///     enum_name = Status,
+///     repr = i16,
+///     sql_type = SmallInt,
+///     variants = (Draft, Published),
+/// }
+///
+/// # fn main() {
+/// #     use diesel::dsl::sql;
+/// #     use diesel::types::SmallInt;
+/// #     let connection = establish_connection();
+/// let status = diesel::select(sql::<SmallInt>("2")).get_result(&connection);
+/// assert_eq!(Ok(Status::Published), status);
+///
+/// let unknown = diesel::select(sql::<SmallInt>("3")).get_result::<Status>(&connection);
+/// assert!(unknown.is_err());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_DbEnum {
+    (
+        enum_name = $enum_name:ident,
+        repr = $repr:ty,
+        sql_type = $sql_type:ident,
+        variants = ($($variant:ident),+ $(,)*),
+    ) => {
+        impl $crate::expression::AsExpression<$crate::types::$sql_type> for $enum_name {
+            type Expression = $crate::expression::bound::Bound<$crate::types::$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+
+        impl<'expr> $crate::expression::AsExpression<$crate::types::$sql_type> for &'expr $enum_name {
+            type Expression = $crate::expression::bound::Bound<$crate::types::$sql_type, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+
+        impl $crate::expression::AsExpression<$crate::types::Nullable<$crate::types::$sql_type>>
+            for $enum_name
+        {
+            type Expression = $crate::expression::bound::Bound<
+                $crate::types::Nullable<$crate::types::$sql_type>,
+                Self,
+            >;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+
+        impl<'expr> $crate::expression::AsExpression<$crate::types::Nullable<$crate::types::$sql_type>>
+            for &'expr $enum_name
+        {
+            type Expression = $crate::expression::bound::Bound<
+                $crate::types::Nullable<$crate::types::$sql_type>,
+                Self,
+            >;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+
+        impl<DB> $crate::types::ToSql<$crate::types::$sql_type, DB> for $enum_name where
+            DB: $crate::backend::Backend + $crate::types::HasSqlType<$crate::types::$sql_type>,
+            $repr: $crate::types::ToSql<$crate::types::$sql_type, DB>,
+        {
+            fn to_sql<W: ::std::io::Write>(
+                &self,
+                out: &mut $crate::types::ToSqlOutput<W, DB>,
+            ) -> Result<$crate::types::IsNull, Box<::std::error::Error + Send + Sync>> {
+                let discriminant = match *self {
+                    $($enum_name::$variant => $enum_name::$variant as $repr,)+
+                };
+                $crate::types::ToSql::<$crate::types::$sql_type, DB>::to_sql(&discriminant, out)
+            }
+        }
+
+        impl<DB> $crate::types::ToSql<$crate::types::Nullable<$crate::types::$sql_type>, DB>
+            for $enum_name where
+            DB: $crate::backend::Backend + $crate::types::HasSqlType<$crate::types::$sql_type>,
+            $enum_name: $crate::types::ToSql<$crate::types::$sql_type, DB>,
+        {
+            fn to_sql<W: ::std::io::Write>(
+                &self,
+                out: &mut $crate::types::ToSqlOutput<W, DB>,
+            ) -> Result<$crate::types::IsNull, Box<::std::error::Error + Send + Sync>> {
+                $crate::types::ToSql::<$crate::types::$sql_type, DB>::to_sql(self, out)
+            }
+        }
+
+        impl<DB> $crate::types::FromSql<$crate::types::$sql_type, DB> for $enum_name where
+            DB: $crate::backend::Backend + $crate::types::HasSqlType<$crate::types::$sql_type>,
+            $repr: $crate::types::FromSql<$crate::types::$sql_type, DB>,
+        {
+            fn from_sql(
+                bytes: Option<&DB::RawValue>,
+            ) -> Result<Self, Box<::std::error::Error + Send + Sync>> {
+                let value =
+                    <$repr as $crate::types::FromSql<$crate::types::$sql_type, DB>>::from_sql(bytes)?;
+                $(
+                    if value == $enum_name::$variant as $repr {
+                        return Ok($enum_name::$variant);
+                    }
+                )+
+                Err(format!(
+                    "Unrecognized discriminant {} for `{}`",
+                    value,
+                    stringify!($enum_name)
+                ).into())
+            }
+        }
+
+        impl<DB> $crate::types::FromSqlRow<$crate::types::$sql_type, DB> for $enum_name where
+            DB: $crate::backend::Backend + $crate::types::HasSqlType<$crate::types::$sql_type>,
+            $enum_name: $crate::types::FromSql<$crate::types::$sql_type, DB>,
+        {
+            fn build_from_row<R: $crate::row::Row<DB>>(
+                row: &mut R,
+            ) -> Result<Self, Box<::std::error::Error + Send + Sync>> {
+                $crate::types::FromSql::<$crate::types::$sql_type, DB>::from_sql(row.take())
+            }
+        }
+
+        impl<DB> $crate::query_source::Queryable<$crate::types::$sql_type, DB> for $enum_name where
+            DB: $crate::backend::Backend + $crate::types::HasSqlType<$crate::types::$sql_type>,
+            $enum_name: $crate::types::FromSqlRow<$crate::types::$sql_type, DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self::Row) -> Self {
+                row
+            }
+        }
+    }
+}
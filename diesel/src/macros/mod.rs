@@ -1,7 +1,7 @@
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __diesel_column {
-    ($($table:ident)::*, $column_name:ident -> ($($Type:tt)*),  $sql_name:expr, $($doc:expr),*) => {
+    ($($table:ident)::*, $column_name:ident -> ($($Type:tt)*),  $sql_name:expr, [$($generated:tt)*], $($doc:expr),*) => {
         $(
             #[doc=$doc]
         )*
@@ -71,6 +71,8 @@ macro_rules! __diesel_column {
             const NAME: &'static str = $sql_name;
         }
 
+        __diesel_writable_column!([$($generated)*], $column_name);
+
         impl<T> $crate::EqAll<T> for $column_name where
             T: $crate::expression::AsExpression<$($Type)*>,
             $crate::dsl::Eq<$column_name, T>: $crate::Expression<SqlType=$crate::types::Bool>,
@@ -87,6 +89,16 @@ macro_rules! __diesel_column {
     }
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diesel_writable_column {
+    ([], $column_name:ident) => {
+        impl $crate::query_source::WritableColumn for $column_name {}
+    };
+
+    ([generated], $column_name:ident) => {};
+}
+
 /// Specifies that a table exists, and what columns it has. This will create a
 /// new public module, with the same name, as the name of the table. In this
 /// module, you'll find a unit struct named `table`, and a unit struct with the
@@ -214,6 +226,27 @@ macro_rules! __diesel_column {
 /// # fn main() {}
 /// ```
 ///
+/// Columns which are computed by the database, such as generated columns on
+/// PostgreSQL, MySQL and SQLite, can be marked with the `generated` attribute.
+/// These columns can be selected and used in filters like any other column, but
+/// they cannot be assigned in an insert or update.
+///
+/// ```
+/// #[macro_use] extern crate diesel;
+///
+/// table! {
+///     line_items {
+///         id -> Integer,
+///         quantity -> Integer,
+///         unit_price -> Integer,
+///         /// Defined as `GENERATED ALWAYS AS (quantity * unit_price) STORED`
+///         #[generated]
+///         total -> Integer,
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
 /// This module will also contain several helper types:
 ///
 /// dsl
@@ -427,11 +460,12 @@ macro_rules! table_body {
         table_name = $name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)*],
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [$($column_doc:expr)*],
         current_column_sql_name = [$($current_column_sql_name:expr)*],
+        current_column_generated = [$($current_column_generated:tt)*],
         #[doc=$new_doc:expr]
         $($body:tt)*
     ) => {
@@ -440,11 +474,12 @@ macro_rules! table_body {
             table_name = $name,
             primary_key_ty = $primary_key_ty,
             primary_key_expr = $primary_key_expr,
-            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name,)*],
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*],
             imports = ($($($import)::+),+),
             table_doc = [$($table_doc)*],
             current_column_doc = [$($column_doc)*$new_doc],
             current_column_sql_name = [$($current_column_sql_name)*],
+            current_column_generated = [$($current_column_generated)*],
             $($body)*
         }
     };
@@ -456,11 +491,12 @@ macro_rules! table_body {
         table_name = $name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)*],
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [$($column_doc:expr)*],
         current_column_sql_name = [],
+        current_column_generated = [$($current_column_generated:tt)*],
         #[sql_name=$new_sql_name:expr]
         $($body:tt)*
     ) => {
@@ -469,11 +505,43 @@ macro_rules! table_body {
             table_name = $name,
             primary_key_ty = $primary_key_ty,
             primary_key_expr = $primary_key_expr,
-            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name,)*],
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*],
             imports = ($($($import)::+),+),
             table_doc = [$($table_doc)*],
             current_column_doc = [$($column_doc)*],
             current_column_sql_name = [$new_sql_name],
+            current_column_generated = [$($current_column_generated)*],
+            $($body)*
+        }
+    };
+
+    // Parse the generated attribute and forward the remaining table body to further instances of
+    // this macro
+    (
+        schema_name = $schema_name:ident,
+        table_name = $name:ident,
+        primary_key_ty = $primary_key_ty:ty,
+        primary_key_expr = $primary_key_expr:expr,
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
+        imports = ($($($import:tt)::+),+),
+        table_doc = [$($table_doc:expr)*],
+        current_column_doc = [$($column_doc:expr)*],
+        current_column_sql_name = [$($current_column_sql_name:expr)*],
+        current_column_generated = [],
+        #[generated]
+        $($body:tt)*
+    ) => {
+        table_body! {
+            schema_name = $schema_name,
+            table_name = $name,
+            primary_key_ty = $primary_key_ty,
+            primary_key_expr = $primary_key_expr,
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*],
+            imports = ($($($import)::+),+),
+            table_doc = [$($table_doc)*],
+            current_column_doc = [$($column_doc)*],
+            current_column_sql_name = [$($current_column_sql_name)*],
+            current_column_generated = [generated],
             $($body)*
         }
     };
@@ -490,11 +558,12 @@ macro_rules! table_body {
         table_name = $name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)*],
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [$($column_doc:expr)*],
         current_column_sql_name = [$new_sql_name:expr],
+        current_column_generated = [$($new_generated:tt)*],
         $new_column_name:ident -> $($ty_path:tt)::* $(<$($ty_params:tt)::*>)*,
         $($body:tt)*
     ) => {
@@ -503,12 +572,13 @@ macro_rules! table_body {
             table_name = $name,
             primary_key_ty = $primary_key_ty,
             primary_key_expr = $primary_key_expr,
-            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name,)*
-                       $new_column_name -> ($($ty_path)::*$(<$($ty_params)::*>)*); doc = [$($column_doc)*]; sql_name = $new_sql_name,],
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*
+                       $new_column_name -> ($($ty_path)::*$(<$($ty_params)::*>)*); doc = [$($column_doc)*]; sql_name = $new_sql_name; generated = [$($new_generated)*],],
             imports = ($($($import)::+),+),
             table_doc = [$($table_doc)*],
             current_column_doc = [],
             current_column_sql_name = [],
+            current_column_generated = [],
             $($body)*
         }
     };
@@ -522,11 +592,12 @@ macro_rules! table_body {
         table_name = $name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)*],
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [$($column_doc:expr)*],
         current_column_sql_name = [$new_sql_name:expr],
+        current_column_generated = [$($new_generated:tt)*],
         $new_column_name:ident -> $new_column_ty:ty,
         $($body:tt)*
     ) => {
@@ -535,12 +606,13 @@ macro_rules! table_body {
             table_name = $name,
             primary_key_ty = $primary_key_ty,
             primary_key_expr = $primary_key_expr,
-            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name,)*
-                       $new_column_name -> ($new_column_ty); doc = [$($column_doc)*]; sql_name = $new_sql_name,],
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*
+                       $new_column_name -> ($new_column_ty); doc = [$($column_doc)*]; sql_name = $new_sql_name; generated = [$($new_generated)*],],
             imports = ($($($import)::+),+),
             table_doc = [$($table_doc)*],
             current_column_doc = [],
             current_column_sql_name = [],
+            current_column_generated = [],
             $($body)*
         }
     };
@@ -565,6 +637,7 @@ macro_rules! table_body {
             table_doc = [$($table_doc)*],
             current_column_doc = [],
             current_column_sql_name = [],
+            current_column_generated = [],
             $($body)+
         }
     };
@@ -575,11 +648,12 @@ macro_rules! table_body {
         table_name = $name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)*],
+        columns = [$($column_name:ident -> $Type:tt; doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)*],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [$($column_doc:expr)*],
         current_column_sql_name = [],
+        current_column_generated = [$($current_column_generated:tt)*],
         $new_column_name:ident ->
         $($body:tt)*
     ) => {
@@ -588,11 +662,12 @@ macro_rules! table_body {
             table_name = $name,
             primary_key_ty = $primary_key_ty,
             primary_key_expr = $primary_key_expr,
-            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name,)*],
+            columns = [$($column_name -> $Type; doc = [$($doc)*]; sql_name = $sql_name; generated = [$($generated)*],)*],
             imports = ($($($import)::+),+),
             table_doc = [$($table_doc)*],
             current_column_doc = [$($column_doc)*],
             current_column_sql_name = [stringify!($new_column_name)],
+            current_column_generated = [$($current_column_generated)*],
             $new_column_name -> $($body)*
         }
     };
@@ -614,6 +689,7 @@ macro_rules! table_body {
             table_doc = [$($table_doc)*],
             current_column_doc = [],
             current_column_sql_name = [],
+            current_column_generated = [],
             $($body)+
         }
     };
@@ -625,11 +701,12 @@ macro_rules! table_body {
         table_name = $table_name:ident,
         primary_key_ty = $primary_key_ty:ty,
         primary_key_expr = $primary_key_expr:expr,
-        columns = [$($column_name:ident -> ($($column_ty:tt)*); doc = [$($doc:expr)*]; sql_name = $sql_name:expr,)+],
+        columns = [$($column_name:ident -> ($($column_ty:tt)*); doc = [$($doc:expr)*]; sql_name = $sql_name:expr; generated = [$($generated:tt)*],)+],
         imports = ($($($import:tt)::+),+),
         table_doc = [$($table_doc:expr)*],
         current_column_doc = [],
         current_column_sql_name = [],
+        current_column_generated = [],
     ) => {
         $(
             #[doc=$table_doc]
@@ -843,7 +920,7 @@ macro_rules! table_body {
                 impl AppearsOnTable<table> for star {
                 }
 
-                $(__diesel_column!(table, $column_name -> ($($column_ty)*), $sql_name, $($doc),*);)+
+                $(__diesel_column!(table, $column_name -> ($($column_ty)*), $sql_name, [$($generated)*], $($doc),*);)+
             }
         }
     }
@@ -994,6 +1071,69 @@ macro_rules! enable_multi_table_joins {
     }
 }
 
+/// Declares an alias of a table, which is rendered as `table AS alias`. This
+/// allows a table to appear more than once in the same query, most commonly
+/// to join a table to itself.
+///
+/// The macro takes the path to the module generated by [`table!`] and the
+/// name of the alias, and returns an [`Alias`]. The columns of the table can
+/// be accessed through the alias with [`Alias::field`], and are distinct from
+/// the columns of the table itself. Both can be used in the `SELECT`, `WHERE`
+/// and `ON` clauses of the same query.
+///
+/// Only a single alias can currently appear in a query.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// let other_users = alias!(users as other_users);
+/// let pairs = users::table
+///     .inner_join(other_users.on(other_users.field(users::id).gt(users::id)))
+///     .select((users::name, other_users.field(users::name)))
+///     .load(&connection);
+/// let expected = vec![(String::from("Sean"), String::from("Tess"))];
+/// assert_eq!(Ok(expected), pairs);
+/// # }
+/// ```
+///
+/// [`table!`]: macro.table.html
+/// [`Alias`]: query_source/struct.Alias.html
+/// [`Alias::field`]: query_source/struct.Alias.html#method.field
+#[macro_export]
+macro_rules! alias {
+    ($($table:ident)::+ as $alias:ident) => {{
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy)]
+        struct $alias;
+
+        impl $crate::query_source::AliasSource for $alias {
+            type Target = $($table)::+::table;
+
+            const NAME: &'static str = stringify!($alias);
+
+            fn target(&self) -> Self::Target {
+                $($table)::+::table
+            }
+        }
+
+        impl_query_id!($alias);
+
+        $crate::query_source::Alias::new($alias)
+    }}
+}
+
 /// Takes a query `QueryFragment` expression as an argument and returns a string
 /// of SQL with placeholders for the dynamic values.
 ///
@@ -1091,6 +1231,8 @@ mod as_changeset;
 #[macro_use]
 mod associations;
 #[macro_use]
+mod db_enum;
+#[macro_use]
 mod identifiable;
 #[macro_use]
 mod insertable;
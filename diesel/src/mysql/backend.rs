@@ -35,6 +35,9 @@ impl Backend for Mysql {
     type BindCollector = RawBytesBindCollector<Mysql>;
     type RawValue = [u8];
     type ByteOrder = NativeEndian;
+
+    // The largest possible limit, as recommended by the MySQL documentation
+    const LIMIT_FOR_OFFSET_WITHOUT_LIMIT: &'static str = " LIMIT 18446744073709551615";
 }
 
 impl TypeMetadata for Mysql {
@@ -3,6 +3,8 @@ mod raw;
 mod stmt;
 mod url;
 
+use std::iter::FromIterator;
+
 use connection::*;
 use query_builder::*;
 use query_builder::bind_collector::RawBytesBindCollector;
@@ -65,19 +67,45 @@ impl Connection for MysqlConnection {
         T::Query: QueryFragment<Self::Backend> + QueryId,
         Self::Backend: HasSqlType<T::SqlType>,
         U: Queryable<T::SqlType, Self::Backend>,
+    {
+        self.query_by_index_map(source, |row: U| row)
+    }
+
+    #[doc(hidden)]
+    fn query_by_index_map<T, U, F, R, C>(&self, source: T, mut f: F) -> QueryResult<C>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        Self::Backend: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Self::Backend>,
+        F: FnMut(U) -> R,
+        C: FromIterator<R>,
     {
         use result::Error::DeserializationError;
         use types::FromSqlRow;
 
-        let mut stmt = try!(self.prepare_query(&source.as_query()));
+        let source = source.as_query();
+        let mut stmt = try!(self.prepare_query(&source));
         let mut metadata = Vec::new();
         Mysql::row_metadata(&mut metadata, &());
         let results = unsafe { stmt.results(metadata)? };
-        results.map(|mut row| {
+        let result = results.map(|mut row| {
             U::Row::build_from_row(&mut row)
                 .map(U::build)
+                .map(&mut f)
                 .map_err(DeserializationError)
-        })
+        });
+        attach_sql_to_deserialization_error(result, &source)
+    }
+
+    #[doc(hidden)]
+    fn prepare_statement<T>(&self, source: &T) -> QueryResult<()>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        self.statement_cache
+            .cached_statement(source, &[], |sql| self.raw_connection.prepare(sql))
+            .map(|_| ())
     }
 
     #[doc(hidden)]
@@ -85,6 +113,17 @@ impl Connection for MysqlConnection {
         f()
     }
 
+    fn execute_query<T>(&self, source: T) -> QueryResult<usize>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+    {
+        // `execute` stores the result set without binding any output
+        // buffers, after which the affected row count of a `SELECT` is the
+        // number of rows it returned.
+        self.execute_returning_count(&source.as_query())
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
@@ -1,3 +1,5 @@
+use std::iter::FromIterator;
+
 use super::{ffi, libc, Binds, Statement};
 use result::QueryResult;
 use row::Row;
@@ -24,15 +26,15 @@ impl<'a> StatementIterator<'a> {
         })
     }
 
-    pub fn map<F, T>(mut self, mut f: F) -> QueryResult<Vec<T>>
+    pub fn map<F, T, C>(self, f: F) -> QueryResult<C>
     where
         F: FnMut(MysqlRow) -> QueryResult<T>,
+        C: FromIterator<T>,
     {
-        let mut results = Vec::new();
-        while let Some(row) = self.next() {
-            results.push(f(row?)?);
-        }
-        Ok(results)
+        MappedRows {
+            iter: self,
+            f: f,
+        }.collect()
     }
 
     fn next(&mut self) -> Option<QueryResult<MysqlRow>> {
@@ -58,6 +60,26 @@ impl<'a> StatementIterator<'a> {
     }
 }
 
+// Each `MysqlRow` borrows the output binds of the statement, so the rows can't
+// be exposed through `Iterator` themselves. Once they've been passed to `f`,
+// nothing is borrowed anymore, so the results can be collected directly.
+struct MappedRows<'a, F> {
+    iter: StatementIterator<'a>,
+    f: F,
+}
+
+impl<'a, F, T> Iterator for MappedRows<'a, F>
+where
+    F: FnMut(MysqlRow) -> QueryResult<T>,
+{
+    type Item = QueryResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next().map(|row| row.and_then(f))
+    }
+}
+
 pub struct MysqlRow<'a> {
     col_idx: usize,
     binds: &'a Binds,
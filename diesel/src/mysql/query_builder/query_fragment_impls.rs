@@ -1,11 +1,62 @@
+use backend::Backend;
 use mysql::Mysql;
-use query_builder::{AstPass, QueryFragment};
-use query_builder::for_update_clause::ForUpdateClause;
+use query_builder::{AstPass, LimitedStatement, QueryFragment};
+use query_builder::limit_clause::NoLimitClause;
+use query_builder::limit_offset_clause::LimitOffsetClause;
+use query_builder::locking_clause::{ForShare, ForUpdate, NoWait, SkipLocked};
+use query_builder::offset_clause::OffsetClause;
 use result::QueryResult;
 
-impl QueryFragment<Mysql> for ForUpdateClause {
+impl QueryFragment<Mysql> for ForUpdate {
     fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
         out.push_sql(" FOR UPDATE");
         Ok(())
     }
 }
+
+// `FOR SHARE`, `SKIP LOCKED` and `NOWAIT` were added in MySQL 8.0
+impl QueryFragment<Mysql> for ForShare {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" FOR SHARE");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Mysql> for SkipLocked {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" SKIP LOCKED");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Mysql> for NoWait {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" NOWAIT");
+        Ok(())
+    }
+}
+
+impl<Stmt> QueryFragment<Mysql> for LimitedStatement<Stmt>
+where
+    Stmt: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        self.statement.walk_ast(out.reborrow())?;
+        out.push_sql(" LIMIT ");
+        self.limit.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// MySQL doesn't accept `OFFSET` without `LIMIT`, so the largest possible
+/// limit is used when only an offset was given.
+impl<O> QueryFragment<Mysql> for LimitOffsetClause<NoLimitClause, OffsetClause<O>>
+where
+    OffsetClause<O>: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(Mysql::LIMIT_FOR_OFFSET_WITHOUT_LIMIT);
+        self.offset_clause.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
@@ -38,4 +38,5 @@ impl TypeMetadata for Pg {
 
 impl SupportsReturningClause for Pg {}
 impl SupportsDefaultKeyword for Pg {}
+impl SupportsOnConflictClause for Pg {}
 impl UsesAnsiSavepointSyntax for Pg {}
@@ -0,0 +1,80 @@
+use std::fmt;
+
+macro_rules! client_encodings {
+    ($($(#[$meta:meta])* $variant:ident => $name:expr,)+) => {
+        /// A character set which PostgreSQL can use to send and receive text.
+        ///
+        /// Passed to
+        /// [`PgConnection::set_client_encoding`](struct.PgConnection.html#method.set_client_encoding).
+        /// Only the encodings PostgreSQL supports on the client side are
+        /// listed. The `Display` implementation gives the name PostgreSQL
+        /// uses for the encoding.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ClientEncoding {
+            $($(#[$meta])* $variant,)+
+        }
+
+        impl ClientEncoding {
+            /// The name PostgreSQL uses for this encoding, as reported by
+            /// `SHOW client_encoding`.
+            pub fn name(&self) -> &'static str {
+                match *self {
+                    $(ClientEncoding::$variant => $name,)+
+                }
+            }
+        }
+    }
+}
+
+client_encodings! {
+    Big5 => "BIG5",
+    EucCn => "EUC_CN",
+    EucJp => "EUC_JP",
+    EucJis2004 => "EUC_JIS_2004",
+    EucKr => "EUC_KR",
+    EucTw => "EUC_TW",
+    Gb18030 => "GB18030",
+    Gbk => "GBK",
+    Iso88595 => "ISO_8859_5",
+    Iso88596 => "ISO_8859_6",
+    Iso88597 => "ISO_8859_7",
+    Iso88598 => "ISO_8859_8",
+    Johab => "JOHAB",
+    Koi8R => "KOI8R",
+    Koi8U => "KOI8U",
+    Latin1 => "LATIN1",
+    Latin2 => "LATIN2",
+    Latin3 => "LATIN3",
+    Latin4 => "LATIN4",
+    Latin5 => "LATIN5",
+    Latin6 => "LATIN6",
+    Latin7 => "LATIN7",
+    Latin8 => "LATIN8",
+    Latin9 => "LATIN9",
+    Latin10 => "LATIN10",
+    MuleInternal => "MULE_INTERNAL",
+    Sjis => "SJIS",
+    ShiftJis2004 => "SHIFT_JIS_2004",
+    /// No conversion is performed, and bytes are passed through as is.
+    SqlAscii => "SQL_ASCII",
+    Uhc => "UHC",
+    /// The encoding Diesel sets when a connection is established.
+    Utf8 => "UTF8",
+    Win866 => "WIN866",
+    Win874 => "WIN874",
+    Win1250 => "WIN1250",
+    Win1251 => "WIN1251",
+    Win1252 => "WIN1252",
+    Win1253 => "WIN1253",
+    Win1254 => "WIN1254",
+    Win1255 => "WIN1255",
+    Win1256 => "WIN1256",
+    Win1257 => "WIN1257",
+    Win1258 => "WIN1258",
+}
+
+impl fmt::Display for ClientEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
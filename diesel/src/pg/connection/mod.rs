@@ -1,25 +1,35 @@
+mod client_encoding;
 mod cursor;
+mod notification;
 pub mod raw;
 mod row;
 #[doc(hidden)]
 pub mod result;
 mod stmt;
 
+use std::cell::Cell;
 use std::ffi::{CStr, CString};
+use std::iter::FromIterator;
 use std::os::raw as libc;
+use std::panic::{self, AssertUnwindSafe};
 
 use connection::*;
-use pg::{Pg, PgMetadataLookup};
+use dsl::sql;
+use pg::{Pg, PgMetadataLookup, PgQueryBuilder};
 use query_builder::*;
 use query_builder::bind_collector::RawBytesBindCollector;
+use query_dsl::LoadDsl;
 use query_source::Queryable;
 use result::*;
 use result::ConnectionError::CouldntSetupConfiguration;
+pub use self::client_encoding::ClientEncoding;
+pub use self::notification::PgNotification;
+
 use self::cursor::Cursor;
 use self::raw::RawConnection;
 use self::result::PgResult;
 use self::stmt::Statement;
-use types::HasSqlType;
+use types::{BigInt, Bool, HasSqlType, Text};
 
 /// The connection string expected by `PgConnection::establish`
 /// should be a PostgreSQL connection string, as documented at
@@ -29,6 +39,25 @@ pub struct PgConnection {
     raw_connection: RawConnection,
     transaction_manager: AnsiTransactionManager,
     statement_cache: StatementCache<Pg, Statement>,
+    prepared_statement_mode: Cell<PreparedStatementMode>,
+}
+
+/// Controls whether a [`PgConnection`] creates server side prepared
+/// statements.
+///
+/// See [`PgConnection::set_prepared_statement_mode`] for details.
+///
+/// [`PgConnection`]: struct.PgConnection.html
+/// [`PgConnection::set_prepared_statement_mode`]: struct.PgConnection.html#method.set_prepared_statement_mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreparedStatementMode {
+    /// Queries are prepared as named statements on the server, and cached
+    /// for the lifetime of the connection whenever it is safe to do so.
+    /// This is the default.
+    Enabled,
+    /// Queries are parsed, bound and executed in a single round trip, and no
+    /// named prepared statements are ever created on the server.
+    Disabled,
 }
 
 unsafe impl Send for PgConnection {}
@@ -52,6 +81,7 @@ impl Connection for PgConnection {
                 raw_connection: raw_conn,
                 transaction_manager: AnsiTransactionManager::new(),
                 statement_cache: StatementCache::new(),
+                prepared_statement_mode: Cell::new(PreparedStatementMode::Enabled),
             };
             conn.set_config_options().map_err(CouldntSetupConfiguration)?;
             Ok(conn)
@@ -70,11 +100,41 @@ impl Connection for PgConnection {
         T::Query: QueryFragment<Pg> + QueryId,
         Pg: HasSqlType<T::SqlType>,
         U: Queryable<T::SqlType, Pg>,
+    {
+        self.query_by_index_map(source, |row: U| row)
+    }
+
+    #[doc(hidden)]
+    fn query_by_index_map<T, U, F, R, C>(&self, source: T, mut f: F) -> QueryResult<C>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg> + QueryId,
+        Pg: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Pg>,
+        F: FnMut(U) -> R,
+        C: FromIterator<R>,
+    {
+        let source = source.as_query();
+        let (query, params) = try!(self.prepare_query(&source));
+        let result = query
+            .execute(&self.raw_connection, &params)
+            .and_then(|r| {
+                Cursor::<T::SqlType, U>::new(r)
+                    .map(|row| row.map(&mut f))
+                    .collect()
+            });
+        attach_sql_to_deserialization_error(result, &source)
+    }
+
+    fn execute_query<T>(&self, source: T) -> QueryResult<usize>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg> + QueryId,
     {
         let (query, params) = try!(self.prepare_query(&source.as_query()));
         query
             .execute(&self.raw_connection, &params)
-            .and_then(|r| Cursor::new(r).collect())
+            .map(|r| r.num_rows())
     }
 
     #[doc(hidden)]
@@ -88,6 +148,14 @@ impl Connection for PgConnection {
             .map(|r| r.rows_affected())
     }
 
+    #[doc(hidden)]
+    fn prepare_statement<T>(&self, source: &T) -> QueryResult<()>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        self.prepare_query(source).map(|_| ())
+    }
+
     #[doc(hidden)]
     fn silence_notices<F: FnOnce() -> T, T>(&self, f: F) -> T {
         self.raw_connection
@@ -105,6 +173,480 @@ impl Connection for PgConnection {
 }
 
 impl PgConnection {
+    /// Changes the current role of this connection by issuing `SET ROLE`.
+    ///
+    /// The role name is always sent as a quoted identifier, so it cannot be
+    /// used to inject SQL, and is matched case sensitively. The new role
+    /// remains in effect for the rest of the session (it is *not* reverted
+    /// when a transaction ends) until [`reset_role`] is called. This is
+    /// commonly used with row level security policies to scope a connection
+    /// to a single tenant.
+    ///
+    /// [`reset_role`]: #method.reset_role
+    pub fn set_role(&self, role: &str) -> QueryResult<()> {
+        self.batch_execute(&set_role_statement(role)?)
+    }
+
+    /// Reverts a previous call to [`set_role`], restoring the role which was
+    /// used to establish the connection.
+    ///
+    /// [`set_role`]: #method.set_role
+    pub fn reset_role(&self) -> QueryResult<()> {
+        self.batch_execute("RESET ROLE")
+    }
+
+    /// Returns the name of the role currently in effect for this connection.
+    pub fn current_role(&self) -> QueryResult<String> {
+        ::select(sql::<Text>("current_user")).get_result(self)
+    }
+
+    /// Changes the character set used for text sent to and received from the
+    /// server for the rest of the session, by issuing `SET client_encoding`.
+    ///
+    /// The server converts text between the database's encoding and the
+    /// client encoding, so this should match the encoding of the text your
+    /// application sends. Diesel sets the client encoding to `UTF8` when the
+    /// connection is established, and always reads text columns as UTF-8.
+    /// With any other encoding, loading text containing non-ASCII characters
+    /// into a `String` will fail, so this is mostly useful together with
+    /// raw byte access, or for connections which only handle ASCII text.
+    pub fn set_client_encoding(&self, encoding: ClientEncoding) -> QueryResult<()> {
+        self.batch_execute(&format!("SET client_encoding = '{}'", encoding.name()))
+    }
+
+    /// Returns the name of the client encoding currently used by this
+    /// connection, as reported by PostgreSQL (e.g. `"UTF8"`).
+    pub fn client_encoding(&self) -> QueryResult<String> {
+        ::select(sql::<Text>("current_setting('client_encoding')")).get_result(self)
+    }
+
+    /// Returns the `TimeZone` setting of this connection, as reported by
+    /// PostgreSQL (e.g. `"UTC"` or `"Europe/Berlin"`).
+    ///
+    /// This is the zone used to display `Timestamptz` values as text, and to
+    /// convert `Timestamp` values to `Timestamptz` (for example with
+    /// [`at_time_zone`] or a cast). It defaults to the server's configured
+    /// time zone.
+    ///
+    /// [`at_time_zone`]: expression/expression_methods/trait.PgTimestampExpressionMethods.html#method.at_time_zone
+    pub fn server_timezone(&self) -> QueryResult<String> {
+        ::select(sql::<Text>("current_setting('TimeZone')")).get_result(self)
+    }
+
+    /// Changes the `TimeZone` setting for the rest of the session.
+    ///
+    /// The zone name is sent as a bind parameter to `set_config`, so it can't
+    /// be used to inject SQL. PostgreSQL validates the name, so an unknown
+    /// time zone results in a `DatabaseError`, and the previous setting is
+    /// kept.
+    pub fn set_time_zone(&self, zone: &str) -> QueryResult<()> {
+        if zone.is_empty() {
+            return Err(Error::QueryBuilderError(
+                "The time zone passed to `set_time_zone` cannot be empty".into(),
+            ));
+        }
+        sql::<Text>("SELECT set_config('TimeZone', $1, false)")
+            .bind::<Text, _>(zone)
+            .get_result::<String>(self)
+            .map(|_| ())
+    }
+
+    /// Changes a configuration parameter for the current transaction by
+    /// issuing `SET LOCAL`.
+    ///
+    /// The setting is reverted automatically when the transaction commits or
+    /// rolls back, which makes this safe to use on connections which are
+    /// shared or returned to a pool. The parameter name is sent as a quoted
+    /// identifier (each part of a dotted name such as
+    /// `pg_trgm.similarity_threshold` is quoted separately), and the value is
+    /// sent as a string literal, so neither can be used to inject SQL.
+    ///
+    /// `SET LOCAL` has no effect outside of a transaction, so this returns an
+    /// error unless it is called from within [`Connection::transaction`] or
+    /// [`Connection::begin_test_transaction`].
+    ///
+    /// [`Connection::transaction`]: ../connection/trait.Connection.html#method.transaction
+    /// [`Connection::begin_test_transaction`]: ../connection/trait.Connection.html#method.begin_test_transaction
+    pub fn set_local(&self, param: &str, value: &str) -> QueryResult<()> {
+        self.ensure_in_transaction("set_local")?;
+        if param.split('.').any(str::is_empty) {
+            return Err(Error::QueryBuilderError(
+                format!("`{}` is not a valid configuration parameter name", param).into(),
+            ));
+        }
+        let mut query = PgQueryBuilder::new();
+        query.push_sql("SET LOCAL ");
+        for (i, part) in param.split('.').enumerate() {
+            if i != 0 {
+                query.push_sql(".");
+            }
+            query.push_identifier(part)?;
+        }
+        query.push_sql(" = E'");
+        query.push_sql(&value.replace('\\', "\\\\").replace('\'', "''"));
+        query.push_sql("'");
+        self.batch_execute(&query.finish())
+    }
+
+    /// Sets `pg_trgm.similarity_threshold` for the current transaction.
+    ///
+    /// This controls how similar two strings must be for
+    /// [`trigram_similar`] (the `%` operator of the `pg_trgm` extension) to
+    /// match them. The threshold is the fraction of trigrams the two strings
+    /// must share, so it must be between `0.0` and `1.0` inclusive. Lower
+    /// values give fuzzier matches. PostgreSQL's default is `0.3`.
+    ///
+    /// The setting is applied with [`set_local`], so it is reverted when the
+    /// transaction ends, and this returns an error outside of a transaction
+    /// or if the threshold is out of range.
+    ///
+    /// [`trigram_similar`]: expression/expression_methods/trait.PgTextExpressionMethods.html#method.trigram_similar
+    /// [`set_local`]: #method.set_local
+    pub fn set_similarity_threshold(&self, threshold: f32) -> QueryResult<()> {
+        if !(threshold >= 0.0 && threshold <= 1.0) {
+            return Err(Error::QueryBuilderError(
+                format!(
+                    "The similarity threshold must be between 0 and 1, got {}",
+                    threshold
+                ).into(),
+            ));
+        }
+        self.set_local("pg_trgm.similarity_threshold", &threshold.to_string())
+    }
+
+    /// Runs the given closure with `search_path` set to the given schemas,
+    /// restoring the previous `search_path` afterwards.
+    ///
+    /// This is useful for applications which keep each tenant's tables in a
+    /// separate schema. The closure is run in a transaction (or a savepoint,
+    /// if a transaction is already open), and the path is set with
+    /// `set_config(..., true)`, which is equivalent to `SET LOCAL`. If the
+    /// closure returns an error, the transaction is rolled back, which also
+    /// reverts the path. Otherwise the previous path is set again before the
+    /// transaction is committed, so it is restored even when a savepoint is
+    /// released into an outer transaction. If the closure panics, the
+    /// transaction is left open, so the previous path is set again before
+    /// the panic is resumed.
+    ///
+    /// Each schema name is quoted, so names are case sensitive and can't be
+    /// used to inject SQL. Schemas which don't exist are ignored by
+    /// PostgreSQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::types::{BigInt, Text};
+    /// #
+    /// # fn main() {
+    /// #     let connection = connection_no_data();
+    /// let current_search_path = || {
+    ///     diesel::select(sql::<Text>("current_setting('search_path')"))
+    ///         .get_result::<String>(&connection)
+    /// };
+    /// let original_search_path = current_search_path();
+    /// connection.execute("CREATE SCHEMA tenant_1").unwrap();
+    /// connection.execute("CREATE TABLE tenant_1.notes (body TEXT)").unwrap();
+    ///
+    /// let note_count = connection.with_search_path(&["tenant_1"], |conn| {
+    ///     conn.execute("INSERT INTO notes VALUES ('hi')")?;
+    ///     diesel::select(sql::<BigInt>("COUNT(*) FROM notes")).get_result::<i64>(conn)
+    /// });
+    /// assert_eq!(Ok(1), note_count);
+    /// assert_eq!(original_search_path, current_search_path());
+    /// # }
+    /// ```
+    pub fn with_search_path<T, E, F>(&self, schemas: &[&str], f: F) -> Result<T, E>
+    where
+        F: FnOnce(&Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let search_path = schemas
+            .iter()
+            .map(|schema| format!("\"{}\"", schema.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let set_search_path = |path: &str| {
+            sql::<Text>("SELECT set_config('search_path', $1, true)")
+                .bind::<Text, _>(path)
+                .get_result::<String>(self)
+                .map(|_| ())
+        };
+
+        let original_search_path =
+            ::select(sql::<Text>("current_setting('search_path')")).get_result::<String>(self)?;
+        self.transaction(|| {
+            set_search_path(&search_path)?;
+            let result = match panic::catch_unwind(AssertUnwindSafe(|| f(self))) {
+                Ok(result) => result?,
+                Err(e) => {
+                    let _ = set_search_path(&original_search_path);
+                    panic::resume_unwind(e)
+                }
+            };
+            set_search_path(&original_search_path)?;
+            Ok(result)
+        })
+    }
+
+    /// Starts listening for notifications sent to `channel` by issuing
+    /// `LISTEN`.
+    ///
+    /// The channel name is sent as a quoted identifier, so it is matched case
+    /// sensitively and can contain any character. This means that it has to
+    /// be spelled exactly as it is passed to [`notify`] (or to `pg_notify`).
+    /// A channel which was written unquoted in a `NOTIFY` statement is folded
+    /// to lower case by PostgreSQL, so it will only be received if `channel`
+    /// is lower case as well.
+    ///
+    /// Received notifications can be read with [`notifications`].
+    ///
+    /// [`notify`]: #method.notify
+    /// [`notifications`]: #method.notifications
+    pub fn listen(&self, channel: &str) -> QueryResult<()> {
+        self.batch_execute(&channel_statement("LISTEN", channel)?)
+    }
+
+    /// Stops listening for notifications sent to `channel` by issuing
+    /// `UNLISTEN`. The channel name is quoted the same way as for
+    /// [`listen`](#method.listen).
+    pub fn unlisten(&self, channel: &str) -> QueryResult<()> {
+        self.batch_execute(&channel_statement("UNLISTEN", channel)?)
+    }
+
+    /// Sends a notification with the given payload to every connection
+    /// listening on `channel`.
+    ///
+    /// This is done with `SELECT pg_notify($1, $2)`, so both the channel name
+    /// and the payload are sent as bind parameters and can contain any
+    /// character. The channel name is used exactly as given, the same as a
+    /// quoted identifier in `NOTIFY`. As with `NOTIFY`, the notification is
+    /// only delivered once the current transaction commits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+    /// #     let connection = PgConnection::establish(&database_url).unwrap();
+    /// connection.listen("Orders").unwrap();
+    /// connection.notify("Orders", "42").unwrap();
+    ///
+    /// let notifications = connection.notifications().unwrap();
+    /// assert_eq!(1, notifications.len());
+    /// assert_eq!("Orders", notifications[0].channel);
+    /// assert_eq!("42", notifications[0].payload);
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    pub fn notify(&self, channel: &str, payload: &str) -> QueryResult<()> {
+        if channel.is_empty() {
+            return Err(Error::QueryBuilderError(
+                "The channel name passed to `notify` cannot be empty".into(),
+            ));
+        }
+        sql::<Text>("SELECT pg_notify($1, $2)::text")
+            .bind::<Text, _>(channel)
+            .bind::<Text, _>(payload)
+            .get_result::<String>(self)
+            .map(|_| ())
+    }
+
+    /// Returns all notifications which have been received on channels this
+    /// connection is listening on, and which haven't been returned yet.
+    ///
+    /// Notifications are received whenever the connection reads from the
+    /// server. This method also reads any notifications which arrived since
+    /// the last query, but it never blocks waiting for new ones.
+    pub fn notifications(&self) -> QueryResult<Vec<PgNotification>> {
+        self.raw_connection.consume_input()?;
+        let mut notifications = Vec::new();
+        while let Some(notification) = self.raw_connection.pop_notification() {
+            notifications.push(notification);
+        }
+        Ok(notifications)
+    }
+
+    /// Acquires the session level advisory lock identified by `key` with
+    /// `pg_advisory_lock`, waiting until it is available.
+    ///
+    /// Advisory locks have no meaning to PostgreSQL itself, they can be used
+    /// for mutual exclusion between any processes which connect to the same
+    /// database, for example to make sure that a scheduled job only runs
+    /// once at a time. A session level lock is held until it is released with
+    /// [`advisory_unlock`] or the connection is closed, even if the current
+    /// transaction is rolled back. The lock can be acquired more than once by
+    /// the same connection, and has to be released as many times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+    /// #     let connection = PgConnection::establish(&database_url).unwrap();
+    /// #     let other_connection = PgConnection::establish(&database_url).unwrap();
+    /// const NIGHTLY_REPORT_LOCK: i64 = 31_337;
+    ///
+    /// connection.advisory_lock(NIGHTLY_REPORT_LOCK).unwrap();
+    /// assert_eq!(Ok(false), other_connection.try_advisory_lock(NIGHTLY_REPORT_LOCK));
+    ///
+    /// assert_eq!(Ok(true), connection.advisory_unlock(NIGHTLY_REPORT_LOCK));
+    /// assert_eq!(Ok(true), other_connection.try_advisory_lock(NIGHTLY_REPORT_LOCK));
+    /// #     other_connection.advisory_unlock(NIGHTLY_REPORT_LOCK).unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`advisory_unlock`]: #method.advisory_unlock
+    pub fn advisory_lock(&self, key: i64) -> QueryResult<()> {
+        self.advisory_lock_function("pg_advisory_lock", key)
+    }
+
+    /// Acquires the session level advisory lock identified by `key` with
+    /// `pg_try_advisory_lock` if it is available, without waiting. Returns
+    /// whether the lock was acquired. See [`advisory_lock`] for details.
+    ///
+    /// [`advisory_lock`]: #method.advisory_lock
+    pub fn try_advisory_lock(&self, key: i64) -> QueryResult<bool> {
+        self.try_advisory_lock_function("pg_try_advisory_lock", key)
+    }
+
+    /// Releases a session level advisory lock which was acquired with
+    /// [`advisory_lock`] or [`try_advisory_lock`], using
+    /// `pg_advisory_unlock`. Returns `false` if this connection didn't hold
+    /// the lock.
+    ///
+    /// [`advisory_lock`]: #method.advisory_lock
+    /// [`try_advisory_lock`]: #method.try_advisory_lock
+    pub fn advisory_unlock(&self, key: i64) -> QueryResult<bool> {
+        self.try_advisory_lock_function("pg_advisory_unlock", key)
+    }
+
+    /// Acquires the transaction level advisory lock identified by `key` with
+    /// `pg_advisory_xact_lock`, waiting until it is available.
+    ///
+    /// The lock is released automatically when the current transaction
+    /// commits or rolls back, and can't be released explicitly. Outside of a
+    /// transaction the lock would be released as soon as it is acquired, so
+    /// this returns an error unless it is called from within
+    /// [`Connection::transaction`] or [`Connection::begin_test_transaction`].
+    ///
+    /// [`Connection::transaction`]: ../connection/trait.Connection.html#method.transaction
+    /// [`Connection::begin_test_transaction`]: ../connection/trait.Connection.html#method.begin_test_transaction
+    pub fn advisory_xact_lock(&self, key: i64) -> QueryResult<()> {
+        self.ensure_in_transaction("advisory_xact_lock")?;
+        self.advisory_lock_function("pg_advisory_xact_lock", key)
+    }
+
+    /// Acquires the transaction level advisory lock identified by `key` with
+    /// `pg_try_advisory_xact_lock` if it is available, without waiting.
+    /// Returns whether the lock was acquired. See [`advisory_xact_lock`] for
+    /// details.
+    ///
+    /// [`advisory_xact_lock`]: #method.advisory_xact_lock
+    pub fn try_advisory_xact_lock(&self, key: i64) -> QueryResult<bool> {
+        self.ensure_in_transaction("try_advisory_xact_lock")?;
+        self.try_advisory_lock_function("pg_try_advisory_xact_lock", key)
+    }
+
+    fn advisory_lock_function(&self, function: &str, key: i64) -> QueryResult<()> {
+        sql::<Text>(&format!("SELECT {}($1)::text", function))
+            .bind::<BigInt, _>(key)
+            .get_result::<String>(self)
+            .map(|_| ())
+    }
+
+    fn try_advisory_lock_function(&self, function: &str, key: i64) -> QueryResult<bool> {
+        sql::<Bool>(&format!("SELECT {}($1)", function))
+            .bind::<BigInt, _>(key)
+            .get_result(self)
+    }
+
+    fn ensure_in_transaction(&self, method: &str) -> QueryResult<()> {
+        let transaction_depth =
+            TransactionManager::<PgConnection>::get_transaction_depth(&self.transaction_manager);
+        if transaction_depth == 0 {
+            Err(Error::QueryBuilderError(
+                format!("`{}` can only be called inside of a transaction", method).into(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Executes the given SQL, returning the number of rows affected if the
+    /// command reports one.
+    ///
+    /// `Connection::execute` returns `0` both for commands which changed no
+    /// rows, and for commands which have no concept of affected rows at all
+    /// (such as `CREATE TABLE`). This method distinguishes the two, returning
+    /// `Ok(None)` for the latter.
+    pub fn execute_returning_optional_count(&self, query: &str) -> QueryResult<Option<usize>> {
+        self.execute_inner(query)
+            .map(|res| res.rows_affected_if_reported())
+    }
+
+    /// Sets whether this connection uses server side prepared statements.
+    ///
+    /// By default, Diesel prepares every query as a named statement and
+    /// keeps it around for the lifetime of the connection, so that later
+    /// executions of the same query skip parsing and planning. Named
+    /// statements are tied to a single server connection, which breaks when
+    /// connecting through a pooler which may hand each transaction a
+    /// different server connection (such as pgbouncer in transaction pooling
+    /// mode).
+    ///
+    /// With `PreparedStatementMode::Disabled`, each query is sent with its
+    /// bind parameters in a single round trip using the unnamed statement.
+    /// Bind parameters are still used, so this has no effect on how values
+    /// are escaped. The tradeoff is performance: the server has to parse and
+    /// plan every query on every execution, which can be noticeably slower
+    /// for complex queries that are run frequently.
+    ///
+    /// Statements which were cached before switching to `Disabled` are kept,
+    /// but are not used until the mode is set back to `Enabled`.
+    pub fn set_prepared_statement_mode(&self, mode: PreparedStatementMode) {
+        self.prepared_statement_mode.set(mode);
+    }
+
+    /// Returns whether this connection uses server side prepared statements.
+    pub fn prepared_statement_mode(&self) -> PreparedStatementMode {
+        self.prepared_statement_mode.get()
+    }
+
+    /// Returns the names of the server side prepared statements which are
+    /// currently held in this connection's statement cache, sorted by name.
+    ///
+    /// This is only intended for debugging, such as checking how many
+    /// statements a long lived connection has accumulated, or confirming
+    /// that no named statements are created when prepared statements are
+    /// disabled. Queries which can't be cached use the unnamed statement, and
+    /// are not included.
+    pub fn prepared_statement_names(&self) -> Vec<String> {
+        let mut names = self.statement_cache
+            .cache
+            .borrow()
+            .values()
+            .filter_map(|statement| statement.name().map(String::from))
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    }
+
     #[cfg_attr(feature = "clippy", allow(type_complexity))]
     fn prepare_query<T: QueryFragment<Pg> + QueryId>(
         &self,
@@ -115,6 +657,13 @@ impl PgConnection {
         let binds = bind_collector.binds;
         let metadata = bind_collector.metadata;
 
+        if self.prepared_statement_mode() == PreparedStatementMode::Disabled {
+            let mut query_builder = PgQueryBuilder::new();
+            source.to_sql(&mut query_builder)?;
+            let query = Statement::unprepared(&query_builder.finish(), &metadata)?;
+            return Ok((MaybeCached::CannotCache(query), binds));
+        }
+
         let cache_len = self.statement_cache.len();
         let query = self.statement_cache
             .cached_statement(source, &metadata, |sql| {
@@ -146,6 +695,31 @@ impl PgConnection {
     }
 }
 
+fn set_role_statement(role: &str) -> QueryResult<String> {
+    if role.is_empty() {
+        return Err(Error::QueryBuilderError(
+            "The role name passed to `set_role` cannot be empty".into(),
+        ));
+    }
+    let mut query = PgQueryBuilder::new();
+    query.push_sql("SET ROLE ");
+    query.push_identifier(role)?;
+    Ok(query.finish())
+}
+
+fn channel_statement(command: &str, channel: &str) -> QueryResult<String> {
+    if channel.is_empty() {
+        return Err(Error::QueryBuilderError(
+            format!("The channel name passed to `{}` cannot be empty", command).into(),
+        ));
+    }
+    let mut query = PgQueryBuilder::new();
+    query.push_sql(command);
+    query.push_sql(" ");
+    query.push_identifier(channel)?;
+    Ok(query.finish())
+}
+
 extern "C" fn noop_notice_processor(_: *mut libc::c_void, _message: *const libc::c_char) {}
 
 extern "C" fn default_notice_processor(_: *mut libc::c_void, message: *const libc::c_char) {
@@ -167,7 +741,8 @@ mod tests {
     use dsl::sql;
     use prelude::*;
     use super::*;
-    use types::{Integer, VarChar};
+    use result::Error;
+    use types::{Integer, Text, VarChar};
 
     #[test]
     fn prepared_statements_are_cached() {
@@ -180,6 +755,43 @@ mod tests {
         assert_eq!(1, connection.statement_cache.len());
     }
 
+    #[test]
+    fn warmed_up_statements_are_cached_before_being_run() {
+        let connection = connection();
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+        let query2 = ::select(AsExpression::<VarChar>::as_expression("hi"));
+
+        connection
+            .warmup(&[query.as_prepared(), query2.as_prepared()])
+            .unwrap();
+        assert_eq!(
+            vec!["__diesel_stmt_0", "__diesel_stmt_1"],
+            connection.prepared_statement_names()
+        );
+
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(Ok("hi".to_string()), query2.get_result(&connection));
+        assert_eq!(2, connection.statement_cache.len());
+    }
+
+    #[test]
+    fn prepared_statement_names_lists_cached_statements() {
+        let connection = connection();
+        assert!(connection.prepared_statement_names().is_empty());
+
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+        let query2 = ::select(AsExpression::<VarChar>::as_expression("hi"));
+        let uncached = ::select(sql::<Integer>("1"));
+
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(Ok("hi".to_string()), query2.get_result(&connection));
+        assert_eq!(Ok(1), uncached.get_result(&connection));
+        assert_eq!(
+            vec!["__diesel_stmt_0", "__diesel_stmt_1"],
+            connection.prepared_statement_names()
+        );
+    }
+
     #[test]
     fn queries_with_identical_sql_but_different_types_are_cached_separately() {
         let connection = connection();
@@ -220,6 +832,249 @@ mod tests {
         assert_eq!(2, connection.statement_cache.len());
     }
 
+    #[test]
+    fn set_role_changes_the_current_role_until_reset() {
+        let connection = connection();
+        let original_role = connection.current_role().unwrap();
+
+        connection.set_role(&original_role).unwrap();
+        assert_eq!(Ok(original_role.clone()), connection.current_role());
+        connection.reset_role().unwrap();
+        assert_eq!(Ok(original_role), connection.current_role());
+    }
+
+    #[test]
+    fn set_role_quotes_the_role_name() {
+        let connection = connection();
+
+        assert_eq!(
+            Ok("SET ROLE \"Tenant_1\"".to_string()),
+            set_role_statement("Tenant_1")
+        );
+        assert_eq!(
+            Ok("SET ROLE \"no_such_role\"\"; DROP TABLE users; --\"".to_string()),
+            set_role_statement("no_such_role\"; DROP TABLE users; --")
+        );
+        assert!(connection.set_role("").is_err());
+        assert!(
+            connection
+                .set_role("no_such_role\"; DROP TABLE users; --")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn set_client_encoding_changes_the_reported_encoding() {
+        let connection = connection();
+        assert_eq!(Ok("UTF8".to_string()), connection.client_encoding());
+
+        connection
+            .set_client_encoding(ClientEncoding::Latin1)
+            .unwrap();
+        assert_eq!(Ok("LATIN1".to_string()), connection.client_encoding());
+        let query = ::select(sql::<Text>("'plain ascii'"));
+        assert_eq!(Ok("plain ascii".to_string()), query.get_result(&connection));
+
+        connection.set_client_encoding(ClientEncoding::Utf8).unwrap();
+        assert_eq!(Ok("UTF8".to_string()), connection.client_encoding());
+        assert_eq!("WIN1252", ClientEncoding::Win1252.to_string());
+    }
+
+    #[test]
+    fn set_time_zone_changes_the_reported_time_zone() {
+        let connection = connection();
+        connection.set_time_zone("UTC").unwrap();
+        assert_eq!(Ok("UTC".to_string()), connection.server_timezone());
+
+        connection.set_time_zone("America/New_York").unwrap();
+        assert_eq!(
+            Ok("America/New_York".to_string()),
+            connection.server_timezone()
+        );
+        let offset = ::select(sql::<Text>(
+            "to_char(TIMESTAMPTZ '2017-01-01 12:00:00+00', 'HH24')",
+        )).get_result::<String>(&connection);
+        assert_eq!(Ok("07".to_string()), offset);
+
+        assert!(connection.set_time_zone("").is_err());
+        assert!(connection.set_time_zone("Not/A_Zone'; --").is_err());
+        assert_eq!(
+            Ok("America/New_York".to_string()),
+            connection.server_timezone()
+        );
+    }
+
+    #[test]
+    fn set_local_is_reverted_when_the_transaction_commits() {
+        let connection = connection();
+        let statement_timeout = || {
+            ::select(sql::<Text>("current_setting('statement_timeout')"))
+                .get_result::<String>(&connection)
+        };
+        let original = statement_timeout().unwrap();
+
+        connection
+            .transaction::<_, Error, _>(|| {
+                connection.set_local("statement_timeout", "1234")?;
+                assert_eq!(Ok("1234ms".to_string()), statement_timeout());
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!(Ok(original), statement_timeout());
+    }
+
+    #[test]
+    fn set_local_outside_of_a_transaction_is_an_error() {
+        let connection = connection();
+
+        assert!(connection.set_local("statement_timeout", "1234").is_err());
+    }
+
+    #[test]
+    fn set_local_quotes_the_parameter_and_value() {
+        let connection = connection();
+        connection.begin_test_transaction().unwrap();
+
+        connection
+            .set_local("diesel_test.setting", "it's a \\ value")
+            .unwrap();
+        let value = ::select(sql::<Text>("current_setting('diesel_test.setting')"))
+            .get_result::<String>(&connection);
+        assert_eq!(Ok("it's a \\ value".to_string()), value);
+        assert!(
+            connection
+                .set_local("statement_timeout\" = 0; DROP TABLE users; --", "0")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn with_search_path_restores_the_original_search_path() {
+        let connection = connection();
+        let search_path = || {
+            ::select(sql::<Text>("current_setting('search_path')"))
+                .get_result::<String>(&connection)
+        };
+        let original = search_path().unwrap();
+
+        let path_in_closure =
+            connection.with_search_path(&["tenant_1", "public"], |_| search_path());
+        assert_eq!(Ok("\"tenant_1\", \"public\"".to_string()), path_in_closure);
+        assert_eq!(Ok(original.clone()), search_path());
+
+        let failed = connection.with_search_path::<(), _, _>(&["tenant_1"], |_| {
+            Err(Error::RollbackTransaction)
+        });
+        assert_eq!(Err(Error::RollbackTransaction), failed);
+        assert_eq!(Ok(original.clone()), search_path());
+
+        connection
+            .transaction::<_, Error, _>(|| {
+                connection.with_search_path(&["tenant_1"], |_| Ok(()))?;
+                assert_eq!(Ok(original.clone()), search_path());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(Ok(original), search_path());
+    }
+
+    #[test]
+    fn with_search_path_restores_the_original_search_path_if_the_closure_panics() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let connection = connection();
+        let search_path = || {
+            ::select(sql::<Text>("current_setting('search_path')"))
+                .get_result::<String>(&connection)
+        };
+        let original = search_path().unwrap();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            connection.with_search_path::<(), Error, _>(&["tenant_1"], |_| {
+                panic!("Something went wrong")
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Ok(original), search_path());
+    }
+
+    #[test]
+    fn with_search_path_quotes_schema_names() {
+        let connection = connection();
+        let search_path = || {
+            ::select(sql::<Text>("current_setting('search_path')"))
+                .get_result::<String>(&connection)
+        };
+
+        let path_in_closure =
+            connection.with_search_path(&["Tenant\"; DROP TABLE users; --"], |_| search_path());
+        assert_eq!(
+            Ok("\"Tenant\"\"; DROP TABLE users; --\"".to_string()),
+            path_in_closure
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "sql-in-deserialization-errors", debug_assertions))]
+    fn deserialization_errors_include_the_sql_of_the_query() {
+        use result::DeserializationErrorWithSql;
+
+        let connection = connection();
+        let query = ::select(sql::<Integer>("NULL"));
+
+        match query.get_result::<i32>(&connection) {
+            Err(Error::DeserializationError(e)) => {
+                let e = e.downcast_ref::<DeserializationErrorWithSql>()
+                    .expect("Expected the error to include the SQL");
+                assert_eq!("SELECT NULL", e.sql());
+                assert!(e.to_string().contains("SELECT NULL"));
+            }
+            other => panic!("Expected a deserialization error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn prepared_statements_are_not_created_when_disabled() {
+        let connection = connection();
+        connection.set_prepared_statement_mode(PreparedStatementMode::Disabled);
+
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(0, connection.statement_cache.len());
+        let named_statements = ::select(sql::<::types::BigInt>(
+            "COUNT(*) FROM pg_prepared_statements",
+        )).get_result(&connection);
+        assert_eq!(Ok(0), named_statements);
+
+        connection.set_prepared_statement_mode(PreparedStatementMode::Enabled);
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(1, connection.statement_cache.len());
+    }
+
+    #[test]
+    fn execute_returning_optional_count_distinguishes_ddl_from_dml() {
+        let connection = connection();
+        connection.begin_test_transaction().unwrap();
+
+        let create_table = "CREATE TEMPORARY TABLE counted (id INTEGER)";
+        assert_eq!(
+            Ok(None),
+            connection.execute_returning_optional_count(create_table)
+        );
+        assert_eq!(
+            Ok(Some(0)),
+            connection.execute_returning_optional_count("DELETE FROM counted")
+        );
+        assert_eq!(
+            Ok(Some(2)),
+            connection.execute_returning_optional_count("INSERT INTO counted VALUES (1), (2)")
+        );
+        assert_eq!(Ok(0), connection.execute("DROP TABLE counted"));
+    }
+
     #[test]
     fn queries_with_sql_literal_nodes_are_not_cached() {
         let connection = connection();
@@ -229,6 +1084,113 @@ mod tests {
         assert_eq!(0, connection.statement_cache.len());
     }
 
+    #[test]
+    fn notifications_on_mixed_case_channels_are_received() {
+        let connection = connection();
+        let backend_pid = ::select(sql::<Integer>("pg_backend_pid()"))
+            .get_result(&connection)
+            .unwrap();
+        connection.listen("Mixed Case Channel").unwrap();
+        connection.notify("Mixed Case Channel", "it's here").unwrap();
+        connection.notify("mixed case channel", "not listening").unwrap();
+
+        let expected = vec![
+            PgNotification {
+                process_id: backend_pid,
+                channel: "Mixed Case Channel".into(),
+                payload: "it's here".into(),
+            },
+        ];
+        assert_eq!(Ok(expected), connection.notifications());
+        assert_eq!(Ok(Vec::new()), connection.notifications());
+
+        connection.unlisten("Mixed Case Channel").unwrap();
+        connection.notify("Mixed Case Channel", "after unlisten").unwrap();
+        assert_eq!(Ok(Vec::new()), connection.notifications());
+    }
+
+    #[test]
+    fn notifications_are_delivered_when_the_transaction_commits() {
+        let connection = connection();
+        connection.listen("orders").unwrap();
+        connection
+            .transaction::<_, Error, _>(|| {
+                connection.notify("orders", "1")?;
+                assert_eq!(Ok(Vec::new()), connection.notifications());
+                Ok(())
+            })
+            .unwrap();
+
+        let payloads = connection
+            .notifications()
+            .unwrap()
+            .into_iter()
+            .map(|n| n.payload)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["1".to_string()], payloads);
+    }
+
+    #[test]
+    fn listening_on_an_empty_channel_name_is_an_error() {
+        let connection = connection();
+        assert!(connection.listen("").is_err());
+        assert!(connection.notify("", "payload").is_err());
+    }
+
+    #[test]
+    fn advisory_locks_are_exclusive_between_connections() {
+        let conn_1 = connection();
+        let conn_2 = connection();
+        let key = 7_425_001;
+
+        conn_1.advisory_lock(key).unwrap();
+        assert_eq!(Ok(false), conn_2.try_advisory_lock(key));
+        assert_eq!(Ok(false), conn_2.advisory_unlock(key));
+
+        assert_eq!(Ok(true), conn_1.advisory_unlock(key));
+        assert_eq!(Ok(true), conn_2.try_advisory_lock(key));
+        assert_eq!(Ok(false), conn_1.try_advisory_lock(key));
+        assert_eq!(Ok(true), conn_2.advisory_unlock(key));
+    }
+
+    #[test]
+    fn transaction_level_advisory_locks_are_released_when_the_transaction_ends() {
+        let conn_1 = connection();
+        let conn_2 = connection();
+        let key = 7_425_002;
+
+        conn_1
+            .transaction::<_, Error, _>(|| {
+                conn_1.advisory_xact_lock(key)?;
+                conn_2.transaction::<_, Error, _>(|| {
+                    assert_eq!(Ok(false), conn_2.try_advisory_xact_lock(key));
+                    Ok(())
+                })
+            })
+            .unwrap();
+
+        conn_2
+            .transaction::<_, Error, _>(|| {
+                assert_eq!(Ok(true), conn_2.try_advisory_xact_lock(key));
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn transaction_level_advisory_locks_require_a_transaction() {
+        let connection = connection();
+
+        assert_eq!(
+            "`advisory_xact_lock` can only be called inside of a transaction",
+            connection.advisory_xact_lock(1).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "`try_advisory_xact_lock` can only be called inside of a transaction",
+            connection.try_advisory_xact_lock(1).unwrap_err().to_string()
+        );
+    }
+
     fn connection() -> PgConnection {
         dotenv().ok();
         let database_url = env::var("PG_DATABASE_URL")
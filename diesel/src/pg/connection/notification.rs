@@ -0,0 +1,15 @@
+/// A notification sent with `NOTIFY` to a channel this connection is
+/// listening on.
+///
+/// Returned by
+/// [`PgConnection::notifications`](struct.PgConnection.html#method.notifications).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgNotification {
+    /// The process ID of the server process which sent the notification.
+    pub process_id: i32,
+    /// The name of the channel the notification was sent to.
+    pub channel: String,
+    /// The payload of the notification. This is empty if no payload was
+    /// given.
+    pub payload: String,
+}
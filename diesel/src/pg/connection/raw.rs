@@ -8,6 +8,7 @@ use std::os::raw as libc;
 use std::{ptr, str};
 
 use result::*;
+use super::notification::PgNotification;
 
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 pub struct RawConnection {
@@ -47,6 +48,41 @@ impl RawConnection {
         }
     }
 
+    /// Reads any input which is waiting on the socket, so that notifications
+    /// which arrived since the last query are returned by
+    /// `pop_notification`.
+    pub fn consume_input(&self) -> QueryResult<()> {
+        let succeeded = unsafe { PQconsumeInput(self.internal_connection) };
+        if succeeded == 1 {
+            Ok(())
+        } else {
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(self.last_error_message()),
+            ))
+        }
+    }
+
+    pub fn pop_notification(&self) -> Option<PgNotification> {
+        unsafe {
+            let notify = PQnotifies(self.internal_connection);
+            if notify.is_null() {
+                return None;
+            }
+            let notification = PgNotification {
+                process_id: (*notify).be_pid,
+                channel: CStr::from_ptr((*notify).relname)
+                    .to_string_lossy()
+                    .into_owned(),
+                payload: CStr::from_ptr((*notify).extra)
+                    .to_string_lossy()
+                    .into_owned(),
+            };
+            PQfreemem(notify as *mut libc::c_void);
+            Some(notification)
+        }
+    }
+
     pub unsafe fn exec(&self, query: *const libc::c_char) -> QueryResult<RawResult> {
         RawResult::new(PQexec(self.internal_connection, query), self)
     }
@@ -72,6 +108,29 @@ impl RawConnection {
         RawResult::new(ptr, self)
     }
 
+    pub unsafe fn exec_params(
+        &self,
+        query: *const libc::c_char,
+        param_count: libc::c_int,
+        param_types: *const Oid,
+        param_values: *const *const libc::c_char,
+        param_lengths: *const libc::c_int,
+        param_formats: *const libc::c_int,
+        result_format: libc::c_int,
+    ) -> QueryResult<RawResult> {
+        let ptr = PQexecParams(
+            self.internal_connection,
+            query,
+            param_count,
+            param_types,
+            param_values,
+            param_lengths,
+            param_formats,
+            result_format,
+        );
+        RawResult::new(ptr, self)
+    }
+
     pub unsafe fn prepare(
         &self,
         stmt_name: *const libc::c_char,
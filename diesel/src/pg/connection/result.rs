@@ -45,15 +45,23 @@ impl PgResult {
     }
 
     pub fn rows_affected(&self) -> usize {
+        self.rows_affected_if_reported().unwrap_or(0)
+    }
+
+    /// Returns `None` for commands which have no concept of affected rows,
+    /// such as DDL statements.
+    pub fn rows_affected_if_reported(&self) -> Option<usize> {
         unsafe {
             let count_char_ptr = PQcmdTuples(self.internal_result.as_ptr());
             let count_bytes = CStr::from_ptr(count_char_ptr).to_bytes();
             let count_str = str::from_utf8_unchecked(count_bytes);
             match count_str {
-                "" => 0,
-                _ => count_str
-                    .parse()
-                    .expect("Error parsing `rows_affected` as integer value"),
+                "" => None,
+                _ => Some(
+                    count_str
+                        .parse()
+                        .expect("Error parsing `rows_affected` as integer value"),
+                ),
             }
         }
     }
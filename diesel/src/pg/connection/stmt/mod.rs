@@ -11,10 +11,18 @@ use result::QueryResult;
 pub use super::raw::RawConnection;
 
 pub struct Statement {
-    name: CString,
+    kind: StatementKind,
     param_formats: Vec<libc::c_int>,
 }
 
+enum StatementKind {
+    Prepared(CString),
+    Unprepared {
+        sql: CString,
+        param_types: Vec<pq_sys::Oid>,
+    },
+}
+
 impl Statement {
     #[cfg_attr(feature = "clippy", allow(ptr_arg))]
     pub fn execute(
@@ -37,14 +45,28 @@ impl Statement {
             })
             .collect::<Vec<_>>();
         let internal_res = unsafe {
-            conn.exec_prepared(
-                self.name.as_ptr(),
-                params_pointer.len() as libc::c_int,
-                params_pointer.as_ptr(),
-                param_lengths.as_ptr(),
-                self.param_formats.as_ptr(),
-                1,
-            )
+            match self.kind {
+                StatementKind::Prepared(ref name) => conn.exec_prepared(
+                    name.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+                StatementKind::Unprepared {
+                    ref sql,
+                    ref param_types,
+                } => conn.exec_params(
+                    sql.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    param_types_to_ptr(Some(param_types)),
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+            }
         };
 
         PgResult::new(internal_res?)
@@ -72,10 +94,35 @@ impl Statement {
         try!(PgResult::new(internal_result?));
 
         Ok(Statement {
-            name: name,
+            kind: StatementKind::Prepared(name),
+            param_formats: vec![1; param_types.len()],
+        })
+    }
+
+    /// Creates a statement which is parsed, bound and executed in a single
+    /// round trip each time it is run, without ever creating a prepared
+    /// statement on the server.
+    pub fn unprepared(sql: &str, param_types: &[PgTypeMetadata]) -> QueryResult<Self> {
+        Ok(Statement {
+            kind: StatementKind::Unprepared {
+                sql: try!(CString::new(sql)),
+                param_types: param_types.iter().map(|x| x.oid).collect(),
+            },
             param_formats: vec![1; param_types.len()],
         })
     }
+
+    /// The name of the prepared statement on the server, or `None` if this
+    /// uses the unnamed statement or was never prepared.
+    pub fn name(&self) -> Option<&str> {
+        match self.kind {
+            StatementKind::Prepared(ref name) => match name.to_str() {
+                Ok("") | Err(_) => None,
+                Ok(name) => Some(name),
+            },
+            StatementKind::Unprepared { .. } => None,
+        }
+    }
 }
 
 fn param_types_to_ptr(param_types: Option<&Vec<u32>>) -> *const pq_sys::Oid {
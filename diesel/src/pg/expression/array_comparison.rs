@@ -1,4 +1,4 @@
-use expression::{AsExpression, Expression, NonAggregate};
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
 use pg::Pg;
 use query_builder::*;
 use result::QueryResult;
@@ -155,3 +155,62 @@ where
     Expr: NonAggregate,
 {
 }
+
+macro_rules! subquery_comparison {
+    ($name:ident, $keyword:expr) => {
+        #[doc(hidden)]
+        #[derive(Debug, Copy, Clone)]
+        pub struct $name<Q> {
+            query: Q,
+        }
+
+        impl<Q> $name<Q> {
+            pub(crate) fn new(query: Q) -> Self {
+                $name { query: query }
+            }
+        }
+
+        impl<Q: SelectQuery> Expression for $name<Q> {
+            type SqlType = Q::SqlType;
+        }
+
+        impl<Q> QueryFragment<Pg> for $name<Q>
+        where
+            Q: QueryFragment<Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+                out.push_sql($keyword);
+                out.push_sql("(");
+                self.query.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl_query_id!($name<Q>);
+
+        impl<Q, QS> SelectableExpression<QS> for $name<Q>
+        where
+            $name<Q>: AppearsOnTable<QS>,
+            Q: SelectableExpression<QS>,
+        {
+        }
+
+        impl<Q, QS> AppearsOnTable<QS> for $name<Q>
+        where
+            $name<Q>: Expression,
+            Q: AppearsOnTable<QS>,
+        {
+        }
+
+        impl<Q> NonAggregate for $name<Q>
+        where
+            $name<Q>: Expression,
+            Q: NonAggregate,
+        {
+        }
+    }
+}
+
+subquery_comparison!(AnySubquery, "ANY");
+subquery_comparison!(AllSubquery, "ALL");
@@ -1,8 +1,11 @@
-use expression::{Expression, NonAggregate};
+use std::marker::PhantomData;
+
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use expression::functions::extract::date_part::DateTruncPrecision;
 use pg::Pg;
 use query_builder::*;
 use result::QueryResult;
-use types::{Date, Timestamp, Timestamptz, VarChar};
+use types::{Date, Interval, NotNull, Nullable, Timestamp, Timestamptz, VarChar};
 
 /// Marker trait for types which are valid in `AT TIME ZONE` expressions
 pub trait DateTimeLike {}
@@ -55,3 +58,116 @@ where
 
 impl_query_id!(AtTimeZone<Ts, Tz>);
 impl_selectable_expression!(AtTimeZone<Ts, Tz>);
+
+/// Marker trait for types which can be passed to `date_trunc`. The result of
+/// `date_trunc` has the same type as its argument.
+pub trait DateTruncatable {}
+impl DateTruncatable for Timestamp {}
+impl DateTruncatable for Timestamptz {}
+impl DateTruncatable for Interval {}
+impl<T: DateTruncatable + NotNull> DateTruncatable for Nullable<T> {}
+
+/// Represents the PostgreSQL `date_trunc` function, which truncates a
+/// timestamp or interval to the given precision.
+///
+/// The precision is one of the types in
+/// [`date_part`](date_part/index.html). The result has
+/// the same type as the truncated expression, so truncating a `Timestamptz`
+/// returns a `Timestamptz`, which is truncated in the connection's time zone.
+/// This is commonly used to group rows into buckets, for example by hour or
+/// by day.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use diesel::dsl::*;
+/// use diesel::dsl::date_part::Hour;
+/// use diesel::types::Timestamp;
+///
+/// let timestamp = sql::<Timestamp>("TIMESTAMP '2017-08-21 12:34:56'");
+/// let truncated = diesel::select(date_trunc(Hour, timestamp).eq(
+///     sql::<Timestamp>("TIMESTAMP '2017-08-21 12:00:00'"),
+/// )).get_result(&connection);
+/// assert_eq!(Ok(true), truncated);
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+pub fn date_trunc<P, T>(_precision: P, expr: T) -> DateTrunc<P, T>
+where
+    P: DateTruncPrecision,
+    T: Expression,
+    T::SqlType: DateTruncatable,
+{
+    DateTrunc {
+        precision: PhantomData,
+        expr: expr,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct DateTrunc<P, T> {
+    precision: PhantomData<P>,
+    expr: T,
+}
+
+impl<P, T> Expression for DateTrunc<P, T>
+where
+    T: Expression,
+    T::SqlType: DateTruncatable,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<P, T> NonAggregate for DateTrunc<P, T>
+where
+    T: NonAggregate,
+    DateTrunc<P, T>: Expression,
+{
+}
+
+impl<P, T> QueryFragment<Pg> for DateTrunc<P, T>
+where
+    P: DateTruncPrecision,
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("date_trunc('");
+        out.push_sql(P::NAME);
+        out.push_sql("', ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(DateTrunc<P, T>);
+
+impl<P, T, QS> SelectableExpression<QS> for DateTrunc<P, T>
+where
+    T: SelectableExpression<QS>,
+    DateTrunc<P, T>: AppearsOnTable<QS>,
+{
+}
+
+impl<P, T, QS> AppearsOnTable<QS> for DateTrunc<P, T>
+where
+    T: AppearsOnTable<QS>,
+    DateTrunc<P, T>: Expression,
+{
+}
@@ -1,6 +1,9 @@
 use expression::{AsExpression, Expression};
+use expression::operators::{Eq, Gt, Lt, NotEq};
+use query_builder::SelectQuery;
+use super::array_comparison::{AllSubquery, AnySubquery};
 use super::operators::*;
-use types::{Array, Text};
+use types::{Array, Integer, Text};
 
 pub trait PgExpressionMethods: Expression + Sized {
     /// Creates a PostgreSQL `IS NOT DISTINCT FROM` expression. This behaves
@@ -68,6 +71,113 @@ pub trait PgExpressionMethods: Expression + Sized {
     {
         IsDistinctFrom::new(self, other.as_expression())
     }
+
+    /// Creates a PostgreSQL `= ANY(subquery)` expression.
+    ///
+    /// The subquery must select a single column of the same type as `self`.
+    /// Unlike [`eq_any`](../../expression_methods/trait.ExpressionMethods.html#method.eq_any),
+    /// which is meant for arrays or lists of values, this always compares
+    /// against the rows of the subquery. When no row matches and the
+    /// subquery returns a `NULL`, the result is `NULL` rather than `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::{posts, users};
+    /// #     let connection = establish_connection();
+    /// let rust_authors = posts::table
+    ///     .select(posts::user_id)
+    ///     .filter(posts::title.eq("About Rust"));
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.eq_any_subquery(rust_authors))
+    ///     .load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["Sean".to_string()]), names);
+    /// # }
+    /// ```
+    fn eq_any_subquery<Q>(self, subquery: Q) -> Eq<Self, AnySubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Eq::new(self, AnySubquery::new(subquery))
+    }
+
+    /// Creates a PostgreSQL `<> ALL(subquery)` expression.
+    ///
+    /// See [`eq_any_subquery`](#method.eq_any_subquery) for details.
+    fn ne_all_subquery<Q>(self, subquery: Q) -> NotEq<Self, AllSubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        NotEq::new(self, AllSubquery::new(subquery))
+    }
+
+    /// Creates a PostgreSQL `> ANY(subquery)` expression.
+    ///
+    /// See [`eq_any_subquery`](#method.eq_any_subquery) for details.
+    fn gt_any_subquery<Q>(self, subquery: Q) -> Gt<Self, AnySubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Gt::new(self, AnySubquery::new(subquery))
+    }
+
+    /// Creates a PostgreSQL `> ALL(subquery)` expression.
+    ///
+    /// The subquery must select a single column of the same type as `self`.
+    /// If the subquery returns no rows, the result is `true`. If any of its
+    /// rows is `NULL` and none are greater than or equal to `self`, the
+    /// result is `NULL`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::{posts, users};
+    /// #     let connection = establish_connection();
+    /// let rust_authors = posts::table
+    ///     .select(posts::user_id)
+    ///     .filter(posts::title.eq("About Rust"));
+    /// let names = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.gt_all_subquery(rust_authors))
+    ///     .load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+    /// # }
+    /// ```
+    fn gt_all_subquery<Q>(self, subquery: Q) -> Gt<Self, AllSubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Gt::new(self, AllSubquery::new(subquery))
+    }
+
+    /// Creates a PostgreSQL `< ANY(subquery)` expression.
+    ///
+    /// See [`eq_any_subquery`](#method.eq_any_subquery) for details.
+    fn lt_any_subquery<Q>(self, subquery: Q) -> Lt<Self, AnySubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Lt::new(self, AnySubquery::new(subquery))
+    }
+
+    /// Creates a PostgreSQL `< ALL(subquery)` expression.
+    ///
+    /// See [`gt_all_subquery`](#method.gt_all_subquery) for details.
+    fn lt_all_subquery<Q>(self, subquery: Q) -> Lt<Self, AllSubquery<Q>>
+    where
+        Q: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Lt::new(self, AllSubquery::new(subquery))
+    }
 }
 
 impl<T: Expression> PgExpressionMethods for T {}
@@ -264,69 +374,116 @@ pub trait ArrayExpressionMethods<ST>: Expression<SqlType = Array<ST>> + Sized {
     {
         IsContainedBy::new(self, other.as_expression())
     }
-}
-
-impl<T, ST> ArrayExpressionMethods<ST> for T
-where
-    T: Expression<SqlType = Array<ST>>,
-{
-}
-
-use expression::operators::{Asc, Desc};
 
-pub trait SortExpressionMethods: Sized {
-    /// Specify that nulls should come before other values in this ordering.
-    /// Normally, nulls come last when sorting in ascending order and first
-    /// when sorting in descending order.
+    /// Indexes a PostgreSQL array.
+    ///
+    /// This operator indexes in to an array to access a single element.
+    ///
+    /// Note that PostgreSQL arrays are 1-indexed. Indexing outside of the
+    /// bounds of the array returns `NULL` rather than an error, so the
+    /// resulting expression is always nullable.
     ///
     /// # Example
     ///
     /// ```rust
     /// # #[macro_use] extern crate diesel;
-    /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
-    /// #     users {
+    /// #     posts {
     /// #         id -> Integer,
-    /// #         name -> VarChar,
+    /// #         tags -> Array<VarChar>,
     /// #     }
     /// # }
     /// #
+    /// # // FIXME: We shouldn't need to define a users table here
     /// # table! {
-    /// #     foos {
+    /// #     users {
     /// #         id -> Integer,
-    /// #         foo -> Nullable<Integer>,
+    /// #         name -> VarChar,
     /// #     }
     /// # }
     /// #
+    /// # #[derive(Insertable)]
+    /// # #[table_name="posts"]
+    /// # struct NewPost<'a> { tags: Vec<&'a str> }
+    /// #
     /// # fn main() {
-    /// #     let connection = connection_no_data();
-    /// #     connection.execute("DROP TABLE IF EXISTS foos").unwrap();
-    /// connection.execute("CREATE TABLE foos (id SERIAL PRIMARY KEY, foo INTEGER)").unwrap();
-    /// connection.execute("INSERT INTO foos (foo) VALUES (NULL), (1), (2)").unwrap();
-    ///
-    /// #     use self::foos::dsl::*;
-    /// assert_eq!(Ok(vec![Some(1), Some(2), None]),
-    ///            foos.select(foo).order(foo.asc()).load(&connection));
-    /// assert_eq!(Ok(vec![None, Some(1), Some(2)]),
-    ///            foos.select(foo).order(foo.asc().nulls_first()).load(&connection));
-    /// #     connection.execute("DROP TABLE foos").unwrap();
+    /// #     use self::posts::dsl::*;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("DROP TABLE IF EXISTS posts").unwrap();
+    /// #     conn.execute("CREATE TABLE posts (id SERIAL PRIMARY KEY, tags TEXT[] NOT NULL)").unwrap();
+    /// #
+    /// diesel::insert_into(posts)
+    ///     .values(&vec![
+    ///         NewPost { tags: vec!["cool", "awesome"] },
+    ///     ])
+    ///     .execute(&conn)
+    ///     .unwrap();
+    ///
+    /// let first_tag = posts.select(tags.index(1)).first(&conn);
+    /// assert_eq!(Ok(Some("cool".to_string())), first_tag);
+    ///
+    /// let missing_tag = posts.select(tags.index(3)).first::<Option<String>>(&conn);
+    /// assert_eq!(Ok(None), missing_tag);
     /// # }
     /// ```
-    fn nulls_first(self) -> NullsFirst<Self> {
-        NullsFirst::new(self)
+    fn index<T>(self, index: T) -> ArrayIndex<Self, T::Expression>
+    where
+        T: AsExpression<Integer>,
+    {
+        ArrayIndex::new(self, index.as_expression())
     }
+}
 
-    /// Specify that nulls should come after other values in this ordering.
-    /// Normally, nulls come last when sorting in ascending order and first
-    /// when sorting in descending order.
+impl<T, ST> ArrayExpressionMethods<ST> for T
+where
+    T: Expression<SqlType = Array<ST>>,
+{
+}
+
+#[doc(hidden)]
+pub use expression_methods::SortExpressionMethods;
+
+pub trait PgTextExpressionMethods: Expression<SqlType = Text> + Sized {
+    /// Returns a SQL `ILIKE` expression
+    fn ilike<T: AsExpression<Text>>(self, other: T) -> ILike<Self, T::Expression> {
+        ILike::new(self.as_expression(), other.as_expression())
+    }
+
+    /// Returns a SQL `NOT ILIKE` expression
+    fn not_ilike<T: AsExpression<Text>>(self, other: T) -> NotILike<Self, T::Expression> {
+        NotILike::new(self.as_expression(), other.as_expression())
+    }
+
+    /// Returns a SQL `%` expression from the `pg_trgm` extension, which is
+    /// true when the two strings are similar to each other.
+    ///
+    /// Two strings are considered similar when the fraction of trigrams they
+    /// share is at least `pg_trgm.similarity_threshold` (0.3 by default).
+    /// The threshold can be changed for a single transaction with
+    /// [`PgConnection::set_similarity_threshold`]. The `pg_trgm` extension
+    /// must be installed in the database for this operator to exist.
+    ///
+    /// [`PgConnection::set_similarity_threshold`]: ../../struct.PgConnection.html#method.set_similarity_threshold
+    fn trigram_similar<T: AsExpression<Text>>(self, other: T) -> TrigramSimilar<Self, T::Expression> {
+        TrigramSimilar::new(self.as_expression(), other.as_expression())
+    }
+
+    /// Returns a SQL `SIMILAR TO` expression.
+    ///
+    /// `SIMILAR TO` patterns always match the whole string. Like `LIKE`, `_`
+    /// matches any single character and `%` matches any sequence of
+    /// characters. In addition, `|` separates alternatives, `*`, `+`, `?` and
+    /// `{m,n}` repeat the previous item, and `(...)` and `[...]` group items
+    /// and match character classes as they would in a regular expression. A
+    /// `\` escapes the character following it, so a literal `%` is written as
+    /// `\%`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # #[macro_use] extern crate diesel;
-    /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
@@ -336,45 +493,25 @@ pub trait SortExpressionMethods: Sized {
     /// #     }
     /// # }
     /// #
-    /// # table! {
-    /// #     foos {
-    /// #         id -> Integer,
-    /// #         foo -> Nullable<Integer>,
-    /// #     }
-    /// # }
-    /// #
     /// # fn main() {
-    /// #     let connection = connection_no_data();
-    /// #     connection.execute("DROP TABLE IF EXISTS foos").unwrap();
-    /// connection.execute("CREATE TABLE foos (id SERIAL PRIMARY KEY, foo INTEGER)").unwrap();
-    /// connection.execute("INSERT INTO foos (foo) VALUES (NULL), (1), (2)").unwrap();
-    ///
-    /// #     use self::foos::dsl::*;
-    /// assert_eq!(Ok(vec![None, Some(2), Some(1)]),
-    ///            foos.select(foo).order(foo.desc()).load(&connection));
-    /// assert_eq!(Ok(vec![Some(2), Some(1), None]),
-    ///            foos.select(foo).order(foo.desc().nulls_last()).load(&connection));
-    /// #     connection.execute("DROP TABLE foos").unwrap();
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let names = users.select(name).filter(name.similar_to("(S|J)%")).load(&connection);
+    /// assert_eq!(Ok(vec!["Sean".to_string()]), names);
     /// # }
     /// ```
-    fn nulls_last(self) -> NullsLast<Self> {
-        NullsLast::new(self)
-    }
-}
-
-impl<T> SortExpressionMethods for Asc<T> {}
-
-impl<T> SortExpressionMethods for Desc<T> {}
-
-pub trait PgTextExpressionMethods: Expression<SqlType = Text> + Sized {
-    /// Returns a SQL `ILIKE` expression
-    fn ilike<T: AsExpression<Text>>(self, other: T) -> ILike<Self, T::Expression> {
-        ILike::new(self.as_expression(), other.as_expression())
+    fn similar_to<T: AsExpression<Text>>(self, other: T) -> SimilarTo<Self, T::Expression> {
+        SimilarTo::new(self.as_expression(), other.as_expression())
     }
 
-    /// Returns a SQL `NOT ILIKE` expression
-    fn not_ilike<T: AsExpression<Text>>(self, other: T) -> NotILike<Self, T::Expression> {
-        NotILike::new(self.as_expression(), other.as_expression())
+    /// Returns a SQL `NOT SIMILAR TO` expression.
+    ///
+    /// See [`similar_to`](#method.similar_to) for the pattern syntax.
+    fn not_similar_to<T: AsExpression<Text>>(
+        self,
+        other: T,
+    ) -> NotSimilarTo<Self, T::Expression> {
+        NotSimilarTo::new(self.as_expression(), other.as_expression())
     }
 }
 
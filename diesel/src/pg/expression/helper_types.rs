@@ -6,3 +6,15 @@ pub type ILike<Lhs, Rhs> = super::operators::ILike<Lhs, AsExprOf<Rhs, VarChar>>;
 
 /// The return type of `lhs.not_ilike(rhs)`
 pub type NotILike<Lhs, Rhs> = super::operators::NotILike<Lhs, AsExprOf<Rhs, VarChar>>;
+
+/// The return type of `lhs.trigram_similar(rhs)`
+pub type TrigramSimilar<Lhs, Rhs> = super::operators::TrigramSimilar<Lhs, AsExprOf<Rhs, VarChar>>;
+
+/// The return type of `lhs.similar_to(rhs)`
+pub type SimilarTo<Lhs, Rhs> = super::operators::SimilarTo<Lhs, AsExprOf<Rhs, VarChar>>;
+
+/// The return type of `lhs.not_similar_to(rhs)`
+pub type NotSimilarTo<Lhs, Rhs> = super::operators::NotSimilarTo<Lhs, AsExprOf<Rhs, VarChar>>;
+
+/// The return type of `lhs.index(rhs)`
+pub type ArrayIndex<Lhs, Rhs> = super::operators::ArrayIndex<Lhs, AsExprOf<Rhs, ::types::Integer>>;
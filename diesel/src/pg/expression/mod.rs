@@ -6,8 +6,11 @@ pub mod extensions;
 pub mod operators;
 #[doc(hidden)]
 pub mod helper_types;
+#[doc(hidden)]
+pub mod nulls_default;
 
-mod date_and_time;
+#[doc(hidden)]
+pub mod date_and_time;
 
 /// PostgreSQL specific expression DSL methods. This module will be glob
 /// imported by [`diesel::dsl`](../../dsl/index.html) when
@@ -15,6 +18,8 @@ mod date_and_time;
 pub mod dsl {
     #[doc(inline)]
     pub use super::array_comparison::{all, any};
+    #[doc(inline)]
+    pub use super::date_and_time::date_trunc;
 
     pub use super::extensions::*;
 }
@@ -0,0 +1,48 @@
+use expression::operators::{Asc, Desc};
+use super::operators::{NullsFirst, NullsLast};
+
+/// Orderings which can have a default placement for `NULL` values applied to
+/// them by `.nulls_last_default()`.
+///
+/// Orderings created with `.asc()` or `.desc()` gain `NULLS LAST`. Orderings
+/// which already call `.nulls_first()` or `.nulls_last()` are left alone.
+/// This is also implemented for tuples of orderings.
+pub trait WithNullsLastDefault {
+    /// The ordering after the default has been applied
+    type Output;
+
+    /// Applies `NULLS LAST` to this ordering if it has no explicit placement
+    fn with_nulls_last_default(self) -> Self::Output;
+}
+
+impl<T> WithNullsLastDefault for Asc<T> {
+    type Output = NullsLast<Self>;
+
+    fn with_nulls_last_default(self) -> Self::Output {
+        NullsLast::new(self)
+    }
+}
+
+impl<T> WithNullsLastDefault for Desc<T> {
+    type Output = NullsLast<Self>;
+
+    fn with_nulls_last_default(self) -> Self::Output {
+        NullsLast::new(self)
+    }
+}
+
+impl<T> WithNullsLastDefault for NullsFirst<T> {
+    type Output = Self;
+
+    fn with_nulls_last_default(self) -> Self::Output {
+        self
+    }
+}
+
+impl<T> WithNullsLastDefault for NullsLast<T> {
+    type Output = Self;
+
+    fn with_nulls_last_default(self) -> Self::Output {
+        self
+    }
+}
@@ -1,4 +1,8 @@
+use expression::{Expression, NonAggregate};
 use pg::Pg;
+use query_builder::*;
+use result::QueryResult;
+use types::{Array, IntoNullable};
 
 diesel_infix_operator!(IsDistinctFrom, " IS DISTINCT FROM ", backend: Pg);
 diesel_infix_operator!(IsNotDistinctFrom, " IS NOT DISTINCT FROM ", backend: Pg);
@@ -7,5 +11,62 @@ diesel_infix_operator!(Contains, " @> ", backend: Pg);
 diesel_infix_operator!(IsContainedBy, " <@ ", backend: Pg);
 diesel_infix_operator!(ILike, " ILIKE ", backend: Pg);
 diesel_infix_operator!(NotILike, " NOT ILIKE ", backend: Pg);
-diesel_postfix_operator!(NullsFirst, " NULLS FIRST", (), backend: Pg);
-diesel_postfix_operator!(NullsLast, " NULLS LAST", (), backend: Pg);
+diesel_infix_operator!(TrigramSimilar, " % ", backend: Pg);
+diesel_infix_operator!(SimilarTo, " SIMILAR TO ", backend: Pg);
+diesel_infix_operator!(NotSimilarTo, " NOT SIMILAR TO ", backend: Pg);
+#[doc(hidden)]
+pub use expression::operators::{NullsFirst, NullsLast};
+
+/// The subscript operator of a PostgreSQL array, `array[index]`.
+///
+/// Since indexing outside of the bounds of the array returns `NULL`, the
+/// element type is always nullable.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayIndex<T, U> {
+    array: T,
+    index: U,
+}
+
+impl<T, U> ArrayIndex<T, U> {
+    pub fn new(array: T, index: U) -> Self {
+        ArrayIndex {
+            array: array,
+            index: index,
+        }
+    }
+}
+
+impl_query_id!(ArrayIndex<T, U>);
+impl_selectable_expression!(ArrayIndex<T, U>);
+
+impl<T, U, ST> Expression for ArrayIndex<T, U>
+where
+    T: Expression<SqlType = Array<ST>>,
+    U: Expression,
+    ST: IntoNullable,
+{
+    type SqlType = ST::Nullable;
+}
+
+impl<T, U> NonAggregate for ArrayIndex<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+{
+}
+
+impl<T, U> QueryFragment<Pg> for ArrayIndex<T, U>
+where
+    T: QueryFragment<Pg>,
+    U: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        // The parenthesis are required for anything other than a column
+        out.push_sql("(");
+        self.array.walk_ast(out.reborrow())?;
+        out.push_sql(")[");
+        self.index.walk_ast(out.reborrow())?;
+        out.push_sql("]");
+        Ok(())
+    }
+}
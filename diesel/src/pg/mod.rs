@@ -5,14 +5,17 @@ mod metadata_lookup;
 mod query_builder;
 mod connection;
 pub mod types;
-pub mod upsert;
+pub mod unnest;
+pub use query_builder::upsert;
 
 pub use self::backend::{Pg, PgTypeMetadata};
-pub use self::connection::PgConnection;
+pub use self::connection::{ClientEncoding, PgConnection, PgNotification, PreparedStatementMode};
 pub use self::metadata_lookup::PgMetadataLookup;
 pub use self::query_builder::PgQueryBuilder;
 
 pub mod data_types {
+    #[doc(inline)]
+    pub use super::types::bpchar::PgTrimmedChar;
     #[doc(inline)]
     pub use super::types::date_and_time::{PgDate, PgInterval, PgTime, PgTimestamp};
     #[doc(inline)]
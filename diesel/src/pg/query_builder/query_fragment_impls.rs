@@ -1,11 +1,81 @@
 use pg::Pg;
 use query_builder::{AstPass, QueryFragment};
-use query_builder::for_update_clause::ForUpdateClause;
+use query_builder::distinct_clause::DistinctOnClause;
+use query_builder::limit_clause::{LimitWithTiesClause, NoLimitClause};
+use query_builder::limit_offset_clause::LimitOffsetClause;
+use query_builder::locking_clause::{ForShare, ForUpdate, NoWait, SkipLocked};
+use query_builder::offset_clause::OffsetClause;
 use result::QueryResult;
 
-impl QueryFragment<Pg> for ForUpdateClause {
+impl QueryFragment<Pg> for ForUpdate {
     fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
         out.push_sql(" FOR UPDATE");
         Ok(())
     }
 }
+
+impl QueryFragment<Pg> for ForShare {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" FOR SHARE");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for SkipLocked {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" SKIP LOCKED");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for NoWait {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" NOWAIT");
+        Ok(())
+    }
+}
+
+impl<Expr> QueryFragment<Pg> for LimitWithTiesClause<Expr>
+where
+    Expr: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" FETCH FIRST ");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(" ROWS WITH TIES");
+        Ok(())
+    }
+}
+
+impl<L, O> QueryFragment<Pg> for LimitOffsetClause<LimitWithTiesClause<L>, O>
+where
+    LimitWithTiesClause<L>: QueryFragment<Pg>,
+    O: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.limit_clause.walk_ast(out.reborrow())?;
+        self.offset_clause.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<O> QueryFragment<Pg> for LimitOffsetClause<NoLimitClause, OffsetClause<O>>
+where
+    OffsetClause<O>: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.offset_clause.walk_ast(out)
+    }
+}
+
+impl<T> QueryFragment<Pg> for DistinctOnClause<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("DISTINCT ON (");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(") ");
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! Support for fixed length `CHAR(n)` values under PostgreSQL.
+use std::error::Error;
+use std::io::prelude::*;
+
+use pg::Pg;
+use types::{self, Bpchar, FromSql, IsNull, ToSql, ToSqlOutput};
+
+/// A `CHAR(n)` value with its trailing padding removed.
+///
+/// PostgreSQL pads `CHAR(n)` values with spaces up to the declared length.
+/// Loading this type instead of `String` strips those spaces, which matches
+/// how PostgreSQL itself compares these values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgTrimmedChar(pub String);
+
+// https://github.com/postgres/postgres/blob/502a3832cc54c7115dacb8a2dae06f0620995ac6/src/include/catalog/pg_type.h#L500
+primitive_impls!(Bpchar -> (String, pg: (1042, 1014)));
+queryable_impls!(Bpchar -> PgTrimmedChar);
+expression_impls!(Bpchar -> &'a str);
+expression_impls!(Bpchar -> PgTrimmedChar);
+
+impl FromSql<types::Bpchar, Pg> for String {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        <String as FromSql<types::Text, Pg>>::from_sql(bytes)
+    }
+}
+
+impl FromSql<types::Bpchar, Pg> for PgTrimmedChar {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        let mut string = <String as FromSql<types::Bpchar, Pg>>::from_sql(bytes)?;
+        let trimmed_len = string.trim_right_matches(' ').len();
+        string.truncate(trimmed_len);
+        Ok(PgTrimmedChar(string))
+    }
+}
+
+impl<'a> ToSql<types::Bpchar, Pg> for &'a str {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut ToSqlOutput<W, Pg>,
+    ) -> Result<IsNull, Box<Error + Send + Sync>> {
+        ToSql::<types::Text, Pg>::to_sql(self, out)
+    }
+}
+
+impl ToSql<types::Bpchar, Pg> for String {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut ToSqlOutput<W, Pg>,
+    ) -> Result<IsNull, Box<Error + Send + Sync>> {
+        ToSql::<types::Bpchar, Pg>::to_sql(&(self as &str), out)
+    }
+}
+
+impl ToSql<types::Bpchar, Pg> for PgTrimmedChar {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut ToSqlOutput<W, Pg>,
+    ) -> Result<IsNull, Box<Error + Send + Sync>> {
+        ToSql::<types::Bpchar, Pg>::to_sql(&self.0, out)
+    }
+}
+
+#[test]
+fn trimmed_char_removes_only_trailing_spaces() {
+    let value = <PgTrimmedChar as FromSql<types::Bpchar, Pg>>::from_sql(Some(&b" ab  "[..])).unwrap();
+    assert_eq!(PgTrimmedChar(" ab".to_string()), value);
+}
+
+#[test]
+fn string_keeps_char_padding() {
+    let value = <String as FromSql<types::Bpchar, Pg>>::from_sql(Some(&b"ab   "[..])).unwrap();
+    assert_eq!("ab   ", value);
+}
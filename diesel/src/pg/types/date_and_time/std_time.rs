@@ -3,10 +3,13 @@ use std::io::Write;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use pg::Pg;
-use types::{self, FromSql, IsNull, Timestamp, ToSql, ToSqlOutput};
+use super::PgInterval;
+use types::{self, FromSql, Interval, IsNull, Timestamp, ToSql, ToSqlOutput};
 
 expression_impls!(Timestamp -> SystemTime);
 queryable_impls!(Timestamp -> SystemTime);
+expression_impls!(Interval -> Duration);
+queryable_impls!(Interval -> Duration);
 
 fn pg_epoch() -> SystemTime {
     let thirty_years = Duration::from_secs(946_684_800);
@@ -45,6 +48,46 @@ impl FromSql<types::Timestamp, Pg> for SystemTime {
     }
 }
 
+/// PostgreSQL intervals are stored as separate months, days and microseconds
+/// components, while `Duration` is a flat span of time. Durations are always
+/// sent as a number of microseconds (any precision beyond that is truncated).
+impl ToSql<types::Interval, Pg> for Duration {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut ToSqlOutput<W, Pg>,
+    ) -> Result<IsNull, Box<Error + Send + Sync>> {
+        let seconds = self.as_secs();
+        if seconds > i64::max_value() as u64 / USEC_PER_SEC {
+            return Err(format!("{:?} is too large to be stored as an interval", self).into());
+        }
+        let interval = PgInterval::from_microseconds(duration_to_usecs(*self) as i64);
+        ToSql::<types::Interval, Pg>::to_sql(&interval, out)
+    }
+}
+
+/// Days are treated as exactly 24 hours. Since the length of a month varies,
+/// intervals with a months component cannot be represented as a `Duration`
+/// and will return an error, as will negative intervals.
+impl FromSql<types::Interval, Pg> for Duration {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        let interval = try!(<PgInterval as FromSql<types::Interval, Pg>>::from_sql(bytes));
+        if interval.months != 0 {
+            return Err(
+                "Intervals containing months cannot be converted to `std::time::Duration`".into(),
+            );
+        }
+        let usecs = i64::from(interval.days)
+            .checked_mul(USEC_PER_DAY)
+            .and_then(|days| days.checked_add(interval.microseconds));
+        match usecs {
+            Some(usecs) if usecs >= 0 => Ok(usecs_to_duration(usecs as u64)),
+            Some(_) => Err("Negative intervals cannot be converted to `std::time::Duration`".into()),
+            None => Err("Interval is too large to be converted to `std::time::Duration`".into()),
+        }
+    }
+}
+
+const USEC_PER_DAY: i64 = 86_400_000_000;
 const USEC_PER_SEC: u64 = 1_000_000;
 const NANO_PER_USEC: u32 = 1_000;
 
@@ -71,8 +114,9 @@ mod tests {
 
     use select;
     use dsl::{now, sql};
+    use expression::AsExpression;
     use prelude::*;
-    use types::Timestamp;
+    use types::{Interval, Timestamp};
 
     fn connection() -> PgConnection {
         dotenv().ok();
@@ -109,4 +153,33 @@ mod tests {
         let query = select(now.at_time_zone("utc").gt(time));
         assert!(query.get_result::<bool>(&connection).unwrap());
     }
+
+    #[test]
+    fn durations_round_trip_as_intervals() {
+        let connection = connection();
+        let duration = Duration::from_secs(90);
+
+        let query = select(sql::<Interval>("'90 seconds'::interval").eq(duration));
+        assert!(query.get_result::<bool>(&connection).unwrap());
+
+        let query = select(sql::<Interval>("'1 day 30 seconds'::interval"));
+        assert_eq!(
+            Ok(Duration::from_secs(86_430)),
+            query.get_result::<Duration>(&connection)
+        );
+
+        let round_tripped = select(AsExpression::<Interval>::as_expression(duration))
+            .get_result::<Duration>(&connection);
+        assert_eq!(Ok(duration), round_tripped);
+    }
+
+    #[test]
+    fn intervals_with_months_cannot_be_loaded_as_durations() {
+        let connection = connection();
+        let query = select(sql::<Interval>("'1 month'::interval"));
+        assert!(query.get_result::<Duration>(&connection).is_err());
+
+        let query = select(sql::<Interval>("'-1 second'::interval"));
+        assert!(query.get_result::<Duration>(&connection).is_err());
+    }
 }
@@ -1,4 +1,5 @@
 mod array;
+pub mod bpchar;
 mod ranges;
 pub mod date_and_time;
 pub mod floats;
@@ -125,8 +126,29 @@ pub mod sql_types {
     /// Alias for `Binary`, to ensure `infer_schema!` works
     pub type Bytea = ::types::Binary;
 
-    #[doc(hidden)]
-    pub type Bpchar = ::types::VarChar;
+    /// The fixed length `CHAR(n)` SQL type, which PostgreSQL calls `bpchar`.
+    ///
+    /// PostgreSQL pads values of this type with spaces up to the declared
+    /// length when they are stored, and ignores trailing spaces when they
+    /// are compared. Values loaded as `String` will include the padding. Load
+    /// into [`PgTrimmedChar`][PgTrimmedChar] instead to have the trailing
+    /// spaces removed.
+    ///
+    /// ### [`ToSql`](/diesel/types/trait.ToSql.html) impls
+    ///
+    /// - [`String`][String]
+    /// - [`&str`][str]
+    ///
+    /// ### [`FromSql`](/diesel/types/trait.FromSql.html) impls
+    ///
+    /// - [`String`][String]
+    /// - [`PgTrimmedChar`][PgTrimmedChar]
+    ///
+    /// [String]: https://doc.rust-lang.org/nightly/std/string/struct.String.html
+    /// [str]: https://doc.rust-lang.org/nightly/std/primitive.str.html
+    /// [PgTrimmedChar]: /diesel/pg/data_types/struct.PgTrimmedChar.html
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Bpchar;
 
     #[cfg(feature = "serde_json")]
     /// The JSON SQL type.  This type can only be used with `feature =
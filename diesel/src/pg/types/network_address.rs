@@ -3,7 +3,7 @@ extern crate libc;
 
 use std::io::prelude::*;
 use std::error::Error;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use pg::Pg;
 use types::{self, Cidr, FromSql, Inet, IsNull, MacAddr, ToSql, ToSqlOutput};
@@ -25,6 +25,10 @@ const PGSQL_AF_INET6: u8 = AF_INET + 1;
 primitive_impls!(MacAddr -> ([u8; 6], pg: (829, 1040)));
 primitive_impls!(Inet -> (IpNetwork, pg: (869, 1041)));
 primitive_impls!(Cidr -> (IpNetwork, pg: (650, 651)));
+queryable_impls!(Inet -> IpAddr);
+expression_impls!(Inet -> IpAddr);
+queryable_impls!(Cidr -> IpAddr);
+expression_impls!(Cidr -> IpAddr);
 
 macro_rules! err {
     () => (Err("invalid network address format".into()));
@@ -128,6 +132,23 @@ macro_rules! impl_Sql {
             }
         }
 
+        // Only the address is returned, the netmask is discarded
+        impl FromSql<$ty, Pg> for IpAddr {
+            fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+                <IpNetwork as FromSql<$ty, Pg>>::from_sql(bytes).map(|network| network.ip())
+            }
+        }
+
+        // The address is sent as a single host (`/32` or `/128`)
+        impl ToSql<$ty, Pg> for IpAddr {
+            fn to_sql<W: Write>(&self, out: &mut ToSqlOutput<W, Pg>) -> Result<IsNull, Box<Error + Send + Sync>> {
+                let network = match *self {
+                    IpAddr::V4(addr) => IpNetwork::V4(Ipv4Network::new(addr, 32)?),
+                    IpAddr::V6(addr) => IpNetwork::V6(Ipv6Network::new(addr, 128)?),
+                };
+                ToSql::<$ty, Pg>::to_sql(&network, out)
+            }
+        }
     }
 }
 
@@ -235,3 +256,25 @@ fn no_address_from_sql() {
     test_no_address_from_sql!(types::Inet);
     test_no_address_from_sql!(types::Cidr);
 }
+
+#[test]
+fn ipaddr_roundtrips_as_a_single_host() {
+    macro_rules! test_ipaddr_roundtrip {
+        ($ty: ty, $address: expr, $prefix: expr) => {
+            let input_address: IpAddr = $address.parse().unwrap();
+            let mut bytes = ToSqlOutput::test();
+            ToSql::<$ty, Pg>::to_sql(&input_address, &mut bytes).unwrap();
+            assert_eq!($prefix, bytes[1]);
+            let output_address: IpAddr = FromSql::<$ty, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+            assert_eq!(input_address, output_address);
+            let output_network: IpNetwork = FromSql::<$ty, Pg>::from_sql(Some(bytes.as_ref())).unwrap();
+            assert_eq!(input_address, output_network.ip());
+            assert_eq!($prefix, output_network.prefix());
+        }
+    }
+
+    test_ipaddr_roundtrip!(types::Inet, "127.0.0.1", 32);
+    test_ipaddr_roundtrip!(types::Cidr, "127.0.0.1", 32);
+    test_ipaddr_roundtrip!(types::Inet, "::1", 128);
+    test_ipaddr_roundtrip!(types::Cidr, "::1", 128);
+}
@@ -0,0 +1,104 @@
+//! Types related to inserting many rows by binding one array per column.
+//!
+//! See [`values_unnest`] for details.
+//!
+//! [`values_unnest`]: ../../query_builder/struct.IncompleteInsertStatement.html#method.values_unnest
+
+use std::marker::PhantomData;
+
+use query_builder::insert_statement::{IncompleteInsertStatement, InsertStatement};
+
+/// The values of an insert statement which uses `unnest`.
+///
+/// Returned by [`values_unnest`]. Each column is sent as a single array
+/// parameter, so the number of bind parameters doesn't depend on the number
+/// of rows being inserted.
+///
+/// [`values_unnest`]: ../../query_builder/struct.IncompleteInsertStatement.html#method.values_unnest
+#[derive(Debug, Clone)]
+pub struct UnnestValues<Columns, Arrays> {
+    pub(crate) columns: PhantomData<Columns>,
+    pub(crate) arrays: Arrays,
+    pub(crate) rows: usize,
+}
+
+/// Transposes a list of rows into one `Vec` per column.
+///
+/// This trait is implemented for `Vec`s of tuples. A `Vec<(A, B)>` becomes
+/// `(Vec<A>, Vec<B>)`. Rows with a single column must be passed as one
+/// element tuples (e.g. `vec![("Sean",), ("Tess",)]`).
+pub trait IntoColumnArrays {
+    /// The tuple of per column arrays
+    type Arrays;
+
+    /// The number of rows which will be transposed
+    fn row_count(&self) -> usize;
+
+    /// Performs the transposition
+    fn into_column_arrays(self) -> Self::Arrays;
+}
+
+impl<T, Op> IncompleteInsertStatement<T, Op> {
+    /// Inserts the given rows by binding one array per column and expanding
+    /// them with `unnest`.
+    ///
+    /// The generated SQL is
+    /// `INSERT INTO t (a, b) SELECT * FROM unnest($1, $2)`. Unlike
+    /// [`values`], which binds one parameter per column per row, this always
+    /// sends exactly one bind parameter per column. This avoids PostgreSQL's
+    /// limit of 65535 bind parameters, and results in the same SQL regardless
+    /// of the number of rows. The rows are given as tuples, and are transposed
+    /// into column arrays before the query is built. The columns must be
+    /// given in the same order as the elements of each row.
+    ///
+    /// This method is only available on PostgreSQL.
+    ///
+    /// [`values`]: #method.values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("DELETE FROM users").unwrap();
+    /// let new_users = vec![
+    ///     (3, "Ruby".to_string()),
+    ///     (4, "Pascal".to_string()),
+    /// ];
+    ///
+    /// let inserted_rows = diesel::insert_into(users)
+    ///     .values_unnest((id, name), new_users)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(2), inserted_rows);
+    ///
+    /// let names = users.select(name).order(id).load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["Ruby".to_string(), "Pascal".to_string()]), names);
+    /// # }
+    /// ```
+    pub fn values_unnest<Columns, Rows>(
+        self,
+        _columns: Columns,
+        rows: Rows,
+    ) -> InsertStatement<T, UnnestValues<Columns, Rows::Arrays>, Op>
+    where
+        Rows: IntoColumnArrays,
+    {
+        let values = UnnestValues {
+            columns: PhantomData,
+            rows: rows.row_count(),
+            arrays: rows.into_column_arrays(),
+        };
+        self.with_values(values)
+    }
+}
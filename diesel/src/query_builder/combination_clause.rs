@@ -0,0 +1,291 @@
+use backend::Backend;
+use expression::AsExpression;
+use expression::operators::{Asc, Desc};
+use query_builder::*;
+use query_builder::limit_clause::{LimitClause, NoLimitClause};
+use query_builder::limit_offset_clause::LimitOffsetClause;
+use query_builder::offset_clause::{NoOffsetClause, OffsetClause};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use query_builder::locking_clause::NoLockingClause;
+use query_dsl::*;
+use result::QueryResult;
+use types;
+
+type Limit = <i64 as AsExpression<types::BigInt>>::Expression;
+
+/// Queries which can be used as an operand of `UNION`, `INTERSECT` or
+/// `EXCEPT`. These are select statements without an `ORDER BY`, `LIMIT` or
+/// `OFFSET` clause, since SQLite and MySQL don't allow those on the operands
+/// of a set operation, and on PostgreSQL they would need to be parenthesized.
+pub trait CombinableQuery: Query {}
+
+impl<F, S, D, W, G> CombinableQuery
+    for SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G, NoLockingClause>
+where
+    Self: Query,
+{
+}
+
+/// Queries which can be used as the left hand side of `INTERSECT` without
+/// changing their meaning.
+///
+/// PostgreSQL and MySQL give `INTERSECT` a higher precedence than `UNION` and
+/// `EXCEPT`, while SQLite evaluates them from left to right. Since the left
+/// hand side can't be parenthesized on SQLite, only select statements and
+/// queries which are already combined with `INTERSECT` can be intersected.
+pub trait CombinableWithIntersect: Query {}
+
+impl<T: CombinableQuery> CombinableWithIntersect for T {}
+
+impl<S, R> CombinableWithIntersect for CombinationClause<Intersect, S, R>
+where
+    Self: Query,
+{
+}
+
+macro_rules! combinator {
+    ($name:ident, $sql:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $name;
+
+        impl<DB: Backend> QueryFragment<DB> for $name {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql($sql);
+                Ok(())
+            }
+        }
+
+        impl_query_id!($name);
+    }
+}
+
+combinator!(Union, " UNION ");
+combinator!(UnionAll, " UNION ALL ");
+combinator!(Intersect, " INTERSECT ");
+combinator!(Except, " EXCEPT ");
+
+/// Two queries combined with `UNION`, `UNION ALL`, `INTERSECT` or `EXCEPT`,
+/// as returned by the methods of [`CombineDsl`](../prelude/trait.CombineDsl.html).
+///
+/// The `ORDER BY`, `LIMIT` and `OFFSET` clauses of this query apply to the
+/// combined result.
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct CombinationClause<
+    Combinator,
+    Source,
+    Rhs,
+    Order = NoOrderClause,
+    Limit = NoLimitClause,
+    Offset = NoOffsetClause,
+> {
+    combinator: Combinator,
+    source: Source,
+    rhs: Rhs,
+    order: Order,
+    limit_offset: LimitOffsetClause<Limit, Offset>,
+}
+
+impl<Combinator, Source, Rhs> CombinationClause<Combinator, Source, Rhs> {
+    pub(crate) fn new(combinator: Combinator, source: Source, rhs: Rhs) -> Self {
+        CombinationClause {
+            combinator: combinator,
+            source: source,
+            rhs: rhs,
+            order: NoOrderClause,
+            limit_offset: LimitOffsetClause {
+                limit_clause: NoLimitClause,
+                offset_clause: NoOffsetClause,
+            },
+        }
+    }
+}
+
+impl<C, S, R, O, L, Of> Query for CombinationClause<C, S, R, O, L, Of>
+where
+    S: Query,
+{
+    type SqlType = S::SqlType;
+}
+
+impl<C, S, R, O, L, Of, DB> QueryFragment<DB> for CombinationClause<C, S, R, O, L, Of>
+where
+    DB: Backend,
+    C: QueryFragment<DB>,
+    S: QueryFragment<DB>,
+    R: QueryFragment<DB>,
+    O: QueryFragment<DB>,
+    LimitOffsetClause<L, Of>: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.source.walk_ast(out.reborrow())?;
+        self.combinator.walk_ast(out.reborrow())?;
+        self.rhs.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(CombinationClause<Combinator, Source, Rhs, Order, Limit, Offset>);
+
+impl<C, S, R> CombineDsl for CombinationClause<C, S, R>
+where
+    Self: Query,
+{
+    type Query = Self;
+
+    fn as_combinable_query(self) -> Self::Query {
+        self
+    }
+}
+
+impl<C, S, R, O, L, Of> LimitDsl for CombinationClause<C, S, R, O, L, Of> {
+    type Output = CombinationClause<C, S, R, O, LimitClause<Limit>, Of>;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            source: self.source,
+            rhs: self.rhs,
+            order: self.order,
+            limit_offset: LimitOffsetClause {
+                limit_clause: LimitClause(AsExpression::<types::BigInt>::as_expression(limit)),
+                offset_clause: self.limit_offset.offset_clause,
+            },
+        }
+    }
+}
+
+impl<C, S, R, O, L, Of> OffsetDsl for CombinationClause<C, S, R, O, L, Of> {
+    type Output = CombinationClause<C, S, R, O, L, OffsetClause<Limit>>;
+
+    fn offset(self, offset: i64) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            source: self.source,
+            rhs: self.rhs,
+            order: self.order,
+            limit_offset: LimitOffsetClause {
+                limit_clause: self.limit_offset.limit_clause,
+                offset_clause: OffsetClause(AsExpression::<types::BigInt>::as_expression(offset)),
+            },
+        }
+    }
+}
+
+impl<C, S, R, O, L, Of, Expr> PositionalOrderDsl<Expr> for CombinationClause<C, S, R, O, L, Of>
+where
+    Expr: IntoPositionalOrder,
+{
+    type Output = CombinationClause<C, S, R, OrderClause<Expr::Output>, L, Of>;
+
+    fn positional_order_by(self, expr: Expr) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            source: self.source,
+            rhs: self.rhs,
+            order: OrderClause(expr.into_positional_order()),
+            limit_offset: self.limit_offset,
+        }
+    }
+}
+
+/// Refers to a column of the result of a query by its position, starting at
+/// 1. This is rendered as `ORDER BY 1` and is used to order the combined
+/// result of a set operation with
+/// [`positional_order_by`](../prelude/trait.PositionalOrderDsl.html).
+///
+/// A plain `u32` can be passed to `positional_order_by` as well. This type is
+/// needed to specify the direction of the ordering, with `.asc()` or
+/// `.desc()`.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderColumn(u32);
+
+impl OrderColumn {
+    /// Sort by this column in ascending order
+    pub fn asc(self) -> Asc<Self> {
+        Asc::new(self)
+    }
+
+    /// Sort by this column in descending order
+    pub fn desc(self) -> Desc<Self> {
+        Desc::new(self)
+    }
+}
+
+impl From<u32> for OrderColumn {
+    fn from(position: u32) -> Self {
+        OrderColumn(position)
+    }
+}
+
+impl<DB: Backend> QueryFragment<DB> for OrderColumn {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(&self.0.to_string());
+        Ok(())
+    }
+}
+
+// The position is part of the SQL, so queries ordered by different positions
+// can't share a prepared statement.
+impl_query_id!(noop: OrderColumn);
+
+/// Types which can be passed to
+/// [`positional_order_by`](../prelude/trait.PositionalOrderDsl.html).
+pub trait IntoPositionalOrder {
+    #[doc(hidden)]
+    type Output;
+
+    #[doc(hidden)]
+    fn into_positional_order(self) -> Self::Output;
+}
+
+impl IntoPositionalOrder for u32 {
+    type Output = OrderColumn;
+
+    fn into_positional_order(self) -> Self::Output {
+        OrderColumn(self)
+    }
+}
+
+impl IntoPositionalOrder for OrderColumn {
+    type Output = Self;
+
+    fn into_positional_order(self) -> Self::Output {
+        self
+    }
+}
+
+impl IntoPositionalOrder for Asc<OrderColumn> {
+    type Output = Self;
+
+    fn into_positional_order(self) -> Self::Output {
+        self
+    }
+}
+
+impl IntoPositionalOrder for Desc<OrderColumn> {
+    type Output = Self;
+
+    fn into_positional_order(self) -> Self::Output {
+        self
+    }
+}
+
+macro_rules! tuple_positional_order {
+    ($($T:ident: $idx:tt),+) => {
+        impl<$($T: IntoPositionalOrder),+> IntoPositionalOrder for ($($T,)+) {
+            type Output = ($($T::Output,)+);
+
+            fn into_positional_order(self) -> Self::Output {
+                ($(self.$idx.into_positional_order(),)+)
+            }
+        }
+    }
+}
+
+tuple_positional_order!(A: 0, B: 1);
+tuple_positional_order!(A: 0, B: 1, C: 2);
+tuple_positional_order!(A: 0, B: 1, C: 2, D: 3);
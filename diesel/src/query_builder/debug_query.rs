@@ -11,6 +11,10 @@ use super::{AstPass, QueryBuilder, QueryFragment};
 /// The `Display` implementation will be the exact query sent to the server,
 /// plus a comment with the values of the bind parameters. The `Debug`
 /// implementation is more structured, and able to be pretty printed.
+///
+/// Formatting with the alternate flag (`{:#}`) will place each clause on its
+/// own line, and indent subqueries. This is only intended for logging, and
+/// does not change the query which is executed.
 pub struct DebugQuery<'a, T: 'a, DB> {
     query: &'a T,
     _marker: PhantomData<DB>,
@@ -35,8 +39,108 @@ where
         let mut query_builder = DB::QueryBuilder::default();
         QueryFragment::<DB>::to_sql(self.query, &mut query_builder).map_err(|_| fmt::Error)?;
         let debug_binds = DebugBinds::<_, DB>::new(self.query);
-        write!(f, "{} -- binds: {:?}", query_builder.finish(), debug_binds)
+        if f.alternate() {
+            let sql = pretty_print_sql(&query_builder.finish());
+            write!(f, "{}\n-- binds: {:?}", sql, debug_binds)
+        } else {
+            write!(f, "{} -- binds: {:?}", query_builder.finish(), debug_binds)
+        }
+    }
+}
+
+/// Keywords which start a new line when pretty printing, as long as they
+/// appear outside of a function call or parenthesized expression.
+const CLAUSE_KEYWORDS: &[&str] = &[
+    "FROM",
+    "INNER JOIN",
+    "LEFT OUTER JOIN",
+    "WHERE",
+    "GROUP BY",
+    "HAVING",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "ORDER BY",
+    "LIMIT",
+    "OFFSET",
+    "FETCH FIRST",
+    "FOR UPDATE",
+    "SET",
+    "VALUES",
+    "ON CONFLICT",
+    "RETURNING",
+];
+
+/// Words which never end a line, since the following keyword is part of the
+/// same construct (`IS DISTINCT FROM`, `DEFAULT VALUES`, `DO UPDATE SET`).
+const JOINED_WITH_NEXT_KEYWORD: &[&str] = &["DISTINCT", "DEFAULT", "UPDATE"];
+
+fn pretty_print_sql(sql: &str) -> String {
+    fn new_line(out: &mut String, indent: usize) {
+        out.push('\n');
+        for _ in 0..indent {
+            out.push_str("    ");
+        }
+    }
+
+    let mut out = String::with_capacity(sql.len());
+    // One entry per open parenthesis, `true` if it opened a subquery
+    let mut parens = Vec::new();
+    let mut indent = 0;
+    let mut quote = None;
+
+    for (i, c) in sql.char_indices() {
+        if let Some(q) = quote {
+            out.push(c);
+            if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        let rest = &sql[i + c.len_utf8()..];
+        match c {
+            '"' | '\'' | '`' => {
+                quote = Some(c);
+                out.push(c);
+            }
+            '(' => {
+                let is_subquery = rest.starts_with("SELECT ");
+                parens.push(is_subquery);
+                out.push(c);
+                if is_subquery {
+                    indent += 1;
+                    new_line(&mut out, indent);
+                }
+            }
+            ')' => {
+                if parens.pop() == Some(true) {
+                    indent -= 1;
+                    new_line(&mut out, indent);
+                }
+                out.push(c);
+            }
+            ' ' if parens.last().cloned().unwrap_or(true) => {
+                let starts_clause = CLAUSE_KEYWORDS.iter().any(|keyword| {
+                    rest.starts_with(keyword) && {
+                        let after = &rest[keyword.len()..];
+                        after.is_empty() || after.starts_with(' ') || after.starts_with('(')
+                    }
+                });
+                let joined = JOINED_WITH_NEXT_KEYWORD
+                    .iter()
+                    .any(|word| out.ends_with(word));
+                if starts_clause && !joined {
+                    new_line(&mut out, indent);
+                } else {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
     }
+
+    out
 }
 
 impl<'a, T, DB> Debug for DebugQuery<'a, T, DB>
@@ -90,3 +194,70 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::pretty_print_sql;
+
+    #[test]
+    fn clauses_are_placed_on_their_own_line() {
+        let sql = r#"SELECT "users"."name" FROM "users" WHERE "users"."id" = $1 ORDER BY "users"."name" LIMIT $2"#;
+        let expected = r#"SELECT "users"."name"
+FROM "users"
+WHERE "users"."id" = $1
+ORDER BY "users"."name"
+LIMIT $2"#;
+        assert_eq!(expected, pretty_print_sql(sql));
+    }
+
+    #[test]
+    fn subqueries_are_indented() {
+        let sql = r#"SELECT "users"."id" FROM "users" WHERE "users"."id" IN (SELECT "posts"."user_id" FROM "posts" WHERE "posts"."id" IN (SELECT 1 FROM "comments"))"#;
+        let expected = r#"SELECT "users"."id"
+FROM "users"
+WHERE "users"."id" IN (
+    SELECT "posts"."user_id"
+    FROM "posts"
+    WHERE "posts"."id" IN (
+        SELECT 1
+        FROM "comments"
+    )
+)"#;
+        assert_eq!(expected, pretty_print_sql(sql));
+    }
+
+    #[test]
+    fn keywords_inside_quotes_and_function_calls_are_left_alone() {
+        let sql = r#"SELECT 'a FROM b', "WHERE x", EXTRACT(YEAR FROM "users"."created_at") FROM "users" WHERE "users"."name" IS DISTINCT FROM $1"#;
+        let expected = r#"SELECT 'a FROM b', "WHERE x", EXTRACT(YEAR FROM "users"."created_at")
+FROM "users"
+WHERE "users"."name" IS DISTINCT FROM $1"#;
+        assert_eq!(expected, pretty_print_sql(sql));
+    }
+
+    #[test]
+    fn having_and_set_operations_start_a_new_line() {
+        let sql = r#"SELECT "posts"."user_id" FROM "posts" GROUP BY "posts"."user_id" HAVING COUNT("posts"."id") > $1 UNION ALL SELECT "users"."id" FROM "users" ORDER BY 1"#;
+        let expected = r#"SELECT "posts"."user_id"
+FROM "posts"
+GROUP BY "posts"."user_id"
+HAVING COUNT("posts"."id") > $1
+UNION ALL SELECT "users"."id"
+FROM "users"
+ORDER BY 1"#;
+        assert_eq!(expected, pretty_print_sql(sql));
+    }
+
+    #[test]
+    fn insert_statements_are_formatted() {
+        let sql = r#"INSERT INTO "users" ("name") VALUES ($1) ON CONFLICT DO NOTHING RETURNING "users"."id""#;
+        let expected = r#"INSERT INTO "users" ("name")
+VALUES ($1)
+ON CONFLICT DO NOTHING
+RETURNING "users"."id""#;
+        assert_eq!(expected, pretty_print_sql(sql));
+
+        let sql = r#"INSERT INTO "users" DEFAULT VALUES"#;
+        assert_eq!(sql, pretty_print_sql(sql));
+    }
+}
@@ -7,6 +7,50 @@ use query_builder::where_clause::*;
 use query_source::Table;
 use result::QueryResult;
 
+/// Represents a SQL `DELETE` statement.
+///
+/// The type parameters on this struct represent:
+///
+/// - `T`: The table we are deleting from.
+/// - `U`: The `WHERE` clause of this query.
+/// - `Ret`: The `RETURNING` clause of this query.
+///
+/// On backends which support the `RETURNING` clause, a delete statement can
+/// be loaded like any other query. If no `RETURNING` clause has been given,
+/// every column of the table is returned, so the deleted rows can be loaded
+/// into a `Queryable` struct representing the whole table. This is useful for
+/// moving rows to another location (e.g. archiving them) in one statement.
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # #[macro_use] extern crate diesel_codegen;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[derive(Queryable, Debug, PartialEq)]
+/// # struct User {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let deleted_users = diesel::delete(users)
+///     .filter(name.eq("Sean"))
+///     .get_results::<User>(&connection);
+/// assert_eq!(Ok(vec![User { id: 1, name: "Sean".into() }]), deleted_users);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
 #[derive(Debug)]
 pub struct DeleteStatement<T, U, Ret = NoReturningClause> {
     table: T,
@@ -75,6 +119,79 @@ where
     }
 }
 
+impl<T, U, Ret> LimitDsl for DeleteStatement<T, U, Ret> {
+    type Output = LimitedStatement<Self>;
+
+    /// Adds a `LIMIT` clause to the statement, so at most `limit` rows are
+    /// deleted. This is useful for deleting a large number of rows in
+    /// batches.
+    ///
+    /// This is only supported on MySQL and on SQLite libraries compiled with
+    /// `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. PostgreSQL has no equivalent, so
+    /// statements with a limit can't be executed there. On PostgreSQL, the
+    /// same can be achieved by filtering on a subquery which selects the
+    /// primary keys of the rows to delete, with a limit, as shown below.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "mysql")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let deleted_rows = diesel::delete(users)
+    ///     .limit(1)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), deleted_rows);
+    /// assert_eq!(Ok(1), users.count().get_result(&connection));
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "mysql"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// On PostgreSQL, with a subquery:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let ids_to_delete = users.select(id).order(id).limit(1);
+    /// let deleted_rows = diesel::delete(users.filter(id.eq_any(ids_to_delete)))
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), deleted_rows);
+    /// assert_eq!(Ok(vec![2]), users.select(id).load(&connection));
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    fn limit(self, limit: i64) -> Self::Output {
+        LimitedStatement::new(self, limit)
+    }
+}
+
 impl<T, U, Ret, DB> QueryFragment<DB> for DeleteStatement<T, U, Ret>
 where
     DB: Backend,
@@ -157,4 +274,14 @@ impl<T, U> DeleteStatement<T, U, NoReturningClause> {
             returning: ReturningClause(returns),
         }
     }
+
+    /// Makes executing this statement return an error unless it deletes
+    /// exactly `expected` rows.
+    ///
+    /// See [`UpdateStatement::require_affected`] for details.
+    ///
+    /// [`UpdateStatement::require_affected`]: struct.UpdateStatement.html#method.require_affected
+    pub fn require_affected(self, expected: usize) -> RequireAffected<Self> {
+        RequireAffected::new(self, expected)
+    }
 }
@@ -23,3 +23,10 @@ impl<DB: Backend> QueryFragment<DB> for DistinctClause {
 }
 
 impl_query_id!(DistinctClause);
+
+/// `DISTINCT ON (expr)`. The `QueryFragment` implementation lives with each
+/// backend which supports it.
+#[derive(Debug, Clone, Copy)]
+pub struct DistinctOnClause<T>(pub T);
+
+impl_query_id!(DistinctOnClause<T>);
@@ -209,6 +209,13 @@ pub fn delete<T: IntoUpdateTarget>(source: T) -> DeleteStatement<T::Table, T::Wh
 ///
 /// assert_eq!(Ok(1), rows_inserted);
 ///
+/// // The tuple can also be passed by value
+/// let rows_inserted = diesel::insert_into(users)
+///     .values((id.eq(3), name.eq("Ruby")))
+///     .execute(&connection);
+///
+/// assert_eq!(Ok(1), rows_inserted);
+///
 /// let new_users = vec![
 ///     (id.eq(2), name.eq("Tess")),
 ///     (id.eq(2), name.eq("Jim")),
@@ -222,6 +229,43 @@ pub fn delete<T: IntoUpdateTarget>(source: T) -> DeleteStatement<T::Table, T::Wh
 /// # }
 /// ```
 ///
+/// ### Using the column default for some rows
+///
+/// A value wrapped in `Option` uses the column's default when it is `None`.
+/// In a batch insert this is decided per row, so some rows can provide a
+/// value while others use `DEFAULT`. Diesel doesn't know which columns have
+/// a default, so inserting `None` into a column without one will fail if the
+/// column is `NOT NULL`.
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> Text,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use users::dsl::*;
+/// #     let connection = establish_connection();
+/// let new_users = vec![
+///     (name.eq("Tess"), Some(id.eq(10))),
+///     (name.eq("Jim"), None),
+/// ];
+///
+/// let rows_inserted = diesel::insert_into(users)
+///     .values(&new_users)
+///     .execute(&connection);
+/// assert_eq!(Ok(2), rows_inserted);
+///
+/// let tess_id = users.select(id).filter(name.eq("Tess")).order(id.desc()).first(&connection);
+/// assert_eq!(Ok(10), tess_id);
+/// # }
+/// ```
+///
 /// ### Using struct for values
 ///
 /// ```rust
@@ -1 +1,61 @@
 simple_clause!(NoGroupByClause, GroupByClause, " GROUP BY ");
+
+#[derive(Debug, Clone, Copy)]
+pub struct HavingClause<GroupBy, Predicate> {
+    pub group_by: GroupBy,
+    pub predicate: Predicate,
+}
+
+impl<GroupBy, Predicate, DB> QueryFragment<DB> for HavingClause<GroupBy, Predicate>
+where
+    DB: Backend,
+    GroupBy: QueryFragment<DB>,
+    Predicate: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.group_by.walk_ast(out.reborrow())?;
+        out.push_sql(" HAVING ");
+        self.predicate.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(HavingClause<GroupBy, Predicate>);
+
+/// Replaces the expression a query is grouped by. Any `HAVING` clause is
+/// kept, so calling `group_by` after `having` doesn't discard the predicate.
+pub trait ReplaceGroupBy<Expr> {
+    type Output;
+
+    fn replace_group_by(self, expr: Expr) -> Self::Output;
+}
+
+impl<Expr> ReplaceGroupBy<Expr> for NoGroupByClause {
+    type Output = GroupByClause<Expr>;
+
+    fn replace_group_by(self, expr: Expr) -> Self::Output {
+        GroupByClause(expr)
+    }
+}
+
+impl<G, Expr> ReplaceGroupBy<Expr> for GroupByClause<G> {
+    type Output = GroupByClause<Expr>;
+
+    fn replace_group_by(self, expr: Expr) -> Self::Output {
+        GroupByClause(expr)
+    }
+}
+
+impl<GroupBy, Predicate, Expr> ReplaceGroupBy<Expr> for HavingClause<GroupBy, Predicate>
+where
+    GroupBy: ReplaceGroupBy<Expr>,
+{
+    type Output = HavingClause<GroupBy::Output, Predicate>;
+
+    fn replace_group_by(self, expr: Expr) -> Self::Output {
+        HavingClause {
+            group_by: self.group_by.replace_group_by(expr),
+            predicate: self.predicate,
+        }
+    }
+}
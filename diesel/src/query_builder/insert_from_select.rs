@@ -0,0 +1,158 @@
+use backend::Backend;
+use expression::Expression;
+use insertable::{CanInsertInSingleQuery, InsertValues, Insertable};
+use query_builder::*;
+use query_builder::insert_statement::InsertStatement;
+use query_source::{Table, WritableColumn};
+use result::QueryResult;
+
+/// The values of an `INSERT INTO table (columns) SELECT ...` statement.
+///
+/// Created by passing a select statement to
+/// [`values`](struct.IncompleteInsertStatement.html#method.values). The
+/// columns which are inserted into must be given with
+/// [`into_columns`](struct.InsertStatement.html#method.into_columns) before
+/// the statement can be executed.
+#[derive(Debug, Clone, Copy)]
+pub struct InsertFromSelect<Select, Columns> {
+    query: Select,
+    columns: Columns,
+}
+
+impl<Select, Columns> InsertFromSelect<Select, Columns> {
+    fn new(query: Select, columns: Columns) -> Self {
+        InsertFromSelect { query, columns }
+    }
+}
+
+/// The columns of an [`InsertFromSelect`](struct.InsertFromSelect.html)
+/// before [`into_columns`](struct.InsertStatement.html#method.into_columns)
+/// has been called. An insert statement with these columns can't be executed.
+#[derive(Debug, Clone, Copy)]
+pub struct NoColumnList;
+
+impl<F, S, D, W, O, L, Of, G, FU, Tab> Insertable<Tab>
+    for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    Tab: Table,
+{
+    type Values = InsertFromSelect<Self, NoColumnList>;
+
+    fn values(self) -> Self::Values {
+        InsertFromSelect::new(self, NoColumnList)
+    }
+}
+
+impl<Select, Columns, DB> CanInsertInSingleQuery<DB> for InsertFromSelect<Select, Columns>
+where
+    DB: Backend,
+{
+    fn rows_to_insert(&self) -> usize {
+        // We can't know how many rows the query returns. This only needs to
+        // be non-zero, so the statement isn't skipped as an empty insert.
+        1
+    }
+}
+
+impl<Select, Columns, Tab, DB> InsertValues<Tab, DB> for InsertFromSelect<Select, Columns>
+where
+    Tab: Table,
+    DB: Backend,
+    Select: Query + QueryFragment<DB>,
+    Columns: ColumnList<Tab> + Expression<SqlType = Select::SqlType>,
+{
+    fn column_names(&self, out: &mut DB::QueryBuilder) -> QueryResult<()> {
+        self.columns.column_names::<DB>(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.query.walk_ast(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        false
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        false
+    }
+}
+
+impl<T, Select, Op, Ret> InsertStatement<T, InsertFromSelect<Select, NoColumnList>, Op, Ret> {
+    /// Sets the columns of the target table which the rows returned by the
+    /// select statement are inserted into.
+    ///
+    /// This must be called before an `INSERT INTO ... SELECT` statement can
+    /// be executed. The columns are matched with the selected expressions by
+    /// their position, and their SQL types must match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # table! {
+    /// #     posts {
+    /// #         id -> Integer,
+    /// #         user_id -> Integer,
+    /// #         title -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("DELETE FROM posts").unwrap();
+    /// let new_posts = users::table
+    ///     .select((users::id, users::name))
+    ///     .filter(users::name.eq("Sean"));
+    ///
+    /// diesel::insert_into(posts::table)
+    ///     .values(new_posts)
+    ///     .into_columns((posts::user_id, posts::title))
+    ///     .execute(&connection)
+    ///     .unwrap();
+    ///
+    /// let titles = posts::table.select(posts::title).load::<String>(&connection);
+    /// assert_eq!(Ok(vec![String::from("Sean")]), titles);
+    /// # }
+    /// ```
+    pub fn into_columns<C>(
+        self,
+        columns: C,
+    ) -> InsertStatement<T, InsertFromSelect<Select, C>, Op, Ret>
+    where
+        T: Table,
+        Select: Query,
+        C: ColumnList<T> + Expression<SqlType = Select::SqlType>,
+    {
+        self.replace_values(|values| InsertFromSelect::new(values.query, columns))
+    }
+}
+
+/// A column, or a tuple of columns, of `Table` which can be inserted into.
+pub trait ColumnList<Table> {
+    /// Adds the comma separated, unqualified names of the columns to the
+    /// query.
+    fn column_names<DB: Backend>(&self, out: &mut DB::QueryBuilder) -> QueryResult<()>;
+}
+
+impl<C> ColumnList<C::Table> for C
+where
+    C: WritableColumn,
+{
+    fn column_names<DB: Backend>(&self, out: &mut DB::QueryBuilder) -> QueryResult<()> {
+        out.push_identifier(C::NAME)
+    }
+}
@@ -14,6 +14,8 @@ use result::QueryResult;
 #[cfg(feature = "sqlite")]
 use sqlite::{Sqlite, SqliteConnection};
 use super::returning_clause::*;
+#[cfg(feature = "sqlite")]
+use super::upsert::OnConflictValues;
 
 /// The structure returned by [`insert_into`](../../fn.insert_into.html).
 #[derive(Debug, Clone, Copy)]
@@ -69,6 +71,11 @@ impl<T, Op> IncompleteInsertStatement<T, Op> {
     ///
     /// See the documentation of [`insert_into`](../fn.insert_into.html) for
     /// usage examples.
+    ///
+    /// A select statement can be passed as well, to insert the rows it returns
+    /// with `INSERT INTO table (columns) SELECT ...`, without loading them
+    /// first. See [`into_columns`](struct.InsertStatement.html#method.into_columns)
+    /// for details.
     pub fn values<U>(self, records: U) -> InsertStatement<T, U::Values, Op>
     where
         U: Insertable<T>,
@@ -80,6 +87,71 @@ impl<T, Op> IncompleteInsertStatement<T, Op> {
             NoReturningClause,
         )
     }
+
+    #[cfg(feature = "postgres")]
+    pub(crate) fn with_values<U>(self, values: U) -> InsertStatement<T, U, Op> {
+        InsertStatement::new(self.target, values, self.operator, NoReturningClause)
+    }
+}
+
+impl<T, Op> IncompleteInsertStatement<T, Op> {
+    /// Inserts every record produced by the given iterator in a single
+    /// statement.
+    ///
+    /// This behaves the same as passing a slice to [`values`], but the records
+    /// can come from any iterator (for example `.iter().map(...)` or
+    /// `.iter().filter(...)`) without first collecting them into a `Vec`.
+    /// The iterator is consumed immediately. As with slices, SQLite will
+    /// execute one insert per record inside of a transaction.
+    ///
+    /// [`values`]: #method.values
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("DELETE FROM users").unwrap();
+    /// let names = vec!["Ruby", "Pascal"];
+    /// let new_users = names.iter()
+    ///     .map(|n| NewUser { name: n.to_string() })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// diesel::insert_into(users)
+    ///     .values_from_iter(new_users.iter().filter(|u| u.name != "Pascal"))
+    ///     .execute(&connection)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Ok(vec!["Ruby".to_string()]), users.select(name).load(&connection));
+    /// # }
+    /// ```
+    pub fn values_from_iter<I>(
+        self,
+        records: I,
+    ) -> InsertStatement<T, BatchInsertValues<<I::Item as Insertable<T>>::Values>, Op>
+    where
+        I: IntoIterator,
+        I::Item: Insertable<T> + UndecoratedInsertRecord<T>,
+    {
+        let records = records.into_iter().map(Insertable::values).collect();
+        InsertStatement::new(
+            self.target,
+            BatchInsertValues::new(records),
+            self.operator,
+            NoReturningClause,
+        )
+    }
 }
 
 /// The structure returned by [`insert`](/diesel/fn.insert.html). The only thing that can be done with it
@@ -133,13 +205,16 @@ impl<T, U, Op, Ret> InsertStatement<T, U, Op, Ret> {
         }
     }
 
-    #[cfg(feature = "postgres")]
     pub(crate) fn replace_values<F, V>(self, f: F) -> InsertStatement<T, V, Op, Ret>
     where
         F: FnOnce(U) -> V,
     {
         InsertStatement::new(self.target, f(self.records), self.operator, self.returning)
     }
+
+    pub(crate) fn target(&self) -> &T {
+        &self.target
+    }
 }
 
 impl<T, U, Op, Ret, DB> QueryFragment<DB> for InsertStatement<T, U, Op, Ret>
@@ -162,6 +237,7 @@ where
         }
 
         self.operator.walk_ast(out.reborrow())?;
+        self.records.walk_insert_modifier(out.reborrow())?;
         out.push_sql(" INTO ");
         self.target.from_clause().walk_ast(out.reborrow())?;
         if self.records.is_noop() {
@@ -171,7 +247,10 @@ where
             if let Some(builder) = out.reborrow().query_builder() {
                 self.records.column_names(builder)?;
             }
-            out.push_sql(") VALUES ");
+            out.push_sql(") ");
+            if self.records.requires_values_keyword() {
+                out.push_sql("VALUES ");
+            }
             if self.records.requires_parenthesis() {
                 out.push_sql("(");
             }
@@ -210,6 +289,94 @@ where
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl<T, V, Op> ExecuteDsl<SqliteConnection> for InsertStatement<T, BatchInsertValues<V>, Op>
+where
+    InsertStatement<T, V, Op>: QueryFragment<Sqlite>,
+    T: Copy,
+    Op: Copy,
+{
+    fn execute(self, conn: &SqliteConnection) -> QueryResult<usize> {
+        use connection::Connection;
+        let target = self.target;
+        let operator = self.operator;
+        let returning = self.returning;
+        let records = self.records.records;
+        conn.transaction(|| {
+            let mut result = 0;
+            for record in records {
+                result += InsertStatement::new(target, record, operator, returning)
+                    .execute(conn)?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+// Like a plain batch insert, an upsert of several rows is executed one row at
+// a time on SQLite, since it doesn't support the `DEFAULT` keyword.
+#[cfg(feature = "sqlite")]
+impl<'a, T, U, Target, Action, Op> ExecuteDsl<SqliteConnection>
+    for InsertStatement<T, OnConflictValues<&'a [U], Target, Action>, Op>
+where
+    &'a U: Insertable<T>,
+    InsertStatement<T, OnConflictValues<<&'a U as Insertable<T>>::Values, Target, Action>, Op>:
+        QueryFragment<Sqlite>,
+    T: Copy,
+    Op: Copy,
+    Target: Clone,
+    Action: Clone,
+{
+    fn execute(self, conn: &SqliteConnection) -> QueryResult<usize> {
+        use connection::Connection;
+        conn.transaction(|| {
+            let mut result = 0;
+            let records: &'a [U] = *self.records.values();
+            for record in records {
+                result += InsertStatement::new(
+                    self.target,
+                    self.records.with_values(record.values()),
+                    self.operator,
+                    self.returning,
+                ).execute(conn)?;
+            }
+            Ok(result)
+        })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, V, Target, Action, Op> ExecuteDsl<SqliteConnection>
+    for InsertStatement<T, OnConflictValues<BatchInsertValues<V>, Target, Action>, Op>
+where
+    InsertStatement<T, OnConflictValues<V, Target, Action>, Op>: QueryFragment<Sqlite>,
+    T: Copy,
+    Op: Copy,
+    Target: Clone,
+    Action: Clone,
+{
+    fn execute(self, conn: &SqliteConnection) -> QueryResult<usize> {
+        use connection::Connection;
+        let target = self.target;
+        let operator = self.operator;
+        let returning = self.returning;
+        let on_conflict = self.records.with_values(());
+        let records = self.records.into_values().records;
+        conn.transaction(|| {
+            let mut result = 0;
+            for record in records {
+                result += InsertStatement::new(
+                    target,
+                    on_conflict.with_values(record),
+                    operator,
+                    returning,
+                ).execute(conn)?;
+            }
+            Ok(result)
+        })
+    }
+}
+
 impl_query_id!(noop: InsertStatement<T, U, Op, Ret>);
 
 impl<T, U, Op> AsQuery for InsertStatement<T, U, Op, NoReturningClause>
@@ -1 +1,9 @@
 simple_clause!(NoLimitClause, LimitClause, " LIMIT ");
+
+/// A limit clause which also returns any rows that tie with the last row
+/// according to the `ORDER BY` clause. The `QueryFragment` implementation
+/// lives with each backend which supports it.
+#[derive(Debug, Clone, Copy)]
+pub struct LimitWithTiesClause<Expr>(pub Expr);
+
+impl_query_id!(LimitWithTiesClause<Expr>);
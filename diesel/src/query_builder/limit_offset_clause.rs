@@ -0,0 +1,111 @@
+use backend::Backend;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+use super::limit_clause::{LimitClause, LimitWithTiesClause, NoLimitClause};
+use super::offset_clause::{NoOffsetClause, OffsetClause};
+
+/// The `LIMIT` and `OFFSET` clauses of a select statement.
+///
+/// These are rendered together, since SQLite and MySQL don't accept an
+/// `OFFSET` without a `LIMIT`. The implementation of `QueryFragment` for an
+/// `OFFSET` without a `LIMIT` lives with each backend.
+#[derive(Debug, Clone, Copy)]
+pub struct LimitOffsetClause<Limit, Offset> {
+    pub limit_clause: Limit,
+    pub offset_clause: Offset,
+}
+
+/// The `LIMIT` and `OFFSET` clauses of a boxed select statement.
+#[allow(missing_debug_implementations)]
+pub struct BoxedLimitOffsetClause<'a, DB> {
+    pub limit: Option<Box<QueryFragment<DB> + 'a>>,
+    pub offset: Option<Box<QueryFragment<DB> + 'a>>,
+}
+
+impl<DB> QueryFragment<DB> for LimitOffsetClause<NoLimitClause, NoOffsetClause>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<DB, L, O> QueryFragment<DB> for LimitOffsetClause<LimitClause<L>, O>
+where
+    DB: Backend,
+    LimitClause<L>: QueryFragment<DB>,
+    O: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.limit_clause.walk_ast(out.reborrow())?;
+        self.offset_clause.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<'a, DB> QueryFragment<DB> for BoxedLimitOffsetClause<'a, DB>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        match self.limit {
+            Some(ref limit) => limit.walk_ast(out.reborrow())?,
+            None => if self.offset.is_some() {
+                out.push_sql(DB::LIMIT_FOR_OFFSET_WITHOUT_LIMIT);
+            },
+        }
+        if let Some(ref offset) = self.offset {
+            offset.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a `LIMIT` or `OFFSET` clause into the form stored by
+/// [`BoxedLimitOffsetClause`](struct.BoxedLimitOffsetClause.html).
+pub trait IntoBoxedClause<'a, DB> {
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>>;
+}
+
+impl<'a, DB> IntoBoxedClause<'a, DB> for NoLimitClause {
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>> {
+        None
+    }
+}
+
+impl<'a, DB> IntoBoxedClause<'a, DB> for NoOffsetClause {
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>> {
+        None
+    }
+}
+
+impl<'a, DB, Expr> IntoBoxedClause<'a, DB> for LimitClause<Expr>
+where
+    DB: Backend,
+    LimitClause<Expr>: QueryFragment<DB> + 'a,
+{
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>> {
+        Some(Box::new(self))
+    }
+}
+
+impl<'a, DB, Expr> IntoBoxedClause<'a, DB> for LimitWithTiesClause<Expr>
+where
+    DB: Backend,
+    LimitWithTiesClause<Expr>: QueryFragment<DB> + 'a,
+{
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>> {
+        Some(Box::new(self))
+    }
+}
+
+impl<'a, DB, Expr> IntoBoxedClause<'a, DB> for OffsetClause<Expr>
+where
+    DB: Backend,
+    OffsetClause<Expr>: QueryFragment<DB> + 'a,
+{
+    fn into_boxed(self) -> Option<Box<QueryFragment<DB> + 'a>> {
+        Some(Box::new(self))
+    }
+}
@@ -0,0 +1,35 @@
+use expression::bound::Bound;
+use query_builder::*;
+use types::BigInt;
+
+/// An `UPDATE` or `DELETE` statement with a `LIMIT` clause.
+///
+/// Returned by `limit` on [`UpdateStatement`](struct.UpdateStatement.html)
+/// and [`DeleteStatement`](struct.DeleteStatement.html). Only MySQL and
+/// SQLite support limiting these statements, so this can't be executed on
+/// PostgreSQL. On SQLite, it requires a library compiled with
+/// `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. Otherwise executing it will return an
+/// error.
+#[derive(Debug, Clone, Copy)]
+pub struct LimitedStatement<Stmt> {
+    // Only read by the `QueryFragment` impls for MySQL and SQLite
+    #[cfg_attr(not(any(feature = "mysql", feature = "sqlite")), allow(dead_code))]
+    pub(crate) statement: Stmt,
+    #[cfg_attr(not(any(feature = "mysql", feature = "sqlite")), allow(dead_code))]
+    pub(crate) limit: Bound<BigInt, i64>,
+}
+
+impl<Stmt> LimitedStatement<Stmt> {
+    pub(crate) fn new(statement: Stmt, limit: i64) -> Self {
+        LimitedStatement {
+            statement,
+            limit: Bound::new(limit),
+        }
+    }
+}
+
+impl_query_id!(LimitedStatement<Stmt>);
+
+impl<Stmt: Query> Query for LimitedStatement<Stmt> {
+    type SqlType = Stmt::SqlType;
+}
@@ -0,0 +1,86 @@
+use backend::Backend;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoLockingClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoLockingClause {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoLockingClause);
+
+/// A row locking clause such as `FOR UPDATE SKIP LOCKED`. The lock modes are
+/// only implemented for backends which support row locking, the
+/// `QueryFragment` impls for them live in the backend modules.
+#[derive(Debug, Clone, Copy)]
+pub struct LockingClause<LockMode, Modifier = NoModifier> {
+    lock_mode: LockMode,
+    modifier: Modifier,
+}
+
+impl<LockMode, Modifier> LockingClause<LockMode, Modifier> {
+    pub(crate) fn new(lock_mode: LockMode, modifier: Modifier) -> Self {
+        LockingClause {
+            lock_mode: lock_mode,
+            modifier: modifier,
+        }
+    }
+}
+
+impl<LockMode> LockingClause<LockMode, NoModifier> {
+    pub(crate) fn with_modifier<Modifier>(
+        self,
+        modifier: Modifier,
+    ) -> LockingClause<LockMode, Modifier> {
+        LockingClause::new(self.lock_mode, modifier)
+    }
+}
+
+impl<DB, L, M> QueryFragment<DB> for LockingClause<L, M>
+where
+    DB: Backend,
+    L: QueryFragment<DB>,
+    M: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.lock_mode.walk_ast(out.reborrow())?;
+        self.modifier.walk_ast(out.reborrow())
+    }
+}
+
+impl_query_id!(LockingClause<LockMode, Modifier>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForUpdate;
+
+impl_query_id!(ForUpdate);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ForShare;
+
+impl_query_id!(ForShare);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoModifier;
+
+impl<DB: Backend> QueryFragment<DB> for NoModifier {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoModifier);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SkipLocked;
+
+impl_query_id!(SkipLocked);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoWait;
+
+impl_query_id!(NoWait);
@@ -11,37 +11,49 @@ mod clause_macro;
 
 mod ast_pass;
 pub mod bind_collector;
+pub(crate) mod combination_clause;
 mod debug_query;
 mod delete_statement;
 #[doc(hidden)]
 pub mod functions;
 #[doc(hidden)]
 pub mod nodes;
-mod distinct_clause;
-pub(crate) mod for_update_clause;
+pub(crate) mod distinct_clause;
 mod group_by_clause;
-mod limit_clause;
-mod offset_clause;
-mod order_clause;
-mod returning_clause;
+mod insert_from_select;
+pub(crate) mod limit_clause;
+pub(crate) mod limit_offset_clause;
+mod limited_statement;
+pub(crate) mod locking_clause;
+pub(crate) mod offset_clause;
+pub(crate) mod order_clause;
+mod require_affected;
+pub(crate) mod returning_clause;
 mod select_clause;
 mod select_statement;
 pub mod where_clause;
 pub mod insert_statement;
 pub mod update_statement;
+pub mod upsert;
 
 pub use self::ast_pass::AstPass;
 pub use self::bind_collector::BindCollector;
+pub use self::combination_clause::{CombinableQuery, CombinableWithIntersect, CombinationClause,
+                                    IntoPositionalOrder, OrderColumn};
 pub use self::debug_query::DebugQuery;
 pub use self::delete_statement::DeleteStatement;
 #[doc(inline)]
 pub use self::insert_statement::IncompleteInsertStatement;
+pub use self::insert_from_select::{ColumnList, InsertFromSelect, NoColumnList};
 pub use self::query_id::QueryId;
+pub use self::limited_statement::LimitedStatement;
+pub use self::require_affected::RequireAffected;
 #[doc(hidden)]
 pub use self::select_statement::{BoxedSelectStatement, SelectStatement};
 #[doc(inline)]
 pub use self::update_statement::{AsChangeset, Changeset, IncompleteUpdateStatement,
-                                 IntoUpdateTarget, UpdateStatement, UpdateTarget};
+                                 IntoUpdateTarget, UpdateStatement, UpdateTarget,
+                                 VersionedChangeset};
 
 use std::error::Error;
 
@@ -73,6 +85,52 @@ pub trait QueryBuilder<DB: Backend> {
     fn finish(self) -> String;
 }
 
+/// Quotes `identifier` according to the rules of the given backend.
+///
+/// This is intended for tools which need to build SQL dynamically from table
+/// or column names which are not known at compile time. Embedded quote
+/// characters are escaped by doubling them, so the result is always a single
+/// identifier. An error is returned if `identifier` contains a NUL byte, as
+/// no backend allows those in identifiers.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate diesel;
+/// # use diesel::query_builder::quote_identifier;
+/// #
+/// # fn main() {
+/// # #[cfg(feature = "postgres")]
+/// # {
+/// use diesel::pg::Pg;
+///
+/// assert_eq!(Ok(r#""users""#.to_string()), quote_identifier::<Pg>("users"));
+/// assert_eq!(Ok(r#""my ""table""""#.to_string()), quote_identifier::<Pg>(r#"my "table""#));
+/// assert!(quote_identifier::<Pg>("users\0").is_err());
+/// # }
+/// # #[cfg(feature = "mysql")]
+/// # {
+/// use diesel::mysql::Mysql;
+///
+/// assert_eq!(Ok("`my ``table```".to_string()), quote_identifier::<Mysql>("my `table`"));
+/// # }
+/// # }
+/// ```
+pub fn quote_identifier<DB>(identifier: &str) -> QueryResult<String>
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+{
+    if identifier.contains('\0') {
+        return Err(::result::Error::QueryBuilderError(
+            format!("Identifier {:?} contains a NUL byte", identifier).into(),
+        ));
+    }
+    let mut query_builder = DB::QueryBuilder::default();
+    query_builder.push_identifier(identifier)?;
+    Ok(query_builder.finish())
+}
+
 /// A complete SQL query with a return type. This can be a select statement, or
 /// a command such as `update` or `insert` with a `RETURNING` clause. Unlike
 /// [`Expression`](../expression/trait.Expression.html), types implementing this
@@ -85,6 +143,18 @@ impl<'a, T: Query> Query for &'a T {
     type SqlType = T::SqlType;
 }
 
+/// The SQL type of the rows returned by a `SELECT` statement used as a
+/// subquery.
+///
+/// Unlike [`Query`](trait.Query.html), this is also implemented when the
+/// `WHERE` clause references tables which are not in the statement's `FROM`
+/// clause. Such a statement is only valid as a correlated subquery, which is
+/// checked when the subquery is used as part of the outer query.
+#[doc(hidden)]
+pub trait SelectQuery {
+    type SqlType;
+}
+
 /// An untyped fragment of SQL. This may be a complete SQL command (such as
 /// an update statement without a `RETURNING` clause), or a subsection (such as
 /// our internal types used to represent a `WHERE` clause). All methods on
@@ -236,6 +306,39 @@ impl<T: Query> AsQuery for T {
 /// # }
 /// # }
 /// ```
+///
+/// ### Formatting SQL for logging:
+///
+/// Using the alternate flag (`{:#}`) with the `Display` implementation places
+/// each clause on its own line, and indents subqueries. The query sent to the
+/// server is not affected.
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # #[macro_use] extern crate diesel;
+/// # use diesel::*;
+/// # use schema::*;
+/// #
+/// # fn main() {
+/// #   use schema::users::dsl::*;
+/// let query = users.select(name).filter(id.eq(1)).order(name);
+/// let sql = format!("{:#}", debug_query::<DB, _>(&query));
+/// # if cfg!(feature = "postgres") {
+/// #     assert_eq!(sql, "SELECT \"users\".\"name\"\n\
+/// #         FROM \"users\"\n\
+/// #         WHERE \"users\".\"id\" = $1\n\
+/// #         ORDER BY \"users\".\"name\"\n\
+/// #         -- binds: [1]");
+/// # } else {
+/// assert_eq!(sql, "SELECT `users`.`name`\n\
+///     FROM `users`\n\
+///     WHERE `users`.`id` = ?\n\
+///     ORDER BY `users`.`name`\n\
+///     -- binds: [1]");
+/// # }
+/// # }
+/// ```
 pub fn debug_query<DB, T>(query: &T) -> DebugQuery<T, DB> {
     DebugQuery::new(query)
 }
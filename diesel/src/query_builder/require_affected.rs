@@ -0,0 +1,49 @@
+use connection::Connection;
+use query_dsl::ExecuteDsl;
+use result::{Error, QueryResult};
+
+/// A statement which returns an error when executed if it doesn't affect
+/// exactly the expected number of rows.
+///
+/// Returned by `require_affected` on
+/// [`UpdateStatement`](struct.UpdateStatement.html#method.require_affected)
+/// and [`DeleteStatement`](struct.DeleteStatement.html#method.require_affected).
+///
+/// This type can't be used as a query on its own. The only thing you can do
+/// with it is call [`execute`](#method.execute) on it.
+#[derive(Debug, Clone, Copy)]
+pub struct RequireAffected<Stmt> {
+    statement: Stmt,
+    expected: usize,
+}
+
+impl<Stmt> RequireAffected<Stmt> {
+    pub(crate) fn new(statement: Stmt, expected: usize) -> Self {
+        RequireAffected {
+            statement,
+            expected,
+        }
+    }
+
+    /// Executes the statement, returning the number of affected rows.
+    ///
+    /// Returns [`Error::UnexpectedRowCount`] if the number of affected rows
+    /// is not the expected one.
+    ///
+    /// [`Error::UnexpectedRowCount`]: ../result/enum.Error.html#variant.UnexpectedRowCount
+    pub fn execute<Conn>(self, conn: &Conn) -> QueryResult<usize>
+    where
+        Conn: Connection,
+        Stmt: ExecuteDsl<Conn>,
+    {
+        let actual = self.statement.execute(conn)?;
+        if actual == self.expected {
+            Ok(actual)
+        } else {
+            Err(Error::UnexpectedRowCount {
+                expected: self.expected,
+                actual,
+            })
+        }
+    }
+}
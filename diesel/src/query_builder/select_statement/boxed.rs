@@ -7,6 +7,7 @@ use query_builder::*;
 use query_builder::distinct_clause::DistinctClause;
 use query_builder::group_by_clause::GroupByClause;
 use query_builder::limit_clause::LimitClause;
+use query_builder::limit_offset_clause::BoxedLimitOffsetClause;
 use query_builder::offset_clause::OffsetClause;
 use query_builder::order_clause::OrderClause;
 use query_dsl::*;
@@ -22,8 +23,7 @@ pub struct BoxedSelectStatement<'a, ST, QS, DB> {
     distinct: Box<QueryFragment<DB> + 'a>,
     where_clause: Option<Box<QueryFragment<DB> + 'a>>,
     order: Box<QueryFragment<DB> + 'a>,
-    limit: Box<QueryFragment<DB> + 'a>,
-    offset: Box<QueryFragment<DB> + 'a>,
+    limit_offset: BoxedLimitOffsetClause<'a, DB>,
     group_by: Box<QueryFragment<DB> + 'a>,
     _marker: PhantomData<ST>,
 }
@@ -36,8 +36,7 @@ impl<'a, ST, QS, DB> BoxedSelectStatement<'a, ST, QS, DB> {
         distinct: Box<QueryFragment<DB> + 'a>,
         where_clause: Option<Box<QueryFragment<DB> + 'a>>,
         order: Box<QueryFragment<DB> + 'a>,
-        limit: Box<QueryFragment<DB> + 'a>,
-        offset: Box<QueryFragment<DB> + 'a>,
+        limit_offset: BoxedLimitOffsetClause<'a, DB>,
         group_by: Box<QueryFragment<DB> + 'a>,
     ) -> Self {
         BoxedSelectStatement {
@@ -46,8 +45,7 @@ impl<'a, ST, QS, DB> BoxedSelectStatement<'a, ST, QS, DB> {
             distinct: distinct,
             where_clause: where_clause,
             order: order,
-            limit: limit,
-            offset: offset,
+            limit_offset: limit_offset,
             group_by: group_by,
             _marker: PhantomData,
         }
@@ -62,6 +60,14 @@ where
     type SqlType = ST;
 }
 
+impl<'a, ST, QS, DB> SelectQuery for BoxedSelectStatement<'a, ST, QS, DB>
+where
+    DB: Backend,
+    DB: HasSqlType<ST>,
+{
+    type SqlType = ST;
+}
+
 impl<'a, ST, QS, DB> QueryFragment<DB> for BoxedSelectStatement<'a, ST, QS, DB>
 where
     DB: Backend,
@@ -82,8 +88,7 @@ where
 
         self.group_by.walk_ast(out.reborrow())?;
         self.order.walk_ast(out.reborrow())?;
-        self.limit.walk_ast(out.reborrow())?;
-        self.offset.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
@@ -104,8 +109,7 @@ where
 
         self.group_by.walk_ast(out.reborrow())?;
         self.order.walk_ast(out.reborrow())?;
-        self.limit.walk_ast(out.reborrow())?;
-        self.offset.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
@@ -130,8 +134,7 @@ where
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset,
             self.group_by,
         )
     }
@@ -164,8 +167,7 @@ where
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset,
             self.group_by,
         )
     }
@@ -198,7 +200,7 @@ where
 
     fn limit(mut self, limit: i64) -> Self::Output {
         let limit_expression = AsExpression::<BigInt>::as_expression(limit);
-        self.limit = Box::new(LimitClause(limit_expression));
+        self.limit_offset.limit = Some(Box::new(LimitClause(limit_expression)));
         self
     }
 }
@@ -212,7 +214,7 @@ where
 
     fn offset(mut self, offset: i64) -> Self::Output {
         let offset_expression = AsExpression::<BigInt>::as_expression(offset);
-        self.offset = Box::new(OffsetClause(offset_expression));
+        self.limit_offset.offset = Some(Box::new(OffsetClause(offset_expression)));
         self
     }
 }
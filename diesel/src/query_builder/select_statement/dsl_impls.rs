@@ -2,9 +2,10 @@ use associations::HasTable;
 use backend::Backend;
 use expression::*;
 use query_builder::distinct_clause::*;
-use query_builder::for_update_clause::*;
 use query_builder::group_by_clause::*;
 use query_builder::limit_clause::*;
+use query_builder::limit_offset_clause::{BoxedLimitOffsetClause, IntoBoxedClause};
+use query_builder::locking_clause::*;
 use query_builder::offset_clause::*;
 use query_builder::order_clause::*;
 use query_builder::select_clause::*;
@@ -32,8 +33,8 @@ where
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -55,8 +56,8 @@ where
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -77,8 +78,34 @@ where
             DistinctClause,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
+impl<ST, F, S, D, W, O, L, Of, G, FU, Expr> DistinctOnDsl<Expr>
+    for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    Expr: AppearsOnTable<F> + Clone,
+    Self: Expression<SqlType = ST>,
+    SelectStatement<F, S, DistinctOnClause<Expr>, W, OrderClause<Expr>, L, Of, G, FU>:
+        Expression<SqlType = ST>,
+{
+    type Output =
+        SelectStatement<F, S, DistinctOnClause<Expr>, W, OrderClause<Expr>, L, Of, G, FU>;
+
+    fn distinct_on(self, expr: Expr) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            DistinctOnClause(expr.clone()),
+            self.where_clause,
+            OrderClause(expr),
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -100,8 +127,8 @@ where
             self.distinct,
             self.where_clause.and(predicate),
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -143,8 +170,46 @@ where
             self.distinct,
             self.where_clause,
             order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
+impl<F, S, D, W, L, Of, G, FU, Expr> ThenOrderDsl<Expr>
+    for SelectStatement<F, S, D, W, NoOrderClause, L, Of, G, FU>
+where
+    Expr: Expression,
+    Self: OrderDsl<Expr>,
+{
+    type Output = <Self as OrderDsl<Expr>>::Output;
+
+    fn then_order_by(self, expr: Expr) -> Self::Output {
+        self.order(expr)
+    }
+}
+
+impl<ST, F, S, D, W, O, L, Of, G, FU, Expr> ThenOrderDsl<Expr>
+    for SelectStatement<F, S, D, W, OrderClause<O>, L, Of, G, FU>
+where
+    Expr: AppearsOnTable<F>,
+    Self: Expression<SqlType = ST>,
+    SelectStatement<F, S, D, W, OrderClause<(O, Expr)>, L, Of, G, FU>: Expression<SqlType = ST>,
+{
+    type Output = SelectStatement<F, S, D, W, OrderClause<(O, Expr)>, L, Of, G, FU>;
+
+    fn then_order_by(self, expr: Expr) -> Self::Output {
+        let order = OrderClause((self.order.0, expr));
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            order,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -170,7 +235,34 @@ where
             self.where_clause,
             self.order,
             limit_clause,
-            self.offset,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
+impl<ST, F, S, D, W, O, L, Of, G, FU> LimitWithTiesDsl
+    for SelectStatement<F, S, D, W, OrderClause<O>, L, Of, G, FU>
+where
+    Self: Expression<SqlType = ST>,
+    SelectStatement<F, S, D, W, OrderClause<O>, LimitWithTiesClause<Limit>, Of, G, FU>:
+        Expression<SqlType = ST>,
+{
+    type Output =
+        SelectStatement<F, S, D, W, OrderClause<O>, LimitWithTiesClause<Limit>, Of, G, FU>;
+
+    fn limit_with_ties(self, limit: i64) -> Self::Output {
+        let limit_clause =
+            LimitWithTiesClause(AsExpression::<types::BigInt>::as_expression(limit));
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
             self.for_update,
         )
@@ -195,7 +287,7 @@ where
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
+            self.limit_offset.limit_clause,
             offset_clause,
             self.group_by,
             self.for_update,
@@ -205,27 +297,55 @@ where
 
 impl<F, S, D, W, O, L, Of, G, Expr> GroupByDsl<Expr> for SelectStatement<F, S, D, W, O, L, Of, G>
 where
-    SelectStatement<F, S, D, W, O, L, Of, GroupByClause<Expr>>: Query,
-    Expr: Expression,
+    G: ReplaceGroupBy<Expr>,
+    SelectStatement<F, S, D, W, O, L, Of, G::Output>: Query,
+    Expr: AppearsOnTable<F>,
 {
-    type Output = SelectStatement<F, S, D, W, O, L, Of, GroupByClause<Expr>>;
+    type Output = SelectStatement<F, S, D, W, O, L, Of, G::Output>;
 
     fn group_by(self, expr: Expr) -> Self::Output {
-        let group_by = GroupByClause(expr);
+        let group_by = self.group_by.replace_group_by(expr);
         SelectStatement::new(
             self.select,
             self.from,
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             group_by,
             self.for_update,
         )
     }
 }
 
+impl<F, S, D, W, O, L, Of, G, Predicate> HavingDsl<Predicate>
+    for SelectStatement<F, S, D, W, O, L, Of, GroupByClause<G>>
+where
+    SelectStatement<F, S, D, W, O, L, Of, HavingClause<GroupByClause<G>, Predicate>>: Query,
+    Predicate: AppearsOnTable<F, SqlType = Bool>,
+{
+    type Output = SelectStatement<F, S, D, W, O, L, Of, HavingClause<GroupByClause<G>, Predicate>>;
+
+    fn having(self, predicate: Predicate) -> Self::Output {
+        let having = HavingClause {
+            group_by: self.group_by,
+            predicate: predicate,
+        };
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            having,
+            self.for_update,
+        )
+    }
+}
+
 impl<F, S, W, O, L, Of> ForUpdateDsl for SelectStatement<F, S, NoDistinctClause, W, O, L, Of> {
     type Output = SelectStatement<
         F,
@@ -236,7 +356,7 @@ impl<F, S, W, O, L, Of> ForUpdateDsl for SelectStatement<F, S, NoDistinctClause,
         L,
         Of,
         NoGroupByClause,
-        ForUpdateClause,
+        LockingClause<ForUpdate>,
     >;
 
     fn for_update(self) -> Self::Output {
@@ -246,10 +366,78 @@ impl<F, S, W, O, L, Of> ForUpdateDsl for SelectStatement<F, S, NoDistinctClause,
             self.distinct,
             self.where_clause,
             self.order,
-            self.limit,
-            self.offset,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            LockingClause::new(ForUpdate, NoModifier),
+        )
+    }
+}
+
+impl<F, S, W, O, L, Of> ForShareDsl for SelectStatement<F, S, NoDistinctClause, W, O, L, Of> {
+    type Output = SelectStatement<
+        F,
+        S,
+        NoDistinctClause,
+        W,
+        O,
+        L,
+        Of,
+        NoGroupByClause,
+        LockingClause<ForShare>,
+    >;
+
+    fn for_share(self) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            LockingClause::new(ForShare, NoModifier),
+        )
+    }
+}
+
+impl<F, S, D, W, O, L, Of, G, LockMode> SkipLockedDsl
+    for SelectStatement<F, S, D, W, O, L, Of, G, LockingClause<LockMode, NoModifier>>
+{
+    type Output = SelectStatement<F, S, D, W, O, L, Of, G, LockingClause<LockMode, SkipLocked>>;
+
+    fn skip_locked(self) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            self.for_update.with_modifier(SkipLocked),
+        )
+    }
+}
+
+impl<F, S, D, W, O, L, Of, G, LockMode> NoWaitDsl
+    for SelectStatement<F, S, D, W, O, L, Of, G, LockingClause<LockMode, NoModifier>>
+{
+    type Output = SelectStatement<F, S, D, W, O, L, Of, G, LockingClause<LockMode, NoWait>>;
+
+    fn no_wait(self) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
             self.group_by,
-            ForUpdateClause,
+            self.for_update.with_modifier(NoWait),
         )
     }
 }
@@ -262,8 +450,8 @@ where
     D: QueryFragment<DB> + 'a,
     W: Into<Option<Box<QueryFragment<DB> + 'a>>>,
     O: QueryFragment<DB> + 'a,
-    L: QueryFragment<DB> + 'a,
-    Of: QueryFragment<DB> + 'a,
+    L: IntoBoxedClause<'a, DB>,
+    Of: IntoBoxedClause<'a, DB>,
     G: QueryFragment<DB> + 'a,
 {
     type Output = BoxedSelectStatement<'a, S::SqlType, F, DB>;
@@ -275,8 +463,10 @@ where
             Box::new(self.distinct),
             self.where_clause.into(),
             Box::new(self.order),
-            Box::new(self.limit),
-            Box::new(self.offset),
+            BoxedLimitOffsetClause {
+                limit: self.limit_offset.limit_clause.into_boxed(),
+                offset: self.limit_offset.offset_clause.into_boxed(),
+            },
             Box::new(self.group_by),
         )
     }
@@ -291,8 +481,8 @@ where
     D: QueryFragment<DB> + 'a,
     W: Into<Option<Box<QueryFragment<DB> + 'a>>>,
     O: QueryFragment<DB> + 'a,
-    L: QueryFragment<DB> + 'a,
-    Of: QueryFragment<DB> + 'a,
+    L: IntoBoxedClause<'a, DB>,
+    Of: IntoBoxedClause<'a, DB>,
     G: QueryFragment<DB> + 'a,
 {
     type Output = BoxedSelectStatement<'a, <F::DefaultSelection as Expression>::SqlType, F, DB>;
@@ -304,8 +494,10 @@ where
             Box::new(self.distinct),
             self.where_clause.into(),
             Box::new(self.order),
-            Box::new(self.limit),
-            Box::new(self.offset),
+            BoxedLimitOffsetClause {
+                limit: self.limit_offset.limit_clause.into_boxed(),
+                offset: self.limit_offset.offset_clause.into_boxed(),
+            },
             Box::new(self.group_by),
         )
     }
@@ -8,7 +8,7 @@
 //! L: Limit Clause
 //! Of: Offset Clause
 //! G: Group By Clause
-//! FU: For Update Clause
+//! FU: For Update Clause (any row locking clause)
 mod dsl_impls;
 mod boxed;
 
@@ -20,14 +20,19 @@ use query_source::*;
 use query_source::joins::{AppendSelection, Inner, Join};
 use result::QueryResult;
 use super::distinct_clause::NoDistinctClause;
-use super::for_update_clause::NoForUpdateClause;
+use super::locking_clause::NoLockingClause;
 use super::group_by_clause::NoGroupByClause;
 use super::limit_clause::NoLimitClause;
+use super::limit_offset_clause::LimitOffsetClause;
 use super::offset_clause::NoOffsetClause;
 use super::order_clause::NoOrderClause;
+#[cfg(feature = "postgres")]
+use super::order_clause::OrderClause;
+#[cfg(feature = "postgres")]
+use pg::expression::nulls_default::WithNullsLastDefault;
 use super::select_clause::*;
 use super::where_clause::*;
-use super::{AstPass, Query, QueryFragment};
+use super::{AstPass, Query, QueryFragment, SelectQuery};
 
 #[derive(Debug, Clone, Copy)]
 #[doc(hidden)]
@@ -41,15 +46,14 @@ pub struct SelectStatement<
     Limit = NoLimitClause,
     Offset = NoOffsetClause,
     GroupBy = NoGroupByClause,
-    ForUpdate = NoForUpdateClause,
+    ForUpdate = NoLockingClause,
 > {
     select: Select,
     from: From,
     distinct: Distinct,
     where_clause: Where,
     order: Order,
-    limit: Limit,
-    offset: Offset,
+    limit_offset: LimitOffsetClause<Limit, Offset>,
     group_by: GroupBy,
     for_update: ForUpdate,
 }
@@ -73,8 +77,10 @@ impl<F, S, D, W, O, L, Of, G, FU> SelectStatement<F, S, D, W, O, L, Of, G, FU> {
             distinct: distinct,
             where_clause: where_clause,
             order: order,
-            limit: limit,
-            offset: offset,
+            limit_offset: LimitOffsetClause {
+                limit_clause: limit,
+                offset_clause: offset,
+            },
             group_by: group_by,
             for_update,
         }
@@ -92,7 +98,63 @@ impl<F> SelectStatement<F> {
             NoLimitClause,
             NoOffsetClause,
             NoGroupByClause,
-            NoForUpdateClause,
+            NoLockingClause,
+        )
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<F, S, D, W, O, L, Of, G, FU> SelectStatement<F, S, D, W, OrderClause<O>, L, Of, G, FU> {
+    /// Places `NULL` values last for every ordering in this query which
+    /// doesn't specify its own placement.
+    ///
+    /// This must be called after `.order()`. Each ordering must be created
+    /// with `.asc()` or `.desc()`, and orderings which already call
+    /// `.nulls_first()` or `.nulls_last()` keep their explicit placement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::pg::Pg;
+    /// #     use diesel::types::{Integer, Nullable};
+    /// let query = users
+    ///     .order((sql::<Nullable<Integer>>("rank").desc(), name.asc().nulls_first()))
+    ///     .nulls_last_default();
+    /// let sql = diesel::debug_query::<Pg, _>(&query).to_string();
+    /// assert!(sql.ends_with(
+    ///     r#"ORDER BY rank DESC NULLS LAST, "users"."name" ASC NULLS FIRST -- binds: []"#
+    /// ));
+    /// # }
+    /// ```
+    pub fn nulls_last_default(
+        self,
+    ) -> SelectStatement<F, S, D, W, OrderClause<O::Output>, L, Of, G, FU>
+    where
+        O: WithNullsLastDefault,
+    {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            OrderClause(self.order.0.with_nulls_last_default()),
+            self.limit_offset.limit_clause,
+            self.limit_offset.offset_clause,
+            self.group_by,
+            self.for_update,
         )
     }
 }
@@ -105,6 +167,13 @@ where
     type SqlType = S::SelectClauseSqlType;
 }
 
+impl<F, S, D, W, O, L, Of, G, FU> SelectQuery for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    S: SelectClauseExpression<F>,
+{
+    type SqlType = S::SelectClauseSqlType;
+}
+
 #[cfg(feature = "postgres")]
 impl<F, S, D, W, O, L, Of, G, FU> Expression for SelectStatement<F, S, D, W, O, L, Of, G, FU>
 where
@@ -131,8 +200,7 @@ where
     D: QueryFragment<DB>,
     W: QueryFragment<DB>,
     O: QueryFragment<DB>,
-    L: QueryFragment<DB>,
-    Of: QueryFragment<DB>,
+    LimitOffsetClause<L, Of>: QueryFragment<DB>,
     G: QueryFragment<DB>,
     FU: QueryFragment<DB>,
 {
@@ -145,8 +213,7 @@ where
         self.where_clause.walk_ast(out.reborrow())?;
         self.group_by.walk_ast(out.reborrow())?;
         self.order.walk_ast(out.reborrow())?;
-        self.limit.walk_ast(out.reborrow())?;
-        self.offset.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
         self.for_update.walk_ast(out.reborrow())?;
         Ok(())
     }
@@ -160,8 +227,7 @@ where
     D: QueryFragment<DB>,
     W: QueryFragment<DB>,
     O: QueryFragment<DB>,
-    L: QueryFragment<DB>,
-    Of: QueryFragment<DB>,
+    LimitOffsetClause<L, Of>: QueryFragment<DB>,
     G: QueryFragment<DB>,
     FU: QueryFragment<DB>,
 {
@@ -172,8 +238,7 @@ where
         self.where_clause.walk_ast(out.reborrow())?;
         self.group_by.walk_ast(out.reborrow())?;
         self.order.walk_ast(out.reborrow())?;
-        self.limit.walk_ast(out.reborrow())?;
-        self.offset.walk_ast(out.reborrow())?;
+        self.limit_offset.walk_ast(out.reborrow())?;
         self.for_update.walk_ast(out.reborrow())?;
         Ok(())
     }
@@ -1,6 +1,6 @@
 use backend::Backend;
 use query_builder::AstPass;
-use query_source::QuerySource;
+use query_source::{Column, QuerySource};
 use result::QueryResult;
 
 /// Types which can be passed to
@@ -29,6 +29,30 @@ pub trait AsChangeset {
     fn as_changeset(self) -> Self::Changeset;
 }
 
+/// A changeset which carries the version of the row it was loaded from, for
+/// optimistic locking with
+/// [`update.set_versioned`](/diesel/query_builder/struct.IncompleteUpdateStatement.html#method.set_versioned).
+///
+/// ### Deriving
+///
+/// This trait is implemented by `#[derive(AsChangeset)]` when one of the
+/// fields of the struct is annotated with `#[diesel(version)]`. That field
+/// must be an integer, and is not part of the changeset itself, since
+/// `set_versioned` increments the version column instead of assigning it.
+pub trait VersionedChangeset: AsChangeset {
+    /// The column holding the version of the row
+    type VersionColumn: Column + Copy;
+
+    /// The Rust type of the version
+    type Version;
+
+    /// Returns the column holding the version of the row
+    fn version_column(&self) -> Self::VersionColumn;
+
+    /// Returns the version of the row this changeset was loaded from
+    fn current_version(&self) -> Self::Version;
+}
+
 /// Apps should not need to concern themselves with this trait.
 pub trait Changeset<DB: Backend> {
     /// Does this changeset actually include any changes?
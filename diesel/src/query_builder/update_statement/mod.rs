@@ -1,18 +1,21 @@
 pub mod changeset;
 pub mod target;
 
-pub use self::changeset::{AsChangeset, Changeset};
+pub use self::changeset::{AsChangeset, Changeset, VersionedChangeset};
 pub use self::target::{IntoUpdateTarget, UpdateTarget};
 
 use backend::Backend;
-use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
+use expression::operators::Eq;
+use expression::ops::Add;
 use prelude::*;
 use query_builder::*;
 use query_builder::returning_clause::*;
 use query_builder::where_clause::*;
-use query_source::Table;
+use query_source::{Column, Table};
 use result::Error::QueryBuilderError;
 use result::QueryResult;
+use types::ops::Bitwise;
 
 /// The type returned by [`update`](/diesel/fn.update.html). The only thing you can do
 /// with this type is call `set` on it.
@@ -40,6 +43,113 @@ impl<T, U> IncompleteUpdateStatement<T, U> {
             returning: NoReturningClause,
         }
     }
+
+    /// Sets the values of a changeset which has a `#[diesel(version)]`
+    /// field, and turns this statement into an optimistically locked update
+    /// of a single row.
+    ///
+    /// The version field isn't assigned from the struct. Instead, the row is
+    /// only updated if its version column still holds the value of that
+    /// field, and the column is incremented. This is the same as calling
+    /// [`with_version`](struct.UpdateStatement.html#method.with_version)
+    /// with the version column and the value of the field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// table! {
+    ///     documents {
+    ///         id -> Integer,
+    ///         title -> Text,
+    ///         version -> BigInt,
+    ///     }
+    /// }
+    ///
+    /// #[derive(Queryable, AsChangeset)]
+    /// #[table_name = "documents"]
+    /// struct Document {
+    ///     id: i32,
+    ///     title: String,
+    ///     #[diesel(version)]
+    ///     version: i64,
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use self::documents::dsl::*;
+    /// #     use diesel::result::Error;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("CREATE TEMPORARY TABLE documents (
+    /// #         id INTEGER PRIMARY KEY,
+    /// #         title TEXT NOT NULL,
+    /// #         version BIGINT NOT NULL
+    /// #     )").unwrap();
+    /// #     connection.execute("INSERT INTO documents VALUES (1, 'Draft', 1)").unwrap();
+    /// let mut first_copy = documents.find(1).first::<Document>(&connection).unwrap();
+    /// let mut second_copy = documents.find(1).first::<Document>(&connection).unwrap();
+    ///
+    /// first_copy.title = "First edit".into();
+    /// let saved = diesel::update(documents.find(1))
+    ///     .set_versioned(&first_copy)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), saved);
+    ///
+    /// // `second_copy` was loaded before the first edit was saved
+    /// second_copy.title = "Second edit".into();
+    /// let conflict = diesel::update(documents.find(1))
+    ///     .set_versioned(&second_copy)
+    ///     .execute(&connection);
+    /// assert_eq!(Err(Error::UnexpectedRowCount { expected: 1, actual: 0 }), conflict);
+    ///
+    /// let row = documents.find(1).select((title, version)).first(&connection);
+    /// assert_eq!(Ok(("First edit".to_string(), 2)), row);
+    /// # }
+    /// ```
+    pub fn set_versioned<V>(
+        self,
+        values: V,
+    ) -> RequireAffected<
+        UpdateStatement<
+            T,
+            U::Output,
+            (
+                V::Changeset,
+                Eq<
+                    V::VersionColumn,
+                    Add<
+                        V::VersionColumn,
+                        <V::Version as AsExpression<
+                            <V::VersionColumn as Expression>::SqlType,
+                        >>::Expression,
+                    >,
+                >,
+            ),
+            NoReturningClause,
+        >,
+    >
+    where
+        T: Table,
+        V: changeset::VersionedChangeset + changeset::AsChangeset<Target = T>,
+        V::VersionColumn: Column<Table = T>,
+        <V::VersionColumn as Expression>::SqlType: Bitwise,
+        V::Version: AsExpression<<V::VersionColumn as Expression>::SqlType> + From<u8>,
+        U: WhereAnd<
+            Eq<
+                V::VersionColumn,
+                <V::Version as AsExpression<
+                    <V::VersionColumn as Expression>::SqlType,
+                >>::Expression,
+            >,
+        >,
+        UpdateStatement<T, U, V::Changeset, NoReturningClause>: AsQuery,
+    {
+        let version_column = values.version_column();
+        let current_version = values.current_version();
+        self.set(values).with_version(version_column, current_version)
+    }
 }
 
 impl<T, U, Predicate> FilterDsl<Predicate> for IncompleteUpdateStatement<T, U>
@@ -152,6 +262,23 @@ where
     }
 }
 
+impl<T, U, V, Ret> LimitDsl for UpdateStatement<T, U, V, Ret> {
+    type Output = LimitedStatement<Self>;
+
+    /// Adds a `LIMIT` clause to the statement, so at most `limit` rows are
+    /// updated.
+    ///
+    /// This has the same restrictions as
+    /// [`DeleteStatement::limit`](struct.DeleteStatement.html#method.limit):
+    /// it is only supported on MySQL and on SQLite libraries compiled with
+    /// `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. On PostgreSQL, filter the update on
+    /// a subquery which selects the primary keys of the rows to update, with a
+    /// limit, e.g. `update(users.filter(id.eq_any(users.select(id).limit(10))))`.
+    fn limit(self, limit: i64) -> Self::Output {
+        LimitedStatement::new(self, limit)
+    }
+}
+
 impl<T, U, V, Ret, DB> QueryFragment<DB> for UpdateStatement<T, U, V, Ret>
 where
     DB: Backend,
@@ -244,4 +371,155 @@ impl<T, U, V> UpdateStatement<T, U, V, NoReturningClause> {
             returning: ReturningClause(returns),
         }
     }
+
+    /// Makes executing this statement return an error unless it affects
+    /// exactly `expected` rows.
+    ///
+    /// This is useful for optimistic locking, where an update is filtered by
+    /// a version column and must touch exactly one row. When another
+    /// connection has already changed the row, the filter matches nothing,
+    /// and [`Error::UnexpectedRowCount`] is returned instead of `Ok(0)`.
+    ///
+    /// The statement has already run when the error is returned. If it
+    /// affected more rows than expected, run it inside of a transaction so
+    /// that the error rolls the changes back.
+    ///
+    /// [`Error::UnexpectedRowCount`]: ../result/enum.Error.html#variant.UnexpectedRowCount
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     use diesel::result::Error;
+    /// #     let connection = establish_connection();
+    /// let renamed = diesel::update(users.filter(id.eq(1)).filter(name.eq("Sean")))
+    ///     .set(name.eq("Jim"))
+    ///     .require_affected(1)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), renamed);
+    ///
+    /// // The name has already been changed, so this doesn't match any rows
+    /// let stale = diesel::update(users.filter(id.eq(1)).filter(name.eq("Sean")))
+    ///     .set(name.eq("Ruby"))
+    ///     .require_affected(1)
+    ///     .execute(&connection);
+    /// assert_eq!(Err(Error::UnexpectedRowCount { expected: 1, actual: 0 }), stale);
+    /// # }
+    /// ```
+    pub fn require_affected(self, expected: usize) -> RequireAffected<Self> {
+        RequireAffected::new(self, expected)
+    }
+
+    /// Turns this statement into an optimistically locked update of a single
+    /// row.
+    ///
+    /// `version_column` is an integer column (`SmallInt`, `Integer` or
+    /// `BigInt`) which is incremented every time the row is changed, and
+    /// `current_version` is its value when the row was loaded, such as an
+    /// `i64` for a `BigInt` column. The statement will only update the row if
+    /// that column still holds `current_version`, and will increment it as
+    /// part of the update. This is a shorthand for adding
+    /// `.filter(version_column.eq(current_version))`, assigning
+    /// `version_column.eq(version_column + 1)`, and calling
+    /// [`require_affected(1)`](#method.require_affected).
+    ///
+    /// When the changeset is a struct deriving `AsChangeset` with a
+    /// `#[diesel(version)]` field, use
+    /// [`set_versioned`](struct.IncompleteUpdateStatement.html#method.set_versioned)
+    /// instead.
+    ///
+    /// If another connection has updated the row since `current_version` was
+    /// loaded, nothing is updated, and executing the statement returns
+    /// [`Error::UnexpectedRowCount`] with an `actual` count of `0`.
+    ///
+    /// [`Error::UnexpectedRowCount`]: ../result/enum.Error.html#variant.UnexpectedRowCount
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// table! {
+    ///     documents {
+    ///         id -> Integer,
+    ///         title -> Text,
+    ///         version -> Integer,
+    ///     }
+    /// }
+    ///
+    /// # fn main() {
+    /// #     use self::documents::dsl::*;
+    /// #     use diesel::result::Error;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("CREATE TEMPORARY TABLE documents (
+    /// #         id INTEGER PRIMARY KEY,
+    /// #         title TEXT NOT NULL,
+    /// #         version INTEGER NOT NULL
+    /// #     )").unwrap();
+    /// #     connection.execute("INSERT INTO documents VALUES (1, 'Draft', 1)").unwrap();
+    /// let loaded_version = documents.find(1).select(version).first::<i32>(&connection).unwrap();
+    ///
+    /// let saved = diesel::update(documents.find(1))
+    ///     .set(title.eq("First edit"))
+    ///     .with_version(version, loaded_version)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), saved);
+    ///
+    /// // The row has been changed since `loaded_version` was read
+    /// let conflict = diesel::update(documents.find(1))
+    ///     .set(title.eq("Second edit"))
+    ///     .with_version(version, loaded_version)
+    ///     .execute(&connection);
+    /// assert_eq!(Err(Error::UnexpectedRowCount { expected: 1, actual: 0 }), conflict);
+    ///
+    /// let row = documents.find(1).select((title, version)).first(&connection);
+    /// assert_eq!(Ok(("First edit".to_string(), 2)), row);
+    /// # }
+    /// ```
+    pub fn with_version<Col, Version>(
+        self,
+        version_column: Col,
+        current_version: Version,
+    ) -> RequireAffected<
+        UpdateStatement<
+            T,
+            U::Output,
+            (
+                V,
+                Eq<Col, Add<Col, <Version as AsExpression<Col::SqlType>>::Expression>>,
+            ),
+            NoReturningClause,
+        >,
+    >
+    where
+        Col: Column<Table = T> + Copy,
+        Col::SqlType: Bitwise,
+        Version: AsExpression<Col::SqlType> + From<u8>,
+        U: WhereAnd<Eq<Col, <Version as AsExpression<Col::SqlType>>::Expression>>,
+    {
+        let version_matches = Eq::new(version_column, current_version.as_expression());
+        let increment_version = Eq::new(
+            version_column,
+            Add::new(version_column, Version::from(1).as_expression()),
+        );
+        let statement = UpdateStatement {
+            table: self.table,
+            where_clause: self.where_clause.and(version_matches),
+            values: (self.values, increment_version),
+            returning: self.returning,
+        };
+        statement.require_affected(1)
+    }
 }
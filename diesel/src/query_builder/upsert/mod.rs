@@ -0,0 +1,41 @@
+//! Types and functions related to the `ON CONFLICT` clause of `INSERT`
+//!
+//! See [the methods on `InsertStatement`](../insert_statement/struct.InsertStatement.html#impl-1)
+//! for usage examples.
+//!
+//! On PostgreSQL, `ON CONFLICT` requires 9.5 or later. On SQLite,
+//! `on_conflict_do_nothing` is lowered to `INSERT OR IGNORE`, which is
+//! supported by every version. Any form which names a conflict target
+//! requires SQLite 3.24 or later. Since SQLite can't insert several rows in
+//! one query, an upsert of several rows is executed one row at a time.
+//! `on_constraint` is only available on PostgreSQL.
+//!
+//! MySQL has no `ON CONFLICT` clause. `on_conflict_do_nothing` and
+//! `on_conflict(target).do_nothing()` are lowered to `INSERT IGNORE`, and
+//! `on_conflict(target).do_update().set(changes)` to
+//! `ON DUPLICATE KEY UPDATE changes`. MySQL doesn't take a conflict target, so
+//! a conflict with any unique index of the table will be ignored or cause the
+//! update to be applied. Filtered targets and `DO UPDATE ... WHERE` are not supported on
+//! MySQL. Note that MySQL reports 2 affected rows for each row which was
+//! updated instead of inserted.
+
+mod on_conflict_actions;
+mod on_conflict_clause;
+mod on_conflict_extension;
+mod on_conflict_target;
+#[cfg(feature = "postgres")]
+mod upsert_increment;
+
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+#[allow(deprecated)]
+pub use self::on_conflict_actions::{do_nothing, do_update};
+pub use self::on_conflict_actions::excluded;
+#[cfg(feature = "sqlite")]
+pub(crate) use self::on_conflict_clause::OnConflictValues;
+#[cfg(feature = "mysql")]
+pub use self::on_conflict_actions::values;
+pub use self::on_conflict_extension::*;
+#[cfg(feature = "postgres")]
+pub use self::on_conflict_target::on_constraint;
+#[cfg(feature = "postgres")]
+pub use self::upsert_increment::{upsert_increment, UpsertIncrement};
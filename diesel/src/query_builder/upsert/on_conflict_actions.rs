@@ -1,6 +1,11 @@
+use backend::{Backend, SupportsOnConflictClause};
 use expression::{AppearsOnTable, Expression};
+#[cfg(feature = "mysql")]
+use mysql::Mysql;
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 use pg::Pg;
 use query_builder::*;
+use query_builder::where_clause::{NoWhereClause, WhereAnd};
 use query_source::*;
 use result::QueryResult;
 
@@ -10,7 +15,7 @@ use result::QueryResult;
 /// nothing when *any* constraint conflicts, use
 /// [`on_conflict_do_nothing()`](trait.OnConflictExtension.html#method.on_conflict_do_nothing)
 /// instead.
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 #[deprecated(since = "0.99.0", note = "use `.values(...).on_conflict(...).do_nothing()` instead")]
 pub fn do_nothing() -> DoNothing {
     DoNothing
@@ -34,9 +39,10 @@ pub fn do_nothing() -> DoNothing {
 /// # #[macro_use] extern crate diesel_codegen;
 /// # include!("on_conflict_docs_setup.rs");
 /// #
+/// # #[cfg(feature = "postgres")]
 /// # fn main() {
 /// #     use users::dsl::*;
-/// use diesel::pg::upsert::*;
+/// use diesel::query_builder::upsert::*;
 ///
 /// #     let conn = establish_connection();
 /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -55,6 +61,8 @@ pub fn do_nothing() -> DoNothing {
 /// let users_in_db = users.load(&conn);
 /// assert_eq!(Ok(vec![(1, "I DONT KNOW ANYMORE".to_string())]), users_in_db);
 /// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
 /// ```
 ///
 /// ## Set `AsChangeset` struct on conflict
@@ -64,9 +72,10 @@ pub fn do_nothing() -> DoNothing {
 /// # #[macro_use] extern crate diesel_codegen;
 /// # include!("on_conflict_docs_setup.rs");
 /// #
+/// # #[cfg(feature = "postgres")]
 /// # fn main() {
 /// #     use users::dsl::*;
-/// use diesel::pg::upsert::*;
+/// use diesel::query_builder::upsert::*;
 ///
 /// #     let conn = establish_connection();
 /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -85,6 +94,8 @@ pub fn do_nothing() -> DoNothing {
 /// let users_in_db = users.load(&conn);
 /// assert_eq!(Ok(vec![(1, "Sean".to_string())]), users_in_db);
 /// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
 /// ```
 ///
 /// ## Use `excluded` to get the rejected value
@@ -94,9 +105,10 @@ pub fn do_nothing() -> DoNothing {
 /// # #[macro_use] extern crate diesel_codegen;
 /// # include!("on_conflict_docs_setup.rs");
 /// #
+/// # #[cfg(feature = "postgres")]
 /// # fn main() {
 /// #     use users::dsl::*;
-/// use diesel::pg::upsert::*;
+/// use diesel::query_builder::upsert::*;
 ///
 /// #     let conn = establish_connection();
 /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -117,64 +129,125 @@ pub fn do_nothing() -> DoNothing {
 /// let users_in_db = users.load(&conn);
 /// assert_eq!(Ok(vec![(1, "Sean".to_string()), (2, "Tess".to_string())]), users_in_db);
 /// # }
-#[cfg(feature = "with-deprecated")]
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 #[deprecated(since = "0.99.0", note = "use `.values(...).on_conflict(...).do_update()` instead")]
 pub fn do_update() -> IncompleteDoUpdate {
     IncompleteDoUpdate
 }
 
 /// Represents `excluded.column` in an `ON CONFLICT DO UPDATE` clause.
+///
+/// On MySQL this is rendered as `VALUES(column)`.
 pub fn excluded<T>(excluded: T) -> Excluded<T> {
     Excluded(excluded)
 }
 
+/// Represents `VALUES(column)` in an `ON DUPLICATE KEY UPDATE` clause.
+///
+/// This is MySQL's spelling of [`excluded`](fn.excluded.html), and the two
+/// can be used interchangeably.
+#[cfg(feature = "mysql")]
+pub fn values<T>(column: T) -> Excluded<T> {
+    Excluded(column)
+}
+
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
 pub struct DoNothing;
 
-impl QueryFragment<Pg> for DoNothing {
-    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+impl<DB> QueryFragment<DB> for DoNothing
+where
+    DB: Backend + SupportsOnConflictClause,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.push_sql(" DO NOTHING");
         Ok(())
     }
 }
 
 #[derive(Debug, Clone, Copy)]
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 pub struct IncompleteDoUpdate;
 
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 impl IncompleteDoUpdate {
     pub fn set<T: AsChangeset>(self, changeset: T) -> DoUpdate<T> {
-        DoUpdate {
-            changeset: changeset,
-        }
+        DoUpdate::new(changeset)
     }
 }
 
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
-pub struct DoUpdate<T> {
+pub struct DoUpdate<T, W = NoWhereClause> {
     changeset: T,
+    where_clause: W,
 }
 
 impl<T> DoUpdate<T> {
     pub(crate) fn new(changeset: T) -> Self {
-        DoUpdate { changeset }
+        DoUpdate {
+            changeset,
+            where_clause: NoWhereClause,
+        }
     }
 }
 
-impl<T> QueryFragment<Pg> for DoUpdate<T>
+impl<T, W> DoUpdate<T, W> {
+    #[cfg(feature = "mysql")]
+    pub(crate) fn is_noop<DB>(&self) -> bool
+    where
+        DB: Backend,
+        T: Changeset<DB>,
+    {
+        self.changeset.is_noop()
+    }
+
+    pub(crate) fn filter<Predicate>(self, predicate: Predicate) -> DoUpdate<T, W::Output>
+    where
+        W: WhereAnd<Predicate>,
+    {
+        DoUpdate {
+            changeset: self.changeset,
+            where_clause: self.where_clause.and(predicate),
+        }
+    }
+}
+
+impl<T, W, DB> QueryFragment<DB> for DoUpdate<T, W>
 where
-    T: Changeset<Pg>,
+    DB: Backend + SupportsOnConflictClause,
+    T: Changeset<DB>,
+    W: QueryFragment<DB>,
 {
-    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.unsafe_to_cache_prepared();
         if self.changeset.is_noop() {
             out.push_sql(" DO NOTHING");
         } else {
             out.push_sql(" DO UPDATE SET ");
             self.changeset.walk_ast(out.reborrow())?;
+            self.where_clause.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+// MySQL has no conflict target, and the update is introduced by
+// `ON DUPLICATE KEY UPDATE` instead. An update without any changes is turned
+// into `INSERT IGNORE` by `OnConflictValues`, so isn't rendered here.
+#[cfg(feature = "mysql")]
+impl<T> QueryFragment<Mysql> for DoUpdate<T>
+where
+    T: Changeset<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        if !self.changeset.is_noop() {
+            out.push_sql(" ON DUPLICATE KEY UPDATE ");
+            self.changeset.walk_ast(out.reborrow())?;
         }
         Ok(())
     }
@@ -184,17 +257,31 @@ where
 #[derive(Debug, Clone, Copy)]
 pub struct Excluded<T>(T);
 
-impl<T> QueryFragment<Pg> for Excluded<T>
+impl<T, DB> QueryFragment<DB> for Excluded<T>
 where
+    DB: Backend + SupportsOnConflictClause,
     T: Column,
 {
-    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.push_sql("excluded.");
         try!(out.push_identifier(T::NAME));
         Ok(())
     }
 }
 
+#[cfg(feature = "mysql")]
+impl<T> QueryFragment<Mysql> for Excluded<T>
+where
+    T: Column,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("VALUES(");
+        try!(out.push_identifier(T::NAME));
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
 impl<T> Expression for Excluded<T>
 where
     T: Expression,
@@ -210,14 +297,14 @@ where
 }
 
 #[doc(hidden)]
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 pub trait IntoConflictAction<T> {
     type Action: QueryFragment<Pg>;
 
     fn into_conflict_action(self) -> Self::Action;
 }
 
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 impl<T> IntoConflictAction<T> for DoNothing {
     type Action = Self;
 
@@ -226,7 +313,7 @@ impl<T> IntoConflictAction<T> for DoNothing {
     }
 }
 
-#[cfg(feature = "with-deprecated")]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
 impl<Table, Changes> IntoConflictAction<Table> for DoUpdate<Changes>
 where
     Table: QuerySource,
@@ -236,8 +323,6 @@ where
     type Action = DoUpdate<Changes::Changeset>;
 
     fn into_conflict_action(self) -> Self::Action {
-        DoUpdate {
-            changeset: self.changeset.as_changeset(),
-        }
+        DoUpdate::new(self.changeset.as_changeset())
     }
 }
@@ -0,0 +1,440 @@
+use backend::Backend;
+use insertable::*;
+#[cfg(feature = "mysql")]
+use mysql::Mysql;
+#[cfg(feature = "postgres")]
+use pg::Pg;
+use query_builder::*;
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+use query_builder::insert_statement::*;
+use query_source::Table;
+use result::QueryResult;
+#[cfg(feature = "sqlite")]
+use sqlite::Sqlite;
+use super::on_conflict_actions::*;
+use super::on_conflict_target::*;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+pub struct OnConflictDoNothing<T>(T);
+
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+impl<T> OnConflictDoNothing<T> {
+    pub fn new(records: T) -> Self {
+        OnConflictDoNothing(records)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+pub struct OnConflict<Records, Target, Action> {
+    records: Records,
+    target: Target,
+    action: Action,
+}
+
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+impl<Records, Target, Action> OnConflict<Records, Target, Action> {
+    pub fn new(records: Records, target: Target, action: Action) -> Self {
+        OnConflict {
+            records: records,
+            target: target,
+            action: action,
+        }
+    }
+}
+
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+impl<'a, T, Tab> Insertable<Tab> for &'a OnConflictDoNothing<T>
+where
+    T: Insertable<Tab> + Copy,
+    T: UndecoratedInsertRecord<Tab>,
+{
+    type Values = OnConflictValues<T::Values, NoConflictTarget, DoNothing>;
+
+    fn values(self) -> Self::Values {
+        OnConflictValues {
+            values: self.0.values(),
+            target: NoConflictTarget,
+            action: DoNothing,
+        }
+    }
+}
+
+#[cfg(all(feature = "with-deprecated", feature = "postgres"))]
+impl<'a, Records, Target, Action, Tab> Insertable<Tab> for &'a OnConflict<Records, Target, Action>
+where
+    Records: Insertable<Tab> + Copy,
+    Records: UndecoratedInsertRecord<Tab>,
+    Target: OnConflictTarget<Tab> + Clone,
+    Action: IntoConflictAction<Tab> + Copy,
+{
+    type Values = OnConflictValues<Records::Values, Target, Action::Action>;
+
+    fn values(self) -> Self::Values {
+        OnConflictValues {
+            values: self.records.values(),
+            target: self.target.clone(),
+            action: self.action.into_conflict_action(),
+        }
+    }
+}
+
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct OnConflictValues<Values, Target, Action> {
+    values: Values,
+    target: Target,
+    action: Action,
+}
+
+impl<Values> OnConflictValues<Values, NoConflictTarget, DoNothing> {
+    pub(crate) fn do_nothing(values: Values) -> Self {
+        Self::new(values, NoConflictTarget, DoNothing)
+    }
+}
+
+impl<Values, Target, Action> OnConflictValues<Values, Target, Action> {
+    pub(crate) fn new(values: Values, target: Target, action: Action) -> Self {
+        OnConflictValues {
+            values,
+            target,
+            action,
+        }
+    }
+
+    pub(crate) fn replace_action<F, NewAction>(
+        self,
+        f: F,
+    ) -> OnConflictValues<Values, Target, NewAction>
+    where
+        F: FnOnce(Action) -> NewAction,
+    {
+        OnConflictValues::new(self.values, self.target, f(self.action))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Values, Target, Action> CanInsertInSingleQuery<Pg> for OnConflictValues<Values, Target, Action>
+where
+    Values: CanInsertInSingleQuery<Pg>,
+{
+    fn rows_to_insert(&self) -> usize {
+        self.values.rows_to_insert()
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl<Tab, Values, Target, Action> InsertValues<Tab, Pg> for OnConflictValues<Values, Target, Action>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Pg>,
+    Target: QueryFragment<Pg>,
+    Action: QueryFragment<Pg>,
+{
+    fn column_names(&self, out: &mut <Pg as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        if self.values.requires_parenthesis() {
+            out.push_sql("(");
+        }
+        self.values.walk_ast(out.reborrow())?;
+        if self.values.requires_parenthesis() {
+            out.push_sql(")");
+        }
+        out.push_sql(" ON CONFLICT");
+        self.target.walk_ast(out.reborrow())?;
+        self.action.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        false
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+}
+
+// Used to execute an upsert of several rows on SQLite one row at a time
+#[cfg(feature = "sqlite")]
+impl<Values, Target, Action> OnConflictValues<Values, Target, Action> {
+    pub(crate) fn values(&self) -> &Values {
+        &self.values
+    }
+
+    pub(crate) fn into_values(self) -> Values {
+        self.values
+    }
+
+    pub(crate) fn with_values<NewValues>(
+        &self,
+        values: NewValues,
+    ) -> OnConflictValues<NewValues, Target, Action>
+    where
+        Target: Clone,
+        Action: Clone,
+    {
+        OnConflictValues::new(values, self.target.clone(), self.action.clone())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<Values, Target, Action> CanInsertInSingleQuery<Sqlite> for OnConflictValues<Values, Target, Action>
+where
+    Values: CanInsertInSingleQuery<Sqlite>,
+{
+    fn rows_to_insert(&self) -> usize {
+        self.values.rows_to_insert()
+    }
+}
+
+// SQLite only gained `ON CONFLICT` for `INSERT` in 3.24, but has supported
+// `INSERT OR IGNORE` for much longer. Since the two are equivalent when no
+// conflict target is given, we use the latter there.
+#[cfg(feature = "sqlite")]
+impl<Tab, Values> InsertValues<Tab, Sqlite> for OnConflictValues<Values, NoConflictTarget, DoNothing>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Sqlite>,
+{
+    fn column_names(&self, out: &mut <Sqlite as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.values.walk_ast(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        self.values.requires_parenthesis()
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+
+    fn walk_insert_modifier(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql(" OR IGNORE");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<Tab, Values, Target, Action> InsertValues<Tab, Sqlite>
+    for OnConflictValues<Values, ConflictTarget<Target>, Action>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Sqlite>,
+    ConflictTarget<Target>: QueryFragment<Sqlite>,
+    Action: QueryFragment<Sqlite>,
+{
+    fn column_names(&self, out: &mut <Sqlite as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_values_and_clause::<Tab>(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        false
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<Tab, Values, Target, Predicate, Action> InsertValues<Tab, Sqlite>
+    for OnConflictValues<Values, FilteredConflictTarget<Target, Predicate>, Action>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Sqlite>,
+    FilteredConflictTarget<Target, Predicate>: QueryFragment<Sqlite>,
+    Action: QueryFragment<Sqlite>,
+{
+    fn column_names(&self, out: &mut <Sqlite as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_values_and_clause::<Tab>(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        false
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<Values, Target, Action> OnConflictValues<Values, Target, Action> {
+    fn walk_values_and_clause<Tab>(&self, mut out: AstPass<Sqlite>) -> QueryResult<()>
+    where
+        Tab: Table,
+        Values: InsertValues<Tab, Sqlite>,
+        Target: QueryFragment<Sqlite>,
+        Action: QueryFragment<Sqlite>,
+    {
+        if self.values.requires_parenthesis() {
+            out.push_sql("(");
+        }
+        self.values.walk_ast(out.reborrow())?;
+        if self.values.requires_parenthesis() {
+            out.push_sql(")");
+        }
+        out.push_sql(" ON CONFLICT");
+        self.target.walk_ast(out.reborrow())?;
+        self.action.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<Values, Target, Action> CanInsertInSingleQuery<Mysql> for OnConflictValues<Values, Target, Action>
+where
+    Values: CanInsertInSingleQuery<Mysql>,
+{
+    fn rows_to_insert(&self) -> usize {
+        self.values.rows_to_insert()
+    }
+}
+
+// MySQL has no `ON CONFLICT`. Ignoring conflicts is done with `INSERT IGNORE`,
+// and updating the existing row with `ON DUPLICATE KEY UPDATE`, which always
+// applies to every unique index of the table.
+#[cfg(feature = "mysql")]
+impl<Tab, Values> InsertValues<Tab, Mysql> for OnConflictValues<Values, NoConflictTarget, DoNothing>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Mysql>,
+{
+    fn column_names(&self, out: &mut <Mysql as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.values.walk_ast(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        self.values.requires_parenthesis()
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+
+    fn walk_insert_modifier(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" IGNORE");
+        Ok(())
+    }
+}
+
+// MySQL can't restrict `INSERT IGNORE` to a given unique index, so the
+// conflict target is ignored here as it is for `ON DUPLICATE KEY UPDATE`.
+#[cfg(feature = "mysql")]
+impl<Tab, Values, Target> InsertValues<Tab, Mysql>
+    for OnConflictValues<Values, ConflictTarget<Target>, DoNothing>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Mysql>,
+{
+    fn column_names(&self, out: &mut <Mysql as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.values.walk_ast(out)
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        self.values.requires_parenthesis()
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+
+    fn walk_insert_modifier(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" IGNORE");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl<Tab, Values, Target, Changes> InsertValues<Tab, Mysql>
+    for OnConflictValues<Values, ConflictTarget<Target>, DoUpdate<Changes>>
+where
+    Tab: Table,
+    Values: InsertValues<Tab, Mysql>,
+    Changes: Changeset<Mysql>,
+{
+    fn column_names(&self, out: &mut <Mysql as Backend>::QueryBuilder) -> QueryResult<()> {
+        self.values.column_names(out)
+    }
+
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        if self.values.requires_parenthesis() {
+            out.push_sql("(");
+        }
+        self.values.walk_ast(out.reborrow())?;
+        if self.values.requires_parenthesis() {
+            out.push_sql(")");
+        }
+        self.action.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+
+    fn is_noop(&self) -> bool {
+        self.values.is_noop()
+    }
+
+    fn requires_parenthesis(&self) -> bool {
+        false
+    }
+
+    fn requires_values_keyword(&self) -> bool {
+        self.values.requires_values_keyword()
+    }
+
+    fn walk_insert_modifier(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        if self.action.is_noop::<Mysql>() {
+            out.push_sql(" IGNORE");
+        }
+        Ok(())
+    }
+}
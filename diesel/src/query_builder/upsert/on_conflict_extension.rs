@@ -1,7 +1,10 @@
+use expression::AppearsOnTable;
 use expression::operators::Eq;
 use query_builder::AsChangeset;
-use query_builder::insert_statement::{InsertStatement, UndecoratedInsertRecord};
-use query_source::QuerySource;
+use query_builder::insert_statement::{Insert, InsertStatement, UndecoratedInsertRecord};
+use query_builder::where_clause::WhereAnd;
+use query_dsl::FilterDsl;
+use query_source::{QuerySource, Table};
 use super::on_conflict_actions::*;
 use super::on_conflict_clause::*;
 use super::on_conflict_target::*;
@@ -20,9 +23,10 @@ pub trait OnConflictExtension {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -38,6 +42,8 @@ pub trait OnConflictExtension {
     ///     .execute(&conn);
     /// assert_eq!(Ok(0), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ### Vec of Records
@@ -47,9 +53,10 @@ pub trait OnConflictExtension {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -60,6 +67,8 @@ pub trait OnConflictExtension {
     ///     .execute(&conn);
     /// assert_eq!(Ok(1), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ### Slice of records
@@ -69,9 +78,10 @@ pub trait OnConflictExtension {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -83,8 +93,10 @@ pub trait OnConflictExtension {
     ///     .execute(&conn);
     /// assert_eq!(Ok(1), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
-    #[cfg(feature = "with-deprecated")]
+    #[cfg(all(feature = "with-deprecated", feature = "postgres"))]
     #[deprecated(since = "0.99.0", note = "use `.values(...).on_conflict_do_nothing()` instead")]
     fn on_conflict_do_nothing(&self) -> OnConflictDoNothing<&Self> {
         OnConflictDoNothing::new(self)
@@ -113,9 +125,10 @@ pub trait OnConflictExtension {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -140,6 +153,8 @@ pub trait OnConflictExtension {
     ///     .execute(&conn);
     /// assert!(pk_conflict_result.is_err());
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ### Specifying multiple columns as the target
@@ -165,9 +180,10 @@ pub trait OnConflictExtension {
     /// #     hair_color: &'a str,
     /// # }
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("DROP TABLE users").unwrap();
@@ -193,12 +209,14 @@ pub trait OnConflictExtension {
     ///     .execute(&conn);
     /// assert_eq!(Ok(0), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// See the documentation for [`on_constraint`](fn.on_constraint.html) and [`do_update`] for
     /// more examples.
     #[deprecated(since = "0.99.0", note = "use `.values(...).on_conflict(...)` instead")]
-    #[cfg(feature = "with-deprecated")]
+    #[cfg(all(feature = "with-deprecated", feature = "postgres"))]
     fn on_conflict<Target, Action>(
         &self,
         target: Target,
@@ -219,7 +237,9 @@ impl<T> OnConflictExtension for [T] {}
 impl<T> OnConflictExtension for Vec<T> {}
 impl<T> OnConflictExtension for Option<T> {}
 
-impl<T, U, Op, Ret> InsertStatement<T, U, Op, Ret>
+// `ON CONFLICT` can't be combined with `REPLACE`, which already decides what
+// happens on a conflict, so these methods are only available on `INSERT`.
+impl<T, U, Ret> InsertStatement<T, U, Insert, Ret>
 where
     U: UndecoratedInsertRecord<T>,
 {
@@ -235,6 +255,7 @@ where
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
     /// #     let conn = establish_connection();
@@ -253,6 +274,8 @@ where
     ///     .execute(&conn);
     /// assert_eq!(Ok(0), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ### Vec of Records
@@ -262,6 +285,7 @@ where
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
     /// #     let conn = establish_connection();
@@ -274,10 +298,59 @@ where
     ///     .execute(&conn);
     /// assert_eq!(Ok(1), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// ### Returning the inserted rows
+    ///
+    /// Upserts can be combined with `returning` like any other insert
+    /// statement, either before or after `on_conflict_do_nothing`. Rows which
+    /// were skipped because of a conflict are not returned, so `get_result`
+    /// will return `Err(NotFound)` and `get_results` an empty `Vec` if
+    /// nothing was inserted.
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// use diesel::result::Error::NotFound;
+    ///
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// let user = User { id: 1, name: "Sean", };
+    ///
+    /// let inserted_id = diesel::insert_into(users)
+    ///     .values(&user)
+    ///     .on_conflict_do_nothing()
+    ///     .returning(id)
+    ///     .get_result(&conn);
+    /// assert_eq!(Ok(1), inserted_id);
+    ///
+    /// let inserted_id = diesel::insert_into(users)
+    ///     .values(&user)
+    ///     .on_conflict_do_nothing()
+    ///     .returning(id)
+    ///     .get_result::<i32>(&conn);
+    /// assert_eq!(Err(NotFound), inserted_id);
+    ///
+    /// let inserted_ids = diesel::insert_into(users)
+    ///     .values(&user)
+    ///     .returning(id)
+    ///     .on_conflict_do_nothing()
+    ///     .get_results::<i32>(&conn);
+    /// assert_eq!(Ok(Vec::new()), inserted_ids);
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     pub fn on_conflict_do_nothing(
         self,
-    ) -> InsertStatement<T, OnConflictValues<U, NoConflictTarget, DoNothing>, Op, Ret> {
+    ) -> InsertStatement<T, OnConflictValues<U, NoConflictTarget, DoNothing>, Insert, Ret> {
         self.replace_values(OnConflictValues::do_nothing)
     }
 
@@ -299,6 +372,7 @@ where
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
     /// #     let conn = establish_connection();
@@ -324,6 +398,8 @@ where
     ///     .execute(&conn);
     /// assert!(pk_conflict_result.is_err());
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ### Specifying multiple columns as the target
@@ -349,9 +425,10 @@ where
     /// #     hair_color: &'a str,
     /// # }
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::*;
+    /// use diesel::query_builder::upsert::*;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("DROP TABLE users").unwrap();
@@ -377,13 +454,15 @@ where
     ///     .execute(&conn);
     /// assert_eq!(Ok(0), inserted_row_count);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// See the documentation for [`on_constraint`] and [`do_update`] for
     /// more examples.
     ///
-    /// [`on_constraint`]: ../../pg/upsert/fn.on_constraint.html
-    /// [`do_update`]: ../../pg/upsert/struct.IncompleteOnConflict.html#method.do_update
+    /// [`on_constraint`]: ../upsert/fn.on_constraint.html
+    /// [`do_update`]: ../upsert/struct.IncompleteOnConflict.html#method.do_update
     pub fn on_conflict<Target>(
         self,
         target: Target,
@@ -396,6 +475,60 @@ where
             target: ConflictTarget(target),
         }
     }
+
+    /// Adds `ON CONFLICT (primary key) DO UPDATE` to the insert statement,
+    /// using the primary key of the table being inserted into as the conflict
+    /// target.
+    ///
+    /// This is equivalent to `.on_conflict(table.primary_key()).do_update()`.
+    /// Call `.set` on the result with the changes to apply when a row with the
+    /// same primary key already exists. See [`do_update`] for more examples.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// let user = User { id: 1, name: "Pascal" };
+    /// let user2 = User { id: 1, name: "Sean" };
+    ///
+    /// assert_eq!(Ok(1), diesel::insert_into(users).values(&user).execute(&conn));
+    ///
+    /// let insert_count = diesel::insert_into(users)
+    ///     .values(&user2)
+    ///     .on_conflict_primary_key_do_update()
+    ///     .set(&user2)
+    ///     .execute(&conn);
+    /// assert_eq!(Ok(1), insert_count);
+    ///
+    /// let users_in_db = users.load(&conn);
+    /// assert_eq!(Ok(vec![(1, "Sean".to_string())]), users_in_db);
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`do_update`]: ../upsert/struct.IncompleteOnConflict.html#method.do_update
+    pub fn on_conflict_primary_key_do_update(
+        self,
+    ) -> IncompleteDoUpdate<Self, ConflictTarget<T::PrimaryKey>>
+    where
+        T: Table,
+        ConflictTarget<T::PrimaryKey>: OnConflictTarget<T>,
+    {
+        let target = ConflictTarget(self.target().primary_key());
+        IncompleteDoUpdate {
+            stmt: self,
+            target: target,
+        }
+    }
 }
 
 /// A partially constructed `ON CONFLICT` clause.
@@ -406,6 +539,63 @@ pub struct IncompleteOnConflict<Stmt, Target> {
 }
 
 impl<T, U, Op, Ret, Target> IncompleteOnConflict<InsertStatement<T, U, Op, Ret>, Target> {
+    /// Restricts the conflict target to a partial unique index, producing
+    /// `ON CONFLICT (target) WHERE predicate`.
+    ///
+    /// PostgreSQL only uses a partial index as the conflict target if the
+    /// predicate implies the index's own `WHERE` clause. Without it, a
+    /// conflict on a partial index raises a unique violation instead of
+    /// taking the conflict action. The target must be a column or a tuple of
+    /// columns, since a predicate cannot be combined with [`on_constraint`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// conn.execute("CREATE UNIQUE INDEX users_name ON users (name) WHERE name IS NOT NULL")
+    ///     .unwrap();
+    /// let user = User { id: 1, name: "Sean" };
+    /// let same_name_different_id = User { id: 2, name: "Sean" };
+    ///
+    /// assert_eq!(Ok(1), diesel::insert_into(users).values(&user).execute(&conn));
+    ///
+    /// let inserted_row_count = diesel::insert_into(users)
+    ///     .values(&same_name_different_id)
+    ///     .on_conflict(name)
+    ///     .filter_target(name.is_not_null())
+    ///     .do_nothing()
+    ///     .execute(&conn);
+    /// assert_eq!(Ok(0), inserted_row_count);
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`on_constraint`]: fn.on_constraint.html
+    pub fn filter_target<Predicate>(
+        self,
+        predicate: Predicate,
+    ) -> IncompleteOnConflict<
+        InsertStatement<T, U, Op, Ret>,
+        FilteredConflictTarget<Target, Predicate>,
+    >
+    where
+        FilteredConflictTarget<Target, Predicate>: OnConflictTarget<T>,
+    {
+        IncompleteOnConflict {
+            stmt: self.stmt,
+            target: FilteredConflictTarget::new(self.target, predicate),
+        }
+    }
+
     /// Creates a query with `ON CONFLICT (target) DO NOTHING`
     ///
     /// If you want to do nothing when *any* constraint conflicts, use
@@ -440,6 +630,7 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
     /// #     let conn = establish_connection();
@@ -460,6 +651,8 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// let users_in_db = users.load(&conn);
     /// assert_eq!(Ok(vec![(1, "I DONT KNOW ANYMORE".to_string())]), users_in_db);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ## Set `AsChangeset` struct on conflict
@@ -469,6 +662,7 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
     /// #     let conn = establish_connection();
@@ -489,6 +683,8 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// let users_in_db = users.load(&conn);
     /// assert_eq!(Ok(vec![(1, "Sean".to_string())]), users_in_db);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     ///
     /// ## Use `excluded` to get the rejected value
@@ -498,9 +694,10 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// # #[macro_use] extern crate diesel_codegen;
     /// # include!("on_conflict_docs_setup.rs");
     /// #
+    /// # #[cfg(feature = "postgres")]
     /// # fn main() {
     /// #     use users::dsl::*;
-    /// use diesel::pg::upsert::excluded;
+    /// use diesel::query_builder::upsert::excluded;
     ///
     /// #     let conn = establish_connection();
     /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -521,6 +718,8 @@ impl<Stmt, Target> IncompleteOnConflict<Stmt, Target> {
     /// let users_in_db = users.load(&conn);
     /// assert_eq!(Ok(vec![(1, "Sean".to_string()), (2, "Tess".to_string())]), users_in_db);
     /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
     /// ```
     pub fn do_update(self) -> IncompleteDoUpdate<Stmt, Target> {
         IncompleteDoUpdate {
@@ -555,3 +754,73 @@ impl<T, U, Op, Ret, Target> IncompleteDoUpdate<InsertStatement<T, U, Op, Ret>, T
         })
     }
 }
+
+impl<T, U, Target, Changes, W, Op, Ret, Predicate> FilterDsl<Predicate>
+    for InsertStatement<T, OnConflictValues<U, Target, DoUpdate<Changes, W>>, Op, Ret>
+where
+    W: WhereAnd<Predicate>,
+    Predicate: AppearsOnTable<T>,
+{
+    type Output =
+        InsertStatement<T, OnConflictValues<U, Target, DoUpdate<Changes, W::Output>>, Op, Ret>;
+
+    /// Adds a `WHERE` clause to the `DO UPDATE` action, after the `SET`
+    /// clause.
+    ///
+    /// Rows for which the predicate is false are left unchanged, and aren't
+    /// counted as affected. The predicate can reference both the existing
+    /// row and the row proposed for insertion (via [`excluded`]). If the
+    /// conflict target has a predicate (see [`filter_target`]), it is placed
+    /// before `DO UPDATE`, while this one is placed after `SET`. Calling
+    /// `filter` more than once will combine the predicates with `AND`.
+    ///
+    /// [`excluded`]: fn.excluded.html
+    /// [`filter_target`]: struct.IncompleteOnConflict.html#method.filter_target
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use users::dsl::*;
+    /// use diesel::query_builder::upsert::excluded;
+    ///
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// let user = User { id: 1, name: "Sean" };
+    /// assert_eq!(Ok(1), diesel::insert_into(users).values(&user).execute(&conn));
+    ///
+    /// // Skip the write when nothing would change
+    /// let update_count = diesel::insert_into(users)
+    ///     .values(&user)
+    ///     .on_conflict(id)
+    ///     .do_update()
+    ///     .set(name.eq(excluded(name)))
+    ///     .filter(name.ne(excluded(name)))
+    ///     .execute(&conn);
+    /// assert_eq!(Ok(0), update_count);
+    ///
+    /// let renamed_user = User { id: 1, name: "Jim" };
+    /// let update_count = diesel::insert_into(users)
+    ///     .values(&renamed_user)
+    ///     .on_conflict(id)
+    ///     .do_update()
+    ///     .set(name.eq(excluded(name)))
+    ///     .filter(name.ne(excluded(name)))
+    ///     .execute(&conn);
+    /// assert_eq!(Ok(1), update_count);
+    ///
+    /// let users_in_db = users.load(&conn);
+    /// assert_eq!(Ok(vec![(1, "Jim".to_string())]), users_in_db);
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    fn filter(self, predicate: Predicate) -> Self::Output {
+        self.replace_values(|values| values.replace_action(|action| action.filter(predicate)))
+    }
+}
@@ -1,13 +1,20 @@
-use expression::SqlLiteral;
+use backend::{Backend, SupportsOnConflictClause};
+use expression::{AppearsOnTable, SqlLiteral};
+#[cfg(feature = "postgres")]
 use pg::Pg;
 use query_builder::*;
 use query_source::Column;
 use result::QueryResult;
+use types::Bool;
 
 /// Used to specify the constraint name for an upsert statement in the form `ON
 /// CONFLICT ON CONSTRAINT`. Note that `constraint_name` must be the name of a
 /// unique constraint, not the name of an index.
 ///
+/// The name is sent as a quoted identifier, so it is case sensitive and must
+/// match the name of the constraint exactly (PostgreSQL folds unquoted names
+/// to lowercase when the constraint is created).
+///
 /// # Example
 ///
 /// ```rust
@@ -15,9 +22,10 @@ use result::QueryResult;
 /// # #[macro_use] extern crate diesel_codegen;
 /// # include!("on_conflict_docs_setup.rs");
 /// #
+/// # #[cfg(feature = "postgres")]
 /// # fn main() {
 /// #     use users::dsl::*;
-/// use diesel::pg::upsert::*;
+/// use diesel::query_builder::upsert::*;
 ///
 /// #     let conn = establish_connection();
 /// #     conn.execute("TRUNCATE TABLE users").unwrap();
@@ -42,7 +50,10 @@ use result::QueryResult;
 ///     .execute(&conn);
 /// assert!(pk_conflict_result.is_err());
 /// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
 /// ```
+#[cfg(feature = "postgres")]
 pub fn on_constraint(constraint_name: &str) -> OnConstraint {
     OnConstraint {
         constraint_name: constraint_name,
@@ -51,30 +62,75 @@ pub fn on_constraint(constraint_name: &str) -> OnConstraint {
 
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
+#[cfg(feature = "postgres")]
 pub struct OnConstraint<'a> {
     constraint_name: &'a str,
 }
 
-pub trait OnConflictTarget<Table>: QueryFragment<Pg> {}
+pub trait OnConflictTarget<Table> {}
+
+/// A conflict target which names columns of a unique index, and can therefore
+/// be restricted to a partial index with a `WHERE` clause.
+#[doc(hidden)]
+pub trait ColumnConflictTarget<Table>: OnConflictTarget<Table> {}
 
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
-pub struct NoConflictTarget;
+pub struct FilteredConflictTarget<Target, Predicate> {
+    target: Target,
+    predicate: Predicate,
+}
 
-impl QueryFragment<Pg> for NoConflictTarget {
-    fn walk_ast(&self, _: AstPass<Pg>) -> QueryResult<()> {
+impl<Target, Predicate> FilteredConflictTarget<Target, Predicate> {
+    pub(crate) fn new(target: Target, predicate: Predicate) -> Self {
+        FilteredConflictTarget { target, predicate }
+    }
+}
+
+impl<Target, Predicate, DB> QueryFragment<DB> for FilteredConflictTarget<Target, Predicate>
+where
+    DB: Backend + SupportsOnConflictClause,
+    Target: QueryFragment<DB>,
+    Predicate: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.target.walk_ast(out.reborrow())?;
+        out.push_sql(" WHERE ");
+        self.predicate.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
 
-impl<Table> OnConflictTarget<Table> for NoConflictTarget {}
+impl<Table, Target, Predicate> OnConflictTarget<Table> for FilteredConflictTarget<Target, Predicate>
+where
+    Target: ColumnConflictTarget<Table>,
+    Predicate: AppearsOnTable<Table, SqlType = Bool>,
+{
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct NoConflictTarget;
+
+impl<DB> QueryFragment<DB> for NoConflictTarget
+where
+    DB: Backend + SupportsOnConflictClause,
+{
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
 
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
 pub struct ConflictTarget<T>(pub T);
 
-impl<T: Column> QueryFragment<Pg> for ConflictTarget<T> {
-    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+impl<T, DB> QueryFragment<DB> for ConflictTarget<T>
+where
+    DB: Backend + SupportsOnConflictClause,
+    T: Column,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.push_sql(" (");
         try!(out.push_identifier(T::NAME));
         out.push_sql(")");
@@ -84,23 +140,23 @@ impl<T: Column> QueryFragment<Pg> for ConflictTarget<T> {
 
 impl<T: Column> OnConflictTarget<T::Table> for ConflictTarget<T> {}
 
-impl<ST> QueryFragment<Pg> for ConflictTarget<SqlLiteral<ST>>
+impl<T: Column> ColumnConflictTarget<T::Table> for ConflictTarget<T> {}
+
+impl<ST, DB> QueryFragment<DB> for ConflictTarget<SqlLiteral<ST>>
 where
-    SqlLiteral<ST>: QueryFragment<Pg>,
+    DB: Backend + SupportsOnConflictClause,
+    SqlLiteral<ST>: QueryFragment<DB>,
 {
-    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.push_sql(" ");
         self.0.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
 
-impl<Tab, ST> OnConflictTarget<Tab> for ConflictTarget<SqlLiteral<ST>>
-where
-    ConflictTarget<SqlLiteral<ST>>: QueryFragment<Pg>,
-{
-}
+impl<Tab, ST> OnConflictTarget<Tab> for ConflictTarget<SqlLiteral<ST>> {}
 
+#[cfg(feature = "postgres")]
 impl<'a> QueryFragment<Pg> for ConflictTarget<OnConstraint<'a>> {
     fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
         out.push_sql(" ON CONSTRAINT ");
@@ -109,15 +165,17 @@ impl<'a> QueryFragment<Pg> for ConflictTarget<OnConstraint<'a>> {
     }
 }
 
+#[cfg(feature = "postgres")]
 impl<'a, Table> OnConflictTarget<Table> for ConflictTarget<OnConstraint<'a>> {}
 
 macro_rules! on_conflict_tuples {
     ($($col:ident),+) => {
-        impl<T, $($col),+> QueryFragment<Pg> for ConflictTarget<(T, $($col),+)> where
+        impl<T, $($col,)+ DB> QueryFragment<DB> for ConflictTarget<(T, $($col),+)> where
+            DB: Backend + SupportsOnConflictClause,
             T: Column,
             $($col: Column<Table=T::Table>,)+
         {
-            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
                 out.push_sql(" (");
                 try!(out.push_identifier(T::NAME));
                 $(
@@ -134,6 +192,12 @@ macro_rules! on_conflict_tuples {
             $($col: Column<Table=T::Table>,)+
         {
         }
+
+        impl<T, $($col),+> ColumnConflictTarget<T::Table> for ConflictTarget<(T, $($col),+)> where
+            T: Column,
+            $($col: Column<Table=T::Table>,)+
+        {
+        }
     }
 }
 
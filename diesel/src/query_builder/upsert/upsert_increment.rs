@@ -0,0 +1,102 @@
+use expression::AsExpression;
+use expression::operators::Eq;
+use expression::ops::Add;
+use insertable::ColumnInsertValue;
+use query_builder::insert_statement::{Insert, InsertStatement};
+use query_builder::Query;
+use query_builder::returning_clause::ReturningClause;
+use query_source::{Table, WritableColumn};
+use super::on_conflict_actions::{excluded, DoUpdate, Excluded};
+use super::on_conflict_clause::OnConflictValues;
+use super::on_conflict_target::ConflictTarget;
+use types;
+
+/// The query returned by [`upsert_increment`](fn.upsert_increment.html).
+pub type UpsertIncrement<Tab, Key, Value, Counter, Amount> = InsertStatement<
+    Tab,
+    OnConflictValues<
+        (ColumnInsertValue<Key, Value>, ColumnInsertValue<Counter, Amount>),
+        ConflictTarget<Key>,
+        DoUpdate<Eq<Counter, Add<Counter, Excluded<Counter>>>>,
+    >,
+    Insert,
+    ReturningClause<Counter>,
+>;
+
+/// Adds `amount` to a counter column, inserting the row if it doesn't exist
+/// yet.
+///
+/// `key` is an assignment to the column which identifies the row, such as
+/// `path.eq("/")`. That column must have a unique index. This generates
+///
+/// ```sql
+/// INSERT INTO table (key, counter) VALUES ($1, $2)
+/// ON CONFLICT (key) DO UPDATE SET counter = table.counter + excluded.counter
+/// RETURNING counter
+/// ```
+///
+/// The row is inserted with the counter set to `amount` if it doesn't exist.
+/// The whole operation is a single statement, so concurrent increments of
+/// the same row are never lost, unlike loading the counter and writing back
+/// the incremented value. Loading the result returns the new value of the
+/// counter.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// #
+/// table! {
+///     page_views (path) {
+///         path -> Text,
+///         views -> Integer,
+///     }
+/// }
+///
+/// # fn main() {
+/// #     use self::page_views::dsl::*;
+/// use diesel::query_builder::upsert::upsert_increment;
+///
+/// #     let connection = establish_connection();
+/// #     connection.execute("CREATE TEMPORARY TABLE page_views (
+/// #         path TEXT PRIMARY KEY,
+/// #         views INTEGER NOT NULL
+/// #     )").unwrap();
+/// let first_visit = upsert_increment(page_views, path.eq("/"), views, 1)
+///     .get_result(&connection);
+/// assert_eq!(Ok(1), first_visit);
+///
+/// let after_two_more = upsert_increment(page_views, path.eq("/"), views, 2)
+///     .get_result(&connection);
+/// assert_eq!(Ok(3), after_two_more);
+/// # }
+/// ```
+pub fn upsert_increment<Tab, Key, Value, Counter, Amount>(
+    table: Tab,
+    key: Eq<Key, Value>,
+    counter: Counter,
+    amount: Amount,
+) -> UpsertIncrement<Tab, Key, Value, Counter, Amount::Expression>
+where
+    Tab: Table,
+    Key: WritableColumn<Table = Tab> + Copy,
+    Counter: WritableColumn<Table = Tab> + Copy,
+    Counter::SqlType: types::ops::Add,
+    Amount: AsExpression<Counter::SqlType>,
+    UpsertIncrement<Tab, Key, Value, Counter, Amount::Expression>: Query,
+{
+    let (key_column, key_value) = key.into_parts();
+    let values = (
+        ColumnInsertValue::Expression(key_column, key_value),
+        ColumnInsertValue::Expression(counter, amount.as_expression()),
+    );
+    let increment = Eq::new(counter, Add::new(counter, excluded(counter)));
+    ::insert_into(table)
+        .with_values(OnConflictValues::new(
+            values,
+            ConflictTarget(key_column),
+            DoUpdate::new(increment),
+        ))
+        .returning(counter)
+}
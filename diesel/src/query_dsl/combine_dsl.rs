@@ -0,0 +1,175 @@
+use query_builder::{AsQuery, Query, SelectStatement};
+use query_builder::combination_clause::*;
+use query_builder::limit_clause::NoLimitClause;
+use query_builder::offset_clause::NoOffsetClause;
+use query_builder::order_clause::NoOrderClause;
+use query_source::Table;
+
+/// Combines the results of two queries with `UNION`, `UNION ALL`,
+/// `INTERSECT` or `EXCEPT`.
+///
+/// Both queries must select the same SQL types, which is enforced at compile
+/// time. Neither of them may have an `ORDER BY`, `LIMIT` or `OFFSET` clause,
+/// or a locking clause. Instead, `.limit`, `.offset` and
+/// [`.positional_order_by`](trait.PositionalOrderDsl.html) can be called on
+/// the combined query, and apply to the combined result.
+///
+/// MySQL only supports `INTERSECT` and `EXCEPT` since version 8.0.31.
+///
+/// Combined queries can be combined again, as in `a.union(b).union(c)`,
+/// as long as `.limit`, `.offset` and `.positional_order_by` haven't been
+/// called on them yet. The operations are applied from left to right. Since
+/// PostgreSQL and MySQL give `INTERSECT` a higher precedence than `UNION` and
+/// `EXCEPT`, a query combined with `union`, `union_all` or `except` can't be
+/// intersected afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::users::dsl::{users, id, name};
+///
+/// let connection = establish_connection();
+/// let sean = users.select(name).filter(id.eq(1));
+/// let tess = users.select(name).filter(id.eq(2));
+///
+/// let names = sean.union(tess)
+///     .positional_order_by(1)
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec![String::from("Sean"), String::from("Tess")]), names);
+///
+/// let names = users.select(name).filter(id.eq(1))
+///     .union_all(users.select(name))
+///     .positional_order_by(1)
+///     .load::<String>(&connection);
+/// let expected = vec![String::from("Sean"), String::from("Sean"), String::from("Tess")];
+/// assert_eq!(Ok(expected), names);
+/// # }
+/// ```
+pub trait CombineDsl {
+    /// The query which is used as the left hand side of the set operation
+    type Query: Query;
+
+    #[doc(hidden)]
+    fn as_combinable_query(self) -> Self::Query;
+
+    /// Combines both queries with `UNION`, removing duplicate rows
+    fn union<Rhs>(self, rhs: Rhs) -> CombinationClause<Union, Self::Query, Rhs::Query>
+    where
+        Self: Sized,
+        Rhs: AsQuery<SqlType = <Self::Query as Query>::SqlType>,
+        Rhs::Query: CombinableQuery,
+    {
+        CombinationClause::new(Union, self.as_combinable_query(), rhs.as_query())
+    }
+
+    /// Combines both queries with `UNION ALL`, keeping duplicate rows
+    fn union_all<Rhs>(self, rhs: Rhs) -> CombinationClause<UnionAll, Self::Query, Rhs::Query>
+    where
+        Self: Sized,
+        Rhs: AsQuery<SqlType = <Self::Query as Query>::SqlType>,
+        Rhs::Query: CombinableQuery,
+    {
+        CombinationClause::new(UnionAll, self.as_combinable_query(), rhs.as_query())
+    }
+
+    /// Combines both queries with `INTERSECT`, returning only rows which are
+    /// returned by both of them
+    fn intersect<Rhs>(self, rhs: Rhs) -> CombinationClause<Intersect, Self::Query, Rhs::Query>
+    where
+        Self: Sized,
+        Self::Query: CombinableWithIntersect,
+        Rhs: AsQuery<SqlType = <Self::Query as Query>::SqlType>,
+        Rhs::Query: CombinableQuery,
+    {
+        CombinationClause::new(Intersect, self.as_combinable_query(), rhs.as_query())
+    }
+
+    /// Combines both queries with `EXCEPT`, returning only rows which are
+    /// returned by `self` but not by `rhs`
+    fn except<Rhs>(self, rhs: Rhs) -> CombinationClause<Except, Self::Query, Rhs::Query>
+    where
+        Self: Sized,
+        Rhs: AsQuery<SqlType = <Self::Query as Query>::SqlType>,
+        Rhs::Query: CombinableQuery,
+    {
+        CombinationClause::new(Except, self.as_combinable_query(), rhs.as_query())
+    }
+}
+
+impl<T> CombineDsl for T
+where
+    T: Table,
+    T::Query: CombinableQuery,
+{
+    type Query = T::Query;
+
+    fn as_combinable_query(self) -> Self::Query {
+        self.as_query()
+    }
+}
+
+impl<F, S, D, W, G> CombineDsl
+    for SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>
+where
+    Self: CombinableQuery,
+{
+    type Query = Self;
+
+    fn as_combinable_query(self) -> Self::Query {
+        self
+    }
+}
+
+/// Orders the combined result of a set operation by the position of the
+/// selected columns, as in `ORDER BY 1 DESC, 2`.
+///
+/// Columns of a table can't be used to order the result of `UNION`,
+/// `INTERSECT` or `EXCEPT` on every backend, since the result doesn't belong
+/// to any table. Positions start at 1, and can be given either as a `u32`, or
+/// as an [`OrderColumn`](../query_builder/struct.OrderColumn.html) to specify
+/// the direction.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use diesel::query_builder::OrderColumn;
+/// use self::users::dsl::{users, id, name};
+///
+/// let connection = establish_connection();
+/// let query = users.select((id, name)).filter(id.eq(1))
+///     .union(users.select((id, name)).filter(id.eq(2)))
+///     .positional_order_by(OrderColumn::from(1).desc());
+///
+/// let expected = vec![(2, String::from("Tess")), (1, String::from("Sean"))];
+/// assert_eq!(Ok(expected), query.load(&connection));
+/// # }
+/// ```
+pub trait PositionalOrderDsl<Expr> {
+    /// The query returned by `positional_order_by`
+    type Output;
+
+    /// See the trait level documentation
+    fn positional_order_by(self, expr: Expr) -> Self::Output;
+}
@@ -46,3 +46,70 @@ where
         self.as_query().distinct()
     }
 }
+
+/// Adds `DISTINCT ON (expr)` to a query, returning only the first row of each
+/// group of rows which have the same value for `expr`.
+///
+/// PostgreSQL requires the expression passed to `DISTINCT ON` to match the
+/// leading expression of the `ORDER BY` clause. To make it impossible to get
+/// this wrong, `distinct_on` also sets the `ORDER BY` clause of the query to
+/// `expr`, replacing any previous ordering. Additional sort keys which decide
+/// which row is kept for each group can be added afterwards with
+/// [`then_order_by`](trait.ThenOrderDsl.html).
+///
+/// This method is only available on PostgreSQL.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::posts::dsl::*;
+/// #     let connection = establish_connection();
+/// let latest_post_per_user = posts
+///     .select((user_id, title))
+///     .distinct_on(user_id)
+///     .then_order_by(id.desc())
+///     .load(&connection);
+/// let expected = vec![
+///     (1, String::from("About Rust")),
+///     (2, String::from("My first post too")),
+/// ];
+/// assert_eq!(Ok(expected), latest_post_per_user);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+pub trait DistinctOnDsl<Expr> {
+    /// The query returned by `distinct_on`. See [`dsl::DistinctOn`]
+    /// for convenient access to this type.
+    ///
+    /// [`dsl::DistinctOn`]: ../dsl/type.DistinctOn.html
+    type Output;
+
+    /// See the trait level documentation
+    fn distinct_on(self, expr: Expr) -> Self::Output;
+}
+
+impl<T, Expr> DistinctOnDsl<Expr> for T
+where
+    T: Table,
+    T::Query: DistinctOnDsl<Expr>,
+{
+    type Output = <T::Query as DistinctOnDsl<Expr>>::Output;
+
+    fn distinct_on(self, expr: Expr) -> Self::Output {
+        self.as_query().distinct_on(expr)
+    }
+}
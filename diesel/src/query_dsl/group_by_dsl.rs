@@ -2,6 +2,42 @@ use expression::Expression;
 use query_builder::{AsQuery, Query};
 use query_source::Table;
 
+/// Adds a `GROUP BY` clause to a query.
+///
+/// The expression can be a single column or a tuple of columns, and must be
+/// from a table that appears in the `FROM` clause. Aggregate functions such as
+/// [`count`](../dsl/fn.count.html) in the select clause are then computed once
+/// per group. Which other columns may appear in the select clause is not
+/// checked by Diesel, and is left to the database to validate.
+///
+/// The returned query keeps track of the grouping, so further clauses which
+/// only make sense on grouped queries can be added to it.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::count;
+/// #
+/// # fn main() {
+/// #     use schema::posts::dsl::*;
+/// #     let connection = establish_connection();
+/// let posts_per_user = posts
+///     .select((user_id, count(id)))
+///     .group_by(user_id)
+///     .order(user_id)
+///     .load::<(i32, i64)>(&connection);
+/// assert_eq!(Ok(vec![(1, 2), (2, 1)]), posts_per_user);
+///
+/// let posts_per_user_and_title = posts
+///     .select((user_id, title, count(id)))
+///     .group_by((user_id, title))
+///     .load::<(i32, String, i64)>(&connection)
+///     .unwrap();
+/// assert_eq!(3, posts_per_user_and_title.len());
+/// # }
+/// ```
 pub trait GroupByDsl<Expr: Expression> {
     type Output: Query;
 
@@ -0,0 +1,40 @@
+use expression::Expression;
+use query_builder::Query;
+use types::Bool;
+
+/// Adds a `HAVING` clause to a grouped query.
+///
+/// This method is only available on queries which have already had
+/// [`group_by`](trait.GroupByDsl.html) called on them. Unlike
+/// [`filter`](trait.FilterDsl.html), the predicate is applied to each group
+/// rather than to each row, so it may contain aggregate functions such as
+/// [`count`](../dsl/fn.count.html). Calling `group_by` again afterwards
+/// replaces the grouping but keeps the `HAVING` clause.
+///
+/// This is not available on boxed queries. A query which already has a
+/// `HAVING` clause can be boxed, but calling `group_by` on the boxed query
+/// replaces both its `GROUP BY` and its `HAVING` clause.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::count;
+/// #
+/// # fn main() {
+/// #     use schema::posts::dsl::*;
+/// #     let connection = establish_connection();
+/// let prolific_authors = posts
+///     .select(user_id)
+///     .group_by(user_id)
+///     .having(count(id).gt(1))
+///     .load::<i32>(&connection);
+/// assert_eq!(Ok(vec![1]), prolific_authors);
+/// # }
+/// ```
+pub trait HavingDsl<Predicate: Expression<SqlType = Bool>> {
+    type Output: Query;
+
+    fn having(self, predicate: Predicate) -> Self::Output;
+}
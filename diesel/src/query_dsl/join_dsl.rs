@@ -50,10 +50,9 @@ where
 /// association][associations] that defines the relationship.
 ///
 /// You can join to as many tables as you'd like in a query, with the
-/// restriction that no table can appear in the query more than once. The reason
-/// for this restriction is that one of the appearances would require aliasing,
-/// and we do not currently have a fleshed out story for dealing with table
-/// aliases.
+/// restriction that no table can appear in the query more than once. To join
+/// a table to itself, one of the appearances needs to be aliased with
+/// [`alias!`][].
 ///
 /// You may also need to call [`enable_multi_table_joins!`][] (particularly if
 /// you see an unexpected error about `AppearsInFromClause`). See the
@@ -82,6 +81,7 @@ where
 ///
 /// [associations]: ../associations/index.html
 /// [`enable_multi_table_joins!`]: ../macro.enable_multi_table_joins.html
+/// [`alias!`]: ../macro.alias.html
 pub trait JoinDsl: Sized {
     /// Join two tables using a SQL `INNER JOIN`. The `ON` clause is defined
     /// via the [associations API](../associations/index.html).
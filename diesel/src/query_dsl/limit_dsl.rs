@@ -20,3 +20,29 @@ where
         self.as_query().limit(limit)
     }
 }
+
+/// Limits a query to `limit` rows, but also returns any additional rows which
+/// tie with the last row according to the query's `ORDER BY` clause. This is
+/// rendered as `FETCH FIRST n ROWS WITH TIES`.
+///
+/// This method is only available on PostgreSQL (version 13 or later), and
+/// can only be called on queries which have already been ordered, as the
+/// ordering is what determines which rows are considered tied. Any previous
+/// limit is replaced.
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT * FROM scores ORDER BY points DESC FETCH FIRST 3 ROWS WITH TIES`
+/// scores.order(points.desc()).limit_with_ties(3).load(&connection)
+/// ```
+pub trait LimitWithTiesDsl {
+    /// The query returned by `limit_with_ties`. See [`dsl::LimitWithTies`]
+    /// for convenient access to this type.
+    ///
+    /// [`dsl::LimitWithTies`]: ../dsl/type.LimitWithTies.html
+    type Output;
+
+    /// See the trait level documentation
+    fn limit_with_ties(self, limit: i64) -> Self::Output;
+}
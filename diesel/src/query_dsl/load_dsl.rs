@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
 use backend::Backend;
 use connection::Connection;
 use dsl::Limit;
@@ -9,6 +12,11 @@ use types::HasSqlType;
 
 pub trait LoadQuery<Conn, U>: LoadDsl<Conn> {
     fn internal_load(self, conn: &Conn) -> QueryResult<Vec<U>>;
+
+    fn internal_load_map<F, R, C>(self, conn: &Conn, f: F) -> QueryResult<C>
+    where
+        F: FnMut(U) -> R,
+        C: FromIterator<R>;
 }
 
 impl<Conn, T, U> LoadQuery<Conn, U> for T
@@ -22,6 +30,14 @@ where
     fn internal_load(self, conn: &Conn) -> QueryResult<Vec<U>> {
         conn.query_by_index(self)
     }
+
+    fn internal_load_map<F, R, C>(self, conn: &Conn, f: F) -> QueryResult<C>
+    where
+        F: FnMut(U) -> R,
+        C: FromIterator<R>,
+    {
+        conn.query_by_index_map(self, f)
+    }
 }
 
 /// Methods to execute a query given a connection. These are automatically implemented for the
@@ -35,6 +51,92 @@ pub trait LoadDsl<Conn>: Sized {
         self.internal_load(conn)
     }
 
+    /// Executes the given query, collecting the returned rows into any
+    /// collection which implements `FromIterator`, rather than a `Vec`.
+    ///
+    /// The type of the collection usually has to be given, either on the
+    /// variable the result is assigned to or as the first type parameter.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     posts {
+    /// #         id -> Integer,
+    /// #         user_id -> Integer,
+    /// #         title -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::posts::dsl::*;
+    /// #     let connection = establish_connection();
+    /// use std::collections::HashSet;
+    ///
+    /// let authors: HashSet<i32> = posts
+    ///     .select(user_id)
+    ///     .load_into(&connection)
+    ///     .unwrap();
+    /// assert_eq!(2, authors.len());
+    /// assert!(authors.contains(&1) && authors.contains(&2));
+    /// # }
+    /// ```
+    fn load_into<C, U>(self, conn: &Conn) -> QueryResult<C>
+    where
+        Self: LoadQuery<Conn, U>,
+        C: FromIterator<U>,
+    {
+        self.internal_load_map(conn, |row: U| row)
+    }
+
+    /// Executes the given query, passing each row to `f` as soon as it has
+    /// been deserialized, and returning a `Vec` of the results.
+    ///
+    /// This is equivalent to calling `.load` and mapping over the result, but
+    /// it doesn't allocate the intermediate `Vec` of loaded rows. The closure
+    /// usually needs a type annotation for its argument, which determines the
+    /// type each row is loaded into.
+    ///
+    /// The results of `f` are still collected into a `Vec` before this
+    /// returns, so this can't be used to stream rows to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let greetings = users
+    ///     .select((id, name))
+    ///     .order(id)
+    ///     .load_map(&connection, |(user_id, user_name): (i32, String)| {
+    ///         format!("{}: Hello, {}!", user_id, user_name)
+    ///     });
+    /// let expected = vec!["1: Hello, Sean!".to_string(), "2: Hello, Tess!".to_string()];
+    /// assert_eq!(Ok(expected), greetings);
+    /// # }
+    /// ```
+    fn load_map<U, F, R>(self, conn: &Conn, f: F) -> QueryResult<Vec<R>>
+    where
+        Self: LoadQuery<Conn, U>,
+        F: FnMut(U) -> R,
+    {
+        self.internal_load_map(conn, f)
+    }
+
     /// Runs the command, and returns the affected row. `Err(NotFound)` will be
     /// returned if the query affected 0 rows. You can call `.optional()` on the
     /// result of this if the command was optional to get back a
@@ -53,6 +155,65 @@ pub trait LoadDsl<Conn>: Sized {
     {
         self.load(conn)
     }
+
+    /// Executes the given query, grouping the returned rows by the key
+    /// returned from `key_selector`.
+    ///
+    /// The groups are ordered by their key, since they are stored in a
+    /// `BTreeMap`. Within each group, the rows are in the order they were
+    /// returned by the query, so an `ORDER BY` clause on the query determines
+    /// the order of the rows in every group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #     diesel::insert_into(users)
+    /// #         .values(&NewUser::new("Shane"))
+    /// #         .execute(&connection)
+    /// #         .unwrap();
+    /// let names_by_initial = users
+    ///     .select(name)
+    ///     .order(id)
+    ///     .load_grouped_by(|n: &String| n.chars().next(), &connection)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, names_by_initial.len());
+    /// assert_eq!(vec!["Sean", "Shane"], names_by_initial[&Some('S')]);
+    /// assert_eq!(vec!["Tess"], names_by_initial[&Some('T')]);
+    /// # }
+    /// ```
+    fn load_grouped_by<K, U, F>(
+        self,
+        mut key_selector: F,
+        conn: &Conn,
+    ) -> QueryResult<BTreeMap<K, Vec<U>>>
+    where
+        Self: LoadQuery<Conn, U>,
+        K: Ord,
+        F: FnMut(&U) -> K,
+    {
+        let mut groups = BTreeMap::new();
+        for row in self.load(conn)? {
+            groups
+                .entry(key_selector(&row))
+                .or_insert_with(Vec::new)
+                .push(row);
+        }
+        Ok(groups)
+    }
 }
 
 impl<Conn, T> LoadDsl<Conn> for T
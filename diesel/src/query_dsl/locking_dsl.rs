@@ -37,3 +37,91 @@ where
         self.as_query().for_update()
     }
 }
+
+/// Adds `FOR SHARE` to the end of the select statement. Rows locked this way
+/// can still be read and share locked by other transactions, but not updated,
+/// deleted or locked `FOR UPDATE`.
+///
+/// This method is only available for PostgreSQL and MySQL 8.0 or newer. It has
+/// the same restrictions as [`for_update`](trait.ForUpdateDsl.html).
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT * FROM users FOR SHARE`
+/// users.for_share().load(&connection)
+/// ```
+pub trait ForShareDsl {
+    /// The query returned by `for_share`. See [`dsl::ForShare`] for
+    /// convenient access to this type.
+    ///
+    /// [`dsl::ForShare`]: ../dsl/type.ForShare.html
+    type Output;
+
+    /// See the trait level documentation
+    fn for_share(self) -> Self::Output;
+}
+
+impl<T> ForShareDsl for T
+where
+    T: Table + AsQuery,
+    T::Query: ForShareDsl,
+{
+    type Output = <T::Query as ForShareDsl>::Output;
+
+    fn for_share(self) -> Self::Output {
+        self.as_query().for_share()
+    }
+}
+
+/// Adds `SKIP LOCKED` to the locking clause of a query which was locked with
+/// [`for_update`](trait.ForUpdateDsl.html) or
+/// [`for_share`](trait.ForShareDsl.html). Rows which are locked by another
+/// transaction are left out of the result, instead of waiting for the lock to
+/// be released.
+///
+/// This is commonly used to implement job queues, where each worker claims the
+/// next job which no other worker is currently processing. This method is only
+/// available for PostgreSQL and MySQL 8.0 or newer.
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT * FROM jobs LIMIT 1 FOR UPDATE SKIP LOCKED`
+/// jobs.limit(1).for_update().skip_locked().first(&connection)
+/// ```
+pub trait SkipLockedDsl {
+    /// The query returned by `skip_locked`. See [`dsl::SkipLocked`] for
+    /// convenient access to this type.
+    ///
+    /// [`dsl::SkipLocked`]: ../dsl/type.SkipLocked.html
+    type Output;
+
+    /// See the trait level documentation
+    fn skip_locked(self) -> Self::Output;
+}
+
+/// Adds `NOWAIT` to the locking clause of a query which was locked with
+/// [`for_update`](trait.ForUpdateDsl.html) or
+/// [`for_share`](trait.ForShareDsl.html). If any of the selected rows is
+/// locked by another transaction the query fails with an error, instead of
+/// waiting for the lock to be released.
+///
+/// This method is only available for PostgreSQL and MySQL 8.0 or newer.
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT * FROM users FOR UPDATE NOWAIT`
+/// users.for_update().no_wait().load(&connection)
+/// ```
+pub trait NoWaitDsl {
+    /// The query returned by `no_wait`. See [`dsl::NoWait`] for
+    /// convenient access to this type.
+    ///
+    /// [`dsl::NoWait`]: ../dsl/type.NoWait.html
+    type Output;
+
+    /// See the trait level documentation
+    fn no_wait(self) -> Self::Output;
+}
@@ -1,9 +1,11 @@
 mod belonging_to_dsl;
 #[doc(hidden)]
 pub mod boxed_dsl;
+mod combine_dsl;
 mod count_dsl;
 mod distinct_dsl;
 mod group_by_dsl;
+mod having_dsl;
 mod join_dsl;
 #[doc(hidden)]
 pub mod limit_dsl;
@@ -15,21 +17,24 @@ pub mod select_dsl;
 #[doc(hidden)]
 pub mod filter_dsl;
 mod save_changes_dsl;
+mod single_value_dsl;
 mod offset_dsl;
 mod order_dsl;
 
 pub use self::belonging_to_dsl::BelongingToDsl;
 pub use self::boxed_dsl::BoxedDsl;
+pub use self::combine_dsl::{CombineDsl, PositionalOrderDsl};
 pub use self::count_dsl::CountDsl;
-pub use self::distinct_dsl::DistinctDsl;
+pub use self::distinct_dsl::{DistinctDsl, DistinctOnDsl};
 pub use self::filter_dsl::{FilterDsl, FindDsl};
-#[doc(hidden)]
 pub use self::group_by_dsl::GroupByDsl;
+pub use self::having_dsl::HavingDsl;
 pub use self::join_dsl::{InternalJoinDsl, JoinDsl, JoinOnDsl, JoinWithImplicitOnClause};
-pub use self::limit_dsl::LimitDsl;
+pub use self::limit_dsl::{LimitDsl, LimitWithTiesDsl};
 pub use self::load_dsl::{ExecuteDsl, FirstDsl, LoadDsl, LoadQuery};
-pub use self::locking_dsl::ForUpdateDsl;
+pub use self::locking_dsl::{ForShareDsl, ForUpdateDsl, NoWaitDsl, SkipLockedDsl};
 pub use self::offset_dsl::OffsetDsl;
-pub use self::order_dsl::OrderDsl;
+pub use self::order_dsl::{OrderDsl, ThenOrderDsl};
 pub use self::save_changes_dsl::SaveChangesDsl;
 pub use self::select_dsl::SelectDsl;
+pub use self::single_value_dsl::SingleValueDsl;
@@ -58,3 +58,56 @@ where
         self.as_query().order(expr)
     }
 }
+
+/// Appends an expression to the order clause of a query, to be used as a
+/// secondary sort key. If the query has no order clause yet, this behaves
+/// like [`order`](trait.OrderDsl.html).
+///
+/// This is automatically implemented for the various query builder types.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::users::dsl::{users, id, name};
+///
+/// let connection = establish_connection();
+/// # connection.execute("DELETE FROM users").unwrap();
+/// connection.execute("INSERT INTO users (name) VALUES ('Saul'), ('Stan'), ('Stan')").unwrap();
+/// let ordered_name_id_pairs = users
+///     .select((name, id))
+///     .order(name.asc())
+///     .then_order_by(id.desc())
+///     .load(&connection)
+///     .unwrap();
+/// assert_eq!(vec![(String::from("Saul"), 3), (String::from("Stan"), 5), (String::from("Stan"), 4)], ordered_name_id_pairs);
+/// # }
+/// ```
+pub trait ThenOrderDsl<Expr: Expression> {
+    type Output;
+
+    fn then_order_by(self, expr: Expr) -> Self::Output;
+}
+
+impl<T, Expr> ThenOrderDsl<Expr> for T
+where
+    Expr: Expression,
+    T: Table,
+    T::Query: ThenOrderDsl<Expr>,
+{
+    type Output = <T::Query as ThenOrderDsl<Expr>>::Output;
+
+    fn then_order_by(self, expr: Expr) -> Self::Output {
+        self.as_query().then_order_by(expr)
+    }
+}
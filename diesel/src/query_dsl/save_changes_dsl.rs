@@ -1,4 +1,6 @@
 use associations::HasTable;
+use backend::SupportsReturningClause;
+use connection::Connection;
 use dsl::*;
 use query_builder::{AsChangeset, IntoUpdateTarget};
 use query_dsl::*;
@@ -12,6 +14,8 @@ pub trait InternalSaveChangesDsl<Conn, T>: Sized {
 
 impl<T, U, Conn> InternalSaveChangesDsl<Conn, U> for T
 where
+    Conn: Connection,
+    Conn::Backend: SupportsReturningClause,
     T: Copy + AsChangeset<Target = <T as HasTable>::Table> + IntoUpdateTarget,
     Update<T, T>: LoadDsl<Conn> + LoadQuery<Conn, U>,
 {
@@ -0,0 +1,96 @@
+use expression::scalar_subquery::ScalarSubquery;
+use query_builder::SelectQuery;
+use types::{IntoNullable, SingleValue};
+
+/// Uses a query returning a single column as a scalar subquery.
+///
+/// The resulting expression can be used anywhere a single value is expected,
+/// such as the argument to `.eq` or `.gt`. Since the subquery may not return
+/// any rows, its SQL type is always nullable. The query must not return more
+/// than one row, or the database will return an error when it is executed.
+///
+/// The subquery may be correlated, referencing columns of the outer query in
+/// its `WHERE` clause. A correlated subquery can only be used in a query which
+/// has those tables in its `FROM` clause.
+///
+/// A subquery which always returns exactly one row, such as
+/// `posts.filter(user_id.eq(users::id)).count().single_value()`, is still
+/// nullable, since Diesel can't tell that a row will always be returned. Wrap
+/// it in [`coalesce`] to load it as a non-null value, e.g.
+/// `coalesce(post_count, 0)` for an `i64`.
+///
+/// [`coalesce`]: ../dsl/fn.coalesce.html
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     use diesel::dsl::max;
+/// #     let connection = establish_connection();
+/// let newest_user = users
+///     .select(name)
+///     .filter(id.nullable().eq(users.select(max(id)).single_value()));
+/// assert_eq!(Ok(String::from("Tess")), newest_user.first(&connection));
+/// # }
+/// ```
+pub trait SingleValueDsl {
+    /// The expression returned by `single_value`. See [`dsl::SingleValue`]
+    /// for convenient access to this type.
+    ///
+    /// [`dsl::SingleValue`]: ../dsl/type.SingleValue.html
+    type Output;
+
+    /// Wraps this query in parentheses so it can be used as a value.
+    ///
+    /// When the query returns no rows, the value is `NULL`. Comparing `NULL`
+    /// to anything is never true, so a filter using it matches no rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let name_of_user_2 = users.select(name).filter(id.eq(2)).single_value();
+    /// let ids = users
+    ///     .select(id)
+    ///     .filter(name.nullable().eq(name_of_user_2))
+    ///     .load::<i32>(&connection);
+    /// assert_eq!(Ok(vec![2]), ids);
+    ///
+    /// let name_of_user_3 = users.select(name).filter(id.eq(3)).single_value();
+    /// let ids = users
+    ///     .select(id)
+    ///     .filter(name.nullable().eq(name_of_user_3))
+    ///     .load::<i32>(&connection);
+    /// assert_eq!(Ok(vec![]), ids);
+    /// # }
+    /// ```
+    fn single_value(self) -> Self::Output;
+}
+
+impl<T> SingleValueDsl for T
+where
+    T: SelectQuery,
+    T::SqlType: SingleValue + IntoNullable,
+{
+    type Output = ScalarSubquery<Self>;
+
+    fn single_value(self) -> Self::Output {
+        ScalarSubquery::new(self)
+    }
+}
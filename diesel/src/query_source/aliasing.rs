@@ -0,0 +1,234 @@
+use std::marker::PhantomData;
+
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use query_builder::*;
+use result::QueryResult;
+use super::{AppearsInFromClause, Column, Never, Once, QuerySource, Table};
+use super::joins::{Inner, Join, JoinOn, LeftOuter, Plus};
+
+/// Gives an aliased table its name. Types implementing this trait should be
+/// generated by the [`alias!` macro](../macro.alias.html).
+pub trait AliasSource: Copy {
+    /// The table which is aliased
+    type Target: Table;
+
+    /// The name of the alias
+    const NAME: &'static str;
+
+    /// Returns the table which is aliased
+    fn target(&self) -> Self::Target;
+}
+
+/// A table which appears in the `FROM` clause under a different name, as in
+/// `employees AS managers`. This is created with the
+/// [`alias!` macro](../macro.alias.html).
+///
+/// The columns of the aliased table are accessed with
+/// [`field`](#method.field). They are distinct from the columns of the table
+/// itself, which allows joining a table to itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Alias<S> {
+    source: S,
+}
+
+impl<S: AliasSource> Alias<S> {
+    #[doc(hidden)]
+    pub fn new(source: S) -> Self {
+        Alias { source: source }
+    }
+
+    /// Refers to a column, or a tuple of columns, of the aliased table
+    /// through this alias.
+    pub fn field<C>(&self, column: C) -> AliasedField<S, C>
+    where
+        C: AliasableColumns<S::Target>,
+    {
+        AliasedField {
+            source: PhantomData,
+            column: column,
+        }
+    }
+}
+
+impl<S> QuerySource for Alias<S>
+where
+    S: AliasSource,
+    <S::Target as Table>::AllColumns: AliasableColumns<S::Target>,
+    AliasedField<S, <S::Target as Table>::AllColumns>: SelectableExpression<Self>,
+{
+    type FromClause = Self;
+    type DefaultSelection = AliasedField<S, <S::Target as Table>::AllColumns>;
+
+    fn from_clause(&self) -> Self::FromClause {
+        *self
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        self.field(<S::Target as Table>::all_columns())
+    }
+}
+
+impl<S, DB> QueryFragment<DB> for Alias<S>
+where
+    S: AliasSource,
+    DB: Backend,
+    <S::Target as QuerySource>::FromClause: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.source.target().from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" AS ");
+        out.push_identifier(S::NAME)
+    }
+}
+
+impl_query_id!(Alias<S>);
+
+// Tables never appear in the `FROM` clause through an alias of them. This
+// is what makes the columns of the table itself unambiguous when it is
+// joined to an alias of itself.
+impl<S, T> AppearsInFromClause<T> for Alias<S>
+where
+    T: Table,
+{
+    type Count = Never;
+}
+
+/// A column, or a tuple of columns, accessed through an
+/// [`Alias`](struct.Alias.html), as in `managers.name`.
+#[derive(Debug, Clone, Copy)]
+pub struct AliasedField<S, C> {
+    source: PhantomData<S>,
+    column: C,
+}
+
+impl<S, C> Expression for AliasedField<S, C>
+where
+    C: Expression,
+{
+    type SqlType = C::SqlType;
+}
+
+impl<S, C> NonAggregate for AliasedField<S, C>
+where
+    C: NonAggregate,
+    Self: Expression,
+{
+}
+
+impl<S, C, DB> QueryFragment<DB> for AliasedField<S, C>
+where
+    S: AliasSource,
+    C: AliasableColumns<S::Target>,
+    DB: Backend,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.column.walk_aliased_ast(S::NAME, out)
+    }
+}
+
+impl_query_id!(AliasedField<S, C>);
+
+impl<S, C, QS> AppearsOnTable<QS> for AliasedField<S, C>
+where
+    S: AliasSource,
+    C: AliasableColumns<S::Target>,
+    Self: Expression,
+    QS: AliasAppearsInFromClause<S, Count = Once>,
+{
+}
+
+impl<S, C> SelectableExpression<Alias<S>> for AliasedField<S, C>
+where
+    Self: AppearsOnTable<Alias<S>>,
+{
+}
+
+impl<S, C, Left, Right> SelectableExpression<Join<Left, Right, LeftOuter>> for AliasedField<S, C>
+where
+    Self: AppearsOnTable<Join<Left, Right, LeftOuter>>,
+    Left: AliasAppearsInFromClause<S, Count = Once>,
+    Right: AliasAppearsInFromClause<S, Count = Never>,
+{
+}
+
+impl<S, C, Left, Right> SelectableExpression<Join<Left, Right, Inner>> for AliasedField<S, C>
+where
+    Self: AppearsOnTable<Join<Left, Right, Inner>>,
+    Join<Left, Right, Inner>: AliasAppearsInFromClause<S, Count = Once>,
+{
+}
+
+// FIXME: Remove this when overlapping marker traits are stable
+impl<S, C, Join, On> SelectableExpression<JoinOn<Join, On>> for AliasedField<S, C>
+where
+    Self: SelectableExpression<Join> + AppearsOnTable<JoinOn<Join, On>>,
+{
+}
+
+// FIXME: Remove this when overlapping marker traits are stable
+impl<S, C, From> SelectableExpression<SelectStatement<From>> for AliasedField<S, C>
+where
+    Self: SelectableExpression<From> + AppearsOnTable<SelectStatement<From>>,
+{
+}
+
+#[doc(hidden)]
+/// A column, or a tuple of columns, of `Table`, which can be qualified with
+/// the name of an alias of that table instead of the table name.
+pub trait AliasableColumns<Table> {
+    fn walk_aliased_ast<DB: Backend>(&self, alias: &str, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+impl<C> AliasableColumns<C::Table> for C
+where
+    C: Column,
+{
+    fn walk_aliased_ast<DB: Backend>(&self, alias: &str, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(alias)?;
+        out.push_sql(".");
+        out.push_identifier(C::NAME)
+    }
+}
+
+#[doc(hidden)]
+/// The equivalent of `AppearsInFromClause` for aliases. Aliases are counted
+/// separately from tables, since a table and its alias are different query
+/// sources, even though they share their columns.
+pub trait AliasAppearsInFromClause<S> {
+    type Count;
+}
+
+impl<S> AliasAppearsInFromClause<S> for Alias<S> {
+    type Count = Once;
+}
+
+impl<S, T> AliasAppearsInFromClause<S> for T
+where
+    T: Table,
+{
+    type Count = Never;
+}
+
+impl<S, Left, Right, Kind> AliasAppearsInFromClause<S> for Join<Left, Right, Kind>
+where
+    Left: AliasAppearsInFromClause<S>,
+    Right: AliasAppearsInFromClause<S>,
+    Left::Count: Plus<Right::Count>,
+{
+    type Count = <Left::Count as Plus<Right::Count>>::Output;
+}
+
+impl<S, Join, On> AliasAppearsInFromClause<S> for JoinOn<Join, On>
+where
+    Join: AliasAppearsInFromClause<S>,
+{
+    type Count = Join::Count;
+}
+
+impl<S, From> AliasAppearsInFromClause<S> for SelectStatement<From>
+where
+    From: AliasAppearsInFromClause<S>,
+{
+    type Count = From::Count;
+}
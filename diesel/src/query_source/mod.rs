@@ -1,6 +1,7 @@
 //! Types in this module are mostly internal and automatically generated. You
 //! shouldn't need to interact with these types during normal usage, other than
 //! the methods on [`Table`](/diesel/query_source/trait.Table.html)
+mod aliasing;
 #[doc(hidden)]
 pub mod joins;
 
@@ -9,6 +10,9 @@ use expression::{Expression, NonAggregate, SelectableExpression};
 use query_builder::*;
 use types::{FromSqlRow, HasSqlType};
 
+pub use self::aliasing::{Alias, AliasSource, AliasedField};
+#[doc(hidden)]
+pub use self::aliasing::{AliasAppearsInFromClause, AliasableColumns};
 pub use self::joins::JoinTo;
 
 /// Trait indicating that a record can be queried from the database. This trait
@@ -40,6 +44,16 @@ pub trait Column: Expression {
     const NAME: &'static str;
 }
 
+/// A column which can be assigned a value in an `INSERT` or `UPDATE`
+/// statement. The [`table!` macro](../macro.table.html) implements this for
+/// every column, except for columns marked as `#[generated]`.
+///
+/// This trait can't be implemented for every `Column`, since generated
+/// columns must not implement it. If you implement `Column` by hand, also
+/// implement this trait for columns which can be written to. It has no
+/// methods, so `impl WritableColumn for my_column {}` is all that's needed.
+pub trait WritableColumn: Column {}
+
 /// A SQL database table. Types which implement this trait should have been
 /// generated by the [`table!` macro](../macro.table.html).
 pub trait Table: QuerySource + AsQuery + Sized {
@@ -23,6 +23,15 @@ pub enum Error {
     /// return this variant unless you gave it to us, and it can be safely
     /// ignored in error handling.
     RollbackTransaction,
+    /// Returned by statements which were built with
+    /// [`require_affected`](../query_builder/struct.UpdateStatement.html#method.require_affected)
+    /// when the number of rows affected was not the number expected.
+    UnexpectedRowCount {
+        /// The number of rows the statement was expected to affect
+        expected: usize,
+        /// The number of rows the statement actually affected
+        actual: usize,
+    },
     #[doc(hidden)] __Nonexhaustive,
 }
 
@@ -89,6 +98,55 @@ pub enum ConnectionError {
     #[doc(hidden)] __Nonexhaustive, // Match against _ instead, more variants may be added in the future
 }
 
+/// A deserialization error which carries the SQL of the query that produced
+/// the row which failed to deserialize.
+///
+/// When the `sql-in-deserialization-errors` feature is enabled, the boxed
+/// error of `Error::DeserializationError` returned from loading a query is
+/// this type, which makes it much easier to tell which query has a result
+/// type that doesn't match its `Queryable` struct. Only the SQL is included,
+/// never the values of bind parameters. The SQL is only attached in builds
+/// with debug assertions enabled, so that queries don't end up in the error
+/// logs of release builds even if the feature was left on.
+#[derive(Debug)]
+pub struct DeserializationErrorWithSql {
+    sql: String,
+    error: Box<StdError + Send + Sync>,
+}
+
+impl DeserializationErrorWithSql {
+    #[cfg(all(feature = "sql-in-deserialization-errors", debug_assertions))]
+    pub(crate) fn new(sql: String, error: Box<StdError + Send + Sync>) -> Self {
+        DeserializationErrorWithSql { sql, error }
+    }
+
+    /// The SQL of the query which failed to deserialize.
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The error which occurred while deserializing the row.
+    pub fn error(&self) -> &(StdError + Send + Sync + 'static) {
+        &*self.error
+    }
+}
+
+impl Display for DeserializationErrorWithSql {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (in query `{}`)", self.error, self.sql)
+    }
+}
+
+impl StdError for DeserializationErrorWithSql {
+    fn description(&self) -> &str {
+        self.error.description()
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        Some(&*self.error)
+    }
+}
+
 /// A specialized result type for queries.
 ///
 /// This type is exported by `diesel::prelude`, and is generally used by any
@@ -160,6 +218,12 @@ impl Display for Error {
             Error::DeserializationError(ref e) => e.fmt(f),
             Error::SerializationError(ref e) => e.fmt(f),
             Error::RollbackTransaction => write!(f, "{}", self.description()),
+            Error::UnexpectedRowCount { expected, actual } => write!(
+                f,
+                "Expected the statement to affect {} rows, but it affected {}",
+                expected,
+                actual
+            ),
             Error::__Nonexhaustive => unreachable!(),
         }
     }
@@ -175,6 +239,9 @@ impl StdError for Error {
             Error::DeserializationError(ref e) => e.description(),
             Error::SerializationError(ref e) => e.description(),
             Error::RollbackTransaction => "The current transaction was aborted",
+            Error::UnexpectedRowCount { .. } => {
+                "The statement affected an unexpected number of rows"
+            }
             Error::__Nonexhaustive => unreachable!(),
         }
     }
@@ -212,6 +279,16 @@ impl PartialEq for Error {
                 a.message() == b.message()
             }
             (&Error::NotFound, &Error::NotFound) => true,
+            (
+                &Error::UnexpectedRowCount {
+                    expected: e1,
+                    actual: a1,
+                },
+                &Error::UnexpectedRowCount {
+                    expected: e2,
+                    actual: a2,
+                },
+            ) => e1 == e2 && a1 == a2,
             _ => false,
         }
     }
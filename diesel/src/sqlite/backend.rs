@@ -25,6 +25,9 @@ impl Backend for Sqlite {
     type BindCollector = RawBytesBindCollector<Sqlite>;
     type RawValue = SqliteValue;
     type ByteOrder = NativeEndian;
+
+    // SQLite treats a negative limit as unbounded
+    const LIMIT_FOR_OFFSET_WITHOUT_LIMIT: &'static str = " LIMIT -1";
 }
 
 impl TypeMetadata for Sqlite {
@@ -32,4 +35,5 @@ impl TypeMetadata for Sqlite {
     type MetadataLookup = ();
 }
 
+impl SupportsOnConflictClause for Sqlite {}
 impl UsesAnsiSavepointSyntax for Sqlite {}
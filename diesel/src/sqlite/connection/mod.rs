@@ -8,6 +8,7 @@ mod sqlite_value;
 
 pub use self::sqlite_value::SqliteValue;
 
+use std::iter::FromIterator;
 use std::os::raw as libc;
 use std::rc::Rc;
 
@@ -67,11 +68,42 @@ impl Connection for SqliteConnection {
         T::Query: QueryFragment<Self::Backend> + QueryId,
         Self::Backend: HasSqlType<T::SqlType>,
         U: Queryable<T::SqlType, Self::Backend>,
+    {
+        self.query_by_index_map(source, |row: U| row)
+    }
+
+    #[doc(hidden)]
+    fn query_by_index_map<T, U, F, R, C>(&self, source: T, mut f: F) -> QueryResult<C>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        Self::Backend: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Self::Backend>,
+        F: FnMut(U) -> R,
+        C: FromIterator<R>,
+    {
+        let source = source.as_query();
+        let result = {
+            let mut statement = try!(self.prepare_query(&source));
+            let statement_use = StatementUse::new(&mut statement);
+            let iter = StatementIterator::<T::SqlType, U>::new(statement_use);
+            iter.map(|row| row.map(&mut f)).collect()
+        };
+        attach_sql_to_deserialization_error(result, &source)
+    }
+
+    fn execute_query<T>(&self, source: T) -> QueryResult<usize>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
     {
         let mut statement = try!(self.prepare_query(&source.as_query()));
-        let statement_use = StatementUse::new(&mut statement);
-        let iter = StatementIterator::new(statement_use);
-        iter.collect()
+        let mut statement_use = StatementUse::new(&mut statement);
+        let mut row_count = 0;
+        while statement_use.step().is_some() {
+            row_count += 1;
+        }
+        Ok(row_count)
     }
 
     #[doc(hidden)]
@@ -85,6 +117,14 @@ impl Connection for SqliteConnection {
         Ok(self.raw_connection.rows_affected_by_last_query())
     }
 
+    #[doc(hidden)]
+    fn prepare_statement<T>(&self, source: &T) -> QueryResult<()>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        self.cached_prepared_statement(source).map(|_| ())
+    }
+
     #[doc(hidden)]
     fn silence_notices<F: FnOnce() -> T, T>(&self, f: F) -> T {
         f()
@@ -148,6 +188,22 @@ mod tests {
         assert_eq!(1, connection.statement_cache.len());
     }
 
+    #[test]
+    fn warmed_up_statements_are_cached_before_being_run() {
+        let connection = SqliteConnection::establish(":memory:").unwrap();
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+        let query2 = ::select(AsExpression::<Integer>::as_expression(1).eq(2));
+
+        connection
+            .warmup(&[query.as_prepared(), query2.as_prepared()])
+            .unwrap();
+        assert_eq!(2, connection.statement_cache.len());
+
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(Ok(false), query2.get_result(&connection));
+        assert_eq!(2, connection.statement_cache.len());
+    }
+
     #[test]
     fn sql_literal_nodes_are_not_cached() {
         let connection = SqliteConnection::establish(":memory:").unwrap();
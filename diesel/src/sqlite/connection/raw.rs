@@ -8,6 +8,12 @@ use std::{ptr, str};
 use result::*;
 use result::Error::DatabaseError;
 
+/// Returns the version of the SQLite library Diesel is linked against, in the
+/// form `X * 1_000_000 + Y * 1_000 + Z` for version `X.Y.Z`.
+pub fn library_version_number() -> i32 {
+    unsafe { ffi::sqlite3_libversion_number() as i32 }
+}
+
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 pub struct RawConnection {
     pub internal_connection: *mut ffi::sqlite3,
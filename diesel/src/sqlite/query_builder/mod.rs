@@ -1,6 +1,12 @@
+use backend::Backend;
 use super::backend::Sqlite;
-use query_builder::QueryBuilder;
-use result::QueryResult;
+use super::connection::raw::library_version_number;
+use query_builder::{AstPass, LimitedStatement, QueryBuilder, QueryFragment};
+use query_builder::limit_clause::NoLimitClause;
+use query_builder::limit_offset_clause::LimitOffsetClause;
+use query_builder::offset_clause::OffsetClause;
+use query_builder::returning_clause::ReturningClause;
+use result::{Error, QueryResult};
 
 pub mod functions;
 
@@ -36,3 +42,85 @@ impl QueryBuilder<Sqlite> for SqliteQueryBuilder {
         self.sql
     }
 }
+
+/// SQLite only supports `RETURNING` as of 3.35.0. Since the library version is
+/// only known at runtime, older versions return an error when the query is
+/// built rather than sending invalid SQL.
+impl<Expr> QueryFragment<Sqlite> for ReturningClause<Expr>
+where
+    Expr: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        check_returning_is_supported(library_version_number())?;
+        out.push_sql(" RETURNING ");
+        self.0.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// `LIMIT` on `UPDATE` and `DELETE` is only available if SQLite was compiled
+/// with `SQLITE_ENABLE_UPDATE_DELETE_LIMIT`. Otherwise SQLite reports a syntax
+/// error when the statement is prepared.
+impl<Stmt> QueryFragment<Sqlite> for LimitedStatement<Stmt>
+where
+    Stmt: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.statement.walk_ast(out.reborrow())?;
+        out.push_sql(" LIMIT ");
+        self.limit.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// SQLite doesn't accept `OFFSET` without `LIMIT`, so a negative limit is
+/// used when only an offset was given.
+impl<O> QueryFragment<Sqlite> for LimitOffsetClause<NoLimitClause, OffsetClause<O>>
+where
+    OffsetClause<O>: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql(Sqlite::LIMIT_FOR_OFFSET_WITHOUT_LIMIT);
+        self.offset_clause.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+const MIN_VERSION_FOR_RETURNING: i32 = 3_035_000;
+
+fn check_returning_is_supported(version_number: i32) -> QueryResult<()> {
+    if version_number >= MIN_VERSION_FOR_RETURNING {
+        Ok(())
+    } else {
+        Err(Error::QueryBuilderError(
+            format!(
+                "`RETURNING` requires SQLite 3.35.0 or newer, but the linked \
+                 SQLite library is version {}.{}.{}",
+                version_number / 1_000_000,
+                version_number / 1_000 % 1_000,
+                version_number % 1_000
+            ).into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_returning_is_supported;
+
+    #[test]
+    fn returning_is_allowed_on_sqlite_3_35_and_newer() {
+        assert!(check_returning_is_supported(3_035_000).is_ok());
+        assert!(check_returning_is_supported(3_040_001).is_ok());
+    }
+
+    #[test]
+    fn returning_on_older_sqlite_versions_is_a_clear_error() {
+        let error = check_returning_is_supported(3_034_001).unwrap_err();
+        assert_eq!(
+            "`RETURNING` requires SQLite 3.35.0 or newer, but the linked \
+             SQLite library is version 3.34.1",
+            error.to_string()
+        );
+    }
+}
@@ -160,3 +160,31 @@ fn option_to_sql() {
     assert_eq!(IsNull::No, is_null);
     assert_eq!(bytes, expectd_bytes);
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn option_of_borrowed_value_to_sql() {
+    let mut bytes = ToSqlOutput::test();
+    let is_null = ToSql::<types::Nullable<types::Binary>, Pg>::to_sql(&None::<&[u8]>, &mut bytes)
+        .unwrap();
+    assert_eq!(IsNull::Yes, is_null);
+    assert!(bytes.is_empty());
+
+    let is_null = ToSql::<types::Nullable<types::Binary>, Pg>::to_sql(
+        &Some(&b"Sean"[..]),
+        &mut bytes,
+    ).unwrap();
+    assert_eq!(IsNull::No, is_null);
+    assert_eq!(bytes, b"Sean".to_vec());
+
+    let mut bytes = ToSqlOutput::test();
+    let is_null = ToSql::<types::Nullable<types::Integer>, Pg>::to_sql(&None::<&i32>, &mut bytes)
+        .unwrap();
+    assert_eq!(IsNull::Yes, is_null);
+    assert!(bytes.is_empty());
+
+    let is_null = ToSql::<types::Nullable<types::Integer>, Pg>::to_sql(&Some(&1), &mut bytes)
+        .unwrap();
+    assert_eq!(IsNull::No, is_null);
+    assert_eq!(bytes, vec![0, 0, 0, 1]);
+}
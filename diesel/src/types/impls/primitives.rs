@@ -141,6 +141,46 @@ where
     }
 }
 
+macro_rules! smart_pointer_impls {
+    ($($ptr:ident),+) => {$(
+        impl<T, ST, DB> FromSql<ST, DB> for $ptr<T>
+        where
+            T: FromSql<ST, DB>,
+            DB: Backend + HasSqlType<ST>,
+        {
+            fn from_sql(bytes: Option<&DB::RawValue>) -> Result<Self, Box<Error + Send + Sync>> {
+                T::from_sql(bytes).map($ptr::new)
+            }
+        }
+
+        impl<T, ST, DB> ::types::FromSqlRow<ST, DB> for $ptr<T>
+        where
+            DB: Backend + HasSqlType<ST>,
+            $ptr<T>: FromSql<ST, DB>,
+        {
+            fn build_from_row<R: ::row::Row<DB>>(row: &mut R) -> Result<Self, Box<Error + Send + Sync>> {
+                FromSql::<ST, DB>::from_sql(row.take())
+            }
+        }
+
+        impl<T, ST, DB> ::Queryable<ST, DB> for $ptr<T>
+        where
+            DB: Backend + HasSqlType<ST>,
+            Self: ::types::FromSqlRow<ST, DB>,
+        {
+            type Row = Self;
+
+            fn build(row: Self::Row) -> Self {
+                row
+            }
+        }
+    )+}
+}
+
+use std::rc::Rc;
+use std::sync::Arc;
+smart_pointer_impls!(Box, Rc, Arc);
+
 use expression::bound::Bound;
 use expression::{AsExpression, Expression};
 impl<'a, T: ?Sized, ST> ::expression::AsExpression<ST> for Cow<'a, T>
@@ -6,10 +6,14 @@ use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression}
 use insertable::{CanInsertInSingleQuery, InsertValues, Insertable};
 use query_builder::*;
 use query_builder::insert_statement::UndecoratedInsertRecord;
-use query_source::{QuerySource, Queryable, Table};
+#[cfg(feature = "postgres")]
+use query_source::WritableColumn;
+use query_source::{AliasableColumns, QuerySource, Queryable, Table};
 use result::QueryResult;
 use row::Row;
 use types::{FromSqlRow, HasSqlType, NotNull};
+#[cfg(feature = "postgres")]
+use types::ToSql;
 use util::TupleAppend;
 
 macro_rules! tuple_impls {
@@ -103,6 +107,17 @@ macro_rules! tuple_impls {
                 }
             }
 
+            impl<$($T,)+ Tab> Insertable<Tab> for ($($T,)+)
+            where
+                $($T: Insertable<Tab> + UndecoratedInsertRecord<Tab>,)+
+            {
+                type Values = ($($T::Values,)+);
+
+                fn values(self) -> Self::Values {
+                    ($(self.$idx.values(),)+)
+                }
+            }
+
             impl<'a, $($T,)+ Tab> Insertable<Tab> for &'a ($($T,)+)
             where
                 $(&'a $T: Insertable<Tab> + UndecoratedInsertRecord<Tab>,)+
@@ -119,6 +134,134 @@ macro_rules! tuple_impls {
             #[cfg(feature = "postgres")]
             impl<$($T,)+> ::pg::upsert::OnConflictExtension for ($($T,)+) {}
 
+            #[cfg(feature = "postgres")]
+            impl<$($T,)+> ::pg::expression::nulls_default::WithNullsLastDefault for ($($T,)+) where
+                $($T: ::pg::expression::nulls_default::WithNullsLastDefault,)+
+            {
+                type Output = ($($T::Output,)+);
+
+                fn with_nulls_last_default(self) -> Self::Output {
+                    ($(
+                        ::pg::expression::nulls_default::WithNullsLastDefault::with_nulls_last_default(
+                            self.$idx,
+                        ),
+                    )+)
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl<$($T,)+> ::pg::unnest::IntoColumnArrays for Vec<($($T,)+)> {
+                type Arrays = ($(Vec<$T>,)+);
+
+                fn row_count(&self) -> usize {
+                    self.len()
+                }
+
+                fn into_column_arrays(self) -> Self::Arrays {
+                    let mut arrays = ($(Vec::<$T>::with_capacity(self.len()),)+);
+                    for row in self {
+                        $(arrays.$idx.push(row.$idx);)+
+                    }
+                    arrays
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            impl<$($T,)+ $($ST,)+> CanInsertInSingleQuery<::pg::Pg>
+                for ::pg::unnest::UnnestValues<($($T,)+), ($(Vec<$ST>,)+)>
+            {
+                fn rows_to_insert(&self) -> usize {
+                    self.rows
+                }
+            }
+
+            #[cfg(feature = "postgres")]
+            #[allow(unused_assignments)]
+            impl<$($T,)+ $($ST,)+ Tab> InsertValues<Tab, ::pg::Pg>
+                for ::pg::unnest::UnnestValues<($($T,)+), ($(Vec<$ST>,)+)>
+            where
+                Tab: Table,
+                $($T: WritableColumn<Table = Tab>,)+
+                $(::pg::Pg: HasSqlType<$T::SqlType>,)+
+                $(Vec<$ST>: ToSql<::pg::types::sql_types::Array<$T::SqlType>, ::pg::Pg>,)+
+            {
+                fn column_names(&self, out: &mut ::pg::PgQueryBuilder) -> QueryResult<()> {
+                    let mut needs_comma = false;
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        out.push_identifier($T::NAME)?;
+                        needs_comma = true;
+                    )+
+                    Ok(())
+                }
+
+                fn walk_ast(&self, mut out: AstPass<::pg::Pg>) -> QueryResult<()> {
+                    let mut needs_comma = false;
+                    out.push_sql("SELECT * FROM unnest(");
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        out.push_bind_param::<::pg::types::sql_types::Array<$T::SqlType>, _>(
+                            &self.arrays.$idx,
+                        )?;
+                        needs_comma = true;
+                    )+
+                    out.push_sql(")");
+                    Ok(())
+                }
+
+                fn is_noop(&self) -> bool {
+                    false
+                }
+
+                fn requires_parenthesis(&self) -> bool {
+                    false
+                }
+
+                fn requires_values_keyword(&self) -> bool {
+                    false
+                }
+            }
+
+            #[allow(unused_assignments)]
+            impl<$($T,)+ Tab> ColumnList<Tab> for ($($T,)+)
+            where
+                $($T: ColumnList<Tab>,)+
+            {
+                fn column_names<DB: Backend>(&self, out: &mut DB::QueryBuilder) -> QueryResult<()> {
+                    let mut needs_comma = false;
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        self.$idx.column_names::<DB>(out)?;
+                        needs_comma = true;
+                    )+
+                    Ok(())
+                }
+            }
+
+            #[allow(unused_assignments)]
+            impl<$($T,)+ Tab> AliasableColumns<Tab> for ($($T,)+)
+            where
+                $($T: AliasableColumns<Tab>,)+
+            {
+                fn walk_aliased_ast<DB: Backend>(&self, alias: &str, mut out: AstPass<DB>) -> QueryResult<()> {
+                    let mut needs_comma = false;
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        self.$idx.walk_aliased_ast(alias, out.reborrow())?;
+                        needs_comma = true;
+                    )+
+                    Ok(())
+                }
+            }
+
             #[allow(unused_assignments)]
             impl<$($T,)+ Tab, DB> InsertValues<Tab, DB> for ($($T,)+)
             where
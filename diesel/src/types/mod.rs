@@ -270,12 +270,16 @@ pub struct Date;
 ///
 /// - [`PgInterval`][PgInterval] which can be constructed using the [interval
 ///   DSLs][interval dsls]
+/// - [`std::time::Duration`][Duration]
 ///
 /// ### [`FromSql`](/diesel/types/trait.FromSql.html) impls
 ///
 /// - [`PgInterval`][PgInterval] which can be constructed using the [interval
 ///   DSLs][interval dsls]
+/// - [`std::time::Duration`][Duration] (only for intervals without a months
+///   component)
 ///
+/// [Duration]: https://doc.rust-lang.org/std/time/struct.Duration.html
 /// [PgInterval]: /diesel/pg/data_types/struct.PgInterval.html
 /// [interval dsls]: /diesel/pg/expression/extensions/index.html
 #[derive(Debug, Clone, Copy, Default)]
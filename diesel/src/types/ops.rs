@@ -59,6 +59,12 @@ pub trait Div {
     type Output;
 }
 
+/// Represents SQL integer types which support bitwise operators.
+///
+/// The right side of `&`, `|` and XOR always has the same SQL type as the left side. The right
+/// side of the shift operators is always `Integer`.
+pub trait Bitwise {}
+
 macro_rules! numeric_type {
     ($($tpe: ident),*) => {
         $(
@@ -107,6 +113,13 @@ macro_rules! numeric_type {
 
 numeric_type!(SmallInt, Integer, BigInt, Float, Double, Numeric);
 
+impl Bitwise for super::SmallInt {}
+impl Bitwise for super::Nullable<super::SmallInt> {}
+impl Bitwise for super::Integer {}
+impl Bitwise for super::Nullable<super::Integer> {}
+impl Bitwise for super::BigInt {}
+impl Bitwise for super::Nullable<super::BigInt> {}
+
 impl Add for super::Time {
     type Rhs = super::Interval;
     type Output = super::Time;
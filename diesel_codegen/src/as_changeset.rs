@@ -12,6 +12,12 @@ pub fn derive_as_changeset(item: syn::DeriveInput) -> quote::Tokens {
     let table_name = model.table_name();
     let struct_ty = &model.ty;
     let mut lifetimes = item.generics.lifetimes;
+    let version_attrs = model
+        .attrs
+        .as_slice()
+        .iter()
+        .filter(|a| a.is_version)
+        .collect::<Vec<_>>();
     let attrs = model
         .attrs
         .as_slice()
@@ -20,6 +26,7 @@ pub fn derive_as_changeset(item: syn::DeriveInput) -> quote::Tokens {
             Some(ref name) => !model.primary_key_names.contains(name),
             None => true,
         })
+        .filter(|a| !a.is_version)
         .collect::<Vec<_>>();
 
     if attrs.is_empty() {
@@ -34,16 +41,51 @@ pub fn derive_as_changeset(item: syn::DeriveInput) -> quote::Tokens {
         lifetimes.push(syn::LifetimeDef::new("'a"));
     }
 
-    quote!(impl_AsChangeset! {
-        (
-            struct_name = #struct_name,
-            table_name = #table_name,
-            treat_none_as_null = #treat_none_as_null,
-            struct_ty = #struct_ty,
-            lifetimes = (#(#lifetimes),*),
-        ),
-        fields = [#(#attrs)*],
-    })
+    let versioned_changeset_impl = match version_attrs.len() {
+        0 => quote!(),
+        1 => {
+            let version_attr = version_attrs[0];
+            let field_name = match version_attr.field_name {
+                Some(ref name) => name,
+                None => panic!(
+                    "`#[diesel(version)]` can only be used on structs with named fields"
+                ),
+            };
+            let column_name = version_attr.column_name.as_ref().unwrap_or(field_name);
+            let version_ty = &version_attr.ty;
+            let impl_lifetimes = lifetimes.clone();
+            quote!(impl<#(#impl_lifetimes,)* 'update> diesel::query_builder::VersionedChangeset
+                for &'update #struct_ty
+            {
+                type VersionColumn = #table_name::#column_name;
+                type Version = #version_ty;
+
+                fn version_column(&self) -> Self::VersionColumn {
+                    #table_name::#column_name
+                }
+
+                fn current_version(&self) -> Self::Version {
+                    self.#field_name
+                }
+            })
+        }
+        _ => panic!("Only one field can be annotated with `#[diesel(version)]`"),
+    };
+
+    quote!(
+        impl_AsChangeset! {
+            (
+                struct_name = #struct_name,
+                table_name = #table_name,
+                treat_none_as_null = #treat_none_as_null,
+                struct_ty = #struct_ty,
+                lifetimes = (#(#lifetimes),*),
+            ),
+            fields = [#(#attrs)*],
+        }
+
+        #versioned_changeset_impl
+    )
 }
 
 fn treat_none_as_null(attrs: &[syn::Attribute]) -> bool {
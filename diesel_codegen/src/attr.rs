@@ -10,6 +10,7 @@ pub struct Attr {
     pub column_name: Option<syn::Ident>,
     pub field_name: Option<syn::Ident>,
     pub ty: syn::Ty,
+    pub is_version: bool,
     field_position: usize,
 }
 
@@ -20,11 +21,15 @@ impl Attr {
             .cloned()
             .or_else(|| field_name.clone());
         let ty = field.ty.clone();
+        let is_version = list_value_of_attr_with_name(&field.attrs, "diesel")
+            .map(|options| options.iter().any(|option| *option == "version"))
+            .unwrap_or(false);
 
         Attr {
             column_name: column_name,
             field_name: field_name,
             ty: ty,
+            is_version: is_version,
             field_position: index,
         }
     }
@@ -0,0 +1,110 @@
+use quote::Tokens;
+use syn;
+
+use util::{get_optional_option, get_options_from_input};
+
+pub fn derive_db_enum(item: syn::DeriveInput) -> Tokens {
+    let enum_name = &item.ident;
+    let variants = match item.body {
+        syn::Body::Enum(ref variants) => variants,
+        syn::Body::Struct(..) => panic!("`#[derive(DbEnum)]` can only be used on enums"),
+    };
+
+    if !item.generics.lifetimes.is_empty() || !item.generics.ty_params.is_empty() {
+        panic!("`#[derive(DbEnum)]` does not support generic enums");
+    }
+
+    if variants.is_empty() {
+        panic!(
+            "Failed to derive `DbEnum` for `{}`: `DbEnum` cannot be used on \
+             enums with no variants",
+            enum_name
+        );
+    }
+
+    let options =
+        get_options_from_input("diesel", &item.attrs, invalid_diesel_attr).unwrap_or_default();
+    let repr = get_optional_option(&options, "repr").unwrap_or_else(|| {
+        panic!(
+            "`#[derive(DbEnum)]` requires the enum to be annotated with \
+             `#[diesel(repr = \"i16\")]` or `#[diesel(repr = \"i32\")]`"
+        )
+    });
+    let (sql_type, min, max) = match repr {
+        "i16" => ("SmallInt", i64::from(i16::min_value()), i64::from(i16::max_value())),
+        "i32" => ("Integer", i64::from(i32::min_value()), i64::from(i32::max_value())),
+        other => panic!(
+            "`#[derive(DbEnum)]` does not support `#[diesel(repr = \"{}\")]`, \
+             use `i16` or `i32`",
+            other
+        ),
+    };
+    let repr = syn::Ident::new(repr);
+    let sql_type = syn::Ident::new(sql_type);
+
+    // The value stored for each variant is its discriminant, cast to `repr`.
+    // We compute the discriminants here so that a value which doesn't fit in
+    // `repr` is rejected instead of being silently truncated by the cast.
+    let mut next_discriminant = 0;
+    let variant_names = variants
+        .iter()
+        .map(|variant| {
+            if variant.data != syn::VariantData::Unit {
+                panic!(
+                    "Failed to derive `DbEnum` for `{}`: the variant `{}` has \
+                     fields, but only unit variants are supported",
+                    enum_name,
+                    variant.ident
+                );
+            }
+            let discriminant = match variant.discriminant {
+                Some(ref expr) => discriminant_value(enum_name, variant, expr),
+                None => next_discriminant,
+            };
+            if discriminant < min || discriminant > max {
+                panic!(
+                    "Failed to derive `DbEnum` for `{}`: the discriminant {} of \
+                     `{}` doesn't fit in `{}`",
+                    enum_name,
+                    discriminant,
+                    variant.ident,
+                    repr
+                );
+            }
+            next_discriminant = discriminant + 1;
+            &variant.ident
+        })
+        .collect::<Vec<_>>();
+
+    quote!(impl_DbEnum! {
+        enum_name = #enum_name,
+        repr = #repr,
+        sql_type = #sql_type,
+        variants = (#(#variant_names),*),
+    })
+}
+
+fn discriminant_value(
+    enum_name: &syn::Ident,
+    variant: &syn::Variant,
+    expr: &syn::ConstExpr,
+) -> i64 {
+    match *expr {
+        syn::ConstExpr::Lit(syn::Lit::Int(value, _)) if value <= i64::max_value() as u64 => {
+            value as i64
+        }
+        syn::ConstExpr::Unary(syn::UnOp::Neg, ref inner) => {
+            -discriminant_value(enum_name, variant, inner)
+        }
+        _ => panic!(
+            "Failed to derive `DbEnum` for `{}`: the discriminant of `{}` must \
+             be an integer literal",
+            enum_name,
+            variant.ident
+        ),
+    }
+}
+
+fn invalid_diesel_attr() -> ! {
+    panic!("`#[derive(DbEnum)]` expects `#[diesel(repr = \"...\")]`");
+}
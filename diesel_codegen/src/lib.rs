@@ -33,6 +33,7 @@ mod as_changeset;
 mod associations;
 mod ast_builder;
 mod attr;
+mod db_enum;
 mod embed_migrations;
 mod identifiable;
 mod insertable;
@@ -64,7 +65,7 @@ pub fn derive_insertable(input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_derive(AsChangeset,
-                    attributes(table_name, primary_key, column_name, changeset_options))]
+                    attributes(table_name, primary_key, column_name, changeset_options, diesel))]
 pub fn derive_as_changeset(input: TokenStream) -> TokenStream {
     expand_derive(input, as_changeset::derive_as_changeset)
 }
@@ -74,6 +75,11 @@ pub fn derive_associations(input: TokenStream) -> TokenStream {
     expand_derive(input, associations::derive_associations)
 }
 
+#[proc_macro_derive(DbEnum, attributes(diesel))]
+pub fn derive_db_enum(input: TokenStream) -> TokenStream {
+    expand_derive(input, db_enum::derive_db_enum)
+}
+
 #[proc_macro_derive(InferSchema, attributes(infer_schema_options))]
 #[cfg(feature = "diesel_infer_schema")]
 pub fn derive_infer_schema(input: TokenStream) -> TokenStream {
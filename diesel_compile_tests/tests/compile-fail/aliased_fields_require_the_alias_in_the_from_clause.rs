@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let other_users = alias!(users as other_users);
+
+    let _ = users::table.select(other_users.field(users::name));
+    //~^ ERROR E0277
+
+    let _ = users::table.filter(other_users.field(users::id).eq(1));
+    //~^ ERROR E0277
+}
@@ -0,0 +1,27 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let _ = users.select(name).union(users.select(id));
+    //~^ ERROR E0271
+
+    let _ = users.select(name).union_all(users.select((id, name)));
+    //~^ ERROR E0271
+
+    let _ = users.select(name).intersect(users.select(name).order(id));
+    //~^ ERROR E0277
+
+    let _ = users.select(name).except(users.select(name).limit(1));
+    //~^ ERROR E0277
+}
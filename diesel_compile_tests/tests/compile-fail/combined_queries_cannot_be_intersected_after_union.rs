@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let _ = users.select(name)
+        .union(users.select(name))
+        .intersect(users.select(name));
+        //~^ ERROR E0277
+
+    let _ = users.select(name)
+        .union(users.select(name))
+        .positional_order_by(1)
+        .union(users.select(name));
+        //~^ ERROR no method named `union`
+}
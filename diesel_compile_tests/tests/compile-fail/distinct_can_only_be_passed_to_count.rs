@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let source = users::table.select(distinct(users::name));
+    //~^ ERROR E0277
+    let source = users::table.filter(distinct(users::name).eq("Sean"));
+    //~^ ERROR E0599
+}
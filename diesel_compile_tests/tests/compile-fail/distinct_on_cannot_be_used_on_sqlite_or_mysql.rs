@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::mysql::MysqlConnection;
+use diesel::sqlite::SqliteConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = SqliteConnection::establish("").unwrap();
+    users::table
+        .distinct_on(users::name)
+        .load::<(i32, String)>(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+
+    let conn = MysqlConnection::establish("").unwrap();
+    users::table
+        .distinct_on(users::name)
+        .load::<(i32, String)>(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+}
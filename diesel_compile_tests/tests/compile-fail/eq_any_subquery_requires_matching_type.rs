@@ -0,0 +1,34 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+        title -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = PgConnection::establish("").unwrap();
+
+    users::table
+        .filter(users::id.eq_any(posts::table.select(posts::title)))
+        //~^ ERROR E0271
+        .load::<(i32, String)>(&conn);
+
+    users::table
+        .filter(users::id.ne_any(posts::table.select((posts::user_id, posts::title))))
+        //~^ ERROR E0271
+        .load::<(i32, String)>(&conn);
+}
@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+
+table! {
+    line_items {
+        id -> Integer,
+        quantity -> Integer,
+        #[generated]
+        total -> Integer,
+    }
+}
+
+fn main() {
+    let conn = PgConnection::establish("").unwrap();
+
+    insert_into(line_items::table)
+        .values(line_items::total.eq(1))
+        .execute(&conn);
+        //~^ ERROR E0277
+
+    update(line_items::table)
+        .set(line_items::total.eq(1))
+        //~^ ERROR E0277
+        .execute(&conn);
+}
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+    }
+}
+
+fn main() {
+    let source = users::table.group_by(posts::id);
+    //~^ ERROR AppearsInFromClause
+}
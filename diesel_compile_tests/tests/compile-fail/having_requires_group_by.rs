@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::count;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let source = users::table.select(users::name).having(count(users::id).gt(1));
+    //~^ ERROR E0599
+}
@@ -0,0 +1,21 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = PgConnection::establish("").unwrap();
+
+    insert_into(users::table)
+        .values(users::table.select((users::id, users::name)))
+        .execute(&conn);
+        //~^ ERROR E0277
+}
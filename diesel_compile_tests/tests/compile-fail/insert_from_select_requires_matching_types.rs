@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+        title -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = PgConnection::establish("").unwrap();
+
+    insert_into(posts::table)
+        .values(users::table.select((users::name, users::id)))
+        .into_columns((posts::user_id, posts::title))
+        //~^ ERROR E0271
+        .execute(&conn);
+
+    insert_into(posts::table)
+        .values(users::table.select((users::id, users::name)))
+        .into_columns((users::id, users::name))
+        //~^ ERROR E0277
+        .execute(&conn);
+}
@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+fn main() {
+    users::table.skip_locked();
+    //~^ ERROR no method named `skip_locked` found
+    users::table.for_update().no_wait().skip_locked();
+    //~^ ERROR no method named `skip_locked` found
+}
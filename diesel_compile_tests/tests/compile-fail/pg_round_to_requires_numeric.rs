@@ -0,0 +1,20 @@
+#[macro_use] extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::*;
+use diesel::pg::PgConnection;
+
+table! {
+    prices {
+        id -> Integer,
+        amount -> Double,
+    }
+}
+
+fn main() {
+    use self::prices::dsl::*;
+    let connection = PgConnection::establish("").unwrap();
+
+    prices.select(round_to(amount, 2)).load::<f64>(&connection);
+    //~^ ERROR E0271
+}
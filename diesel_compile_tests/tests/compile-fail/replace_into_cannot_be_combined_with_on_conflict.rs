@@ -0,0 +1,24 @@
+#[macro_use] extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    replace_into(users)
+        .values(&name.eq("Sean"))
+        .on_conflict_do_nothing();
+    //~^ ERROR no method named `on_conflict_do_nothing` found
+
+    replace_into(users)
+        .values(&name.eq("Sean"))
+        .on_conflict(id);
+    //~^ ERROR no method named `on_conflict` found
+}
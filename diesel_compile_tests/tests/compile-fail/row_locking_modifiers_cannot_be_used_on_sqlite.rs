@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::sqlite::SqliteConnection;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+fn main() {
+    let conn = SqliteConnection::establish("").unwrap();
+    users::table
+        .for_share()
+        .load(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+    users::table
+        .for_update()
+        .skip_locked()
+        .load(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+}
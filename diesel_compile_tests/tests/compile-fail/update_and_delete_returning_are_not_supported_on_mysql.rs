@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::mysql::MysqlConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let conn = MysqlConnection::establish("").unwrap();
+    update(users.filter(id.eq(1)))
+        .set(name.eq("Sean"))
+        .returning(name)
+        .load::<String>(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+    delete(users.filter(id.eq(1)))
+        .returning(id)
+        .get_results::<i32>(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+}
@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::types::*;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+sql_aggregate_function!(median, median_t, (x: Integer) -> Nullable<Integer>);
+
+fn main() {
+    use self::users::dsl::*;
+
+    let source = users.filter(median(id).gt(3));
+    //~^ ERROR NonAggregate
+}
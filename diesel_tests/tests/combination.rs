@@ -0,0 +1,179 @@
+use schema::*;
+use diesel::*;
+use diesel::query_builder::OrderColumn;
+
+#[test]
+fn union_removes_duplicate_rows() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim'), ('Sean')")
+        .unwrap();
+
+    let data = users
+        .select(name)
+        .filter(name.eq("Sean"))
+        .union(users.select(name).filter(name.ne("Jim")))
+        .positional_order_by(1)
+        .load::<String>(&connection);
+
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string()];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn union_all_keeps_duplicate_rows() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let data = users
+        .select(name)
+        .union_all(users.select(name).filter(name.eq("Tess")))
+        .positional_order_by(1)
+        .load::<String>(&connection);
+
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string(), "Tess".to_string()];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn tables_can_be_combined_with_queries_selecting_the_same_types() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let tess = find_user_by_name("Tess", &connection);
+
+    let data = users
+        .union(users.filter(name.eq("Tess")))
+        .positional_order_by(OrderColumn::from(2).desc())
+        .load::<User>(&connection);
+
+    let sean = find_user_by_name("Sean", &connection);
+    assert_eq!(Ok(vec![tess, sean]), data);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Our CI runs a MySQL version older than 8.0.31
+fn intersect_returns_rows_returned_by_both_queries() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let data = users
+        .select(name)
+        .filter(name.ne("Jim"))
+        .intersect(users.select(name).filter(name.ne("Sean")))
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Tess".to_string()]), data);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Our CI runs a MySQL version older than 8.0.31
+fn except_removes_rows_returned_by_the_second_query() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let data = users
+        .select(name)
+        .except(users.select(name).filter(name.eq("Tess")))
+        .positional_order_by(1)
+        .load::<String>(&connection);
+
+    let expected_data = vec!["Jim".to_string(), "Sean".to_string()];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn order_limit_and_offset_apply_to_the_combined_result() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let source = users
+        .select(name)
+        .filter(name.eq("Sean"))
+        .union(users.select(name).filter(name.ne("Sean")))
+        .positional_order_by(OrderColumn::from(1).desc())
+        .limit(2)
+        .offset(1);
+
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` WHERE `users`.`name` = ? \
+                            UNION \
+                            SELECT `users`.`name` FROM `users` WHERE `users`.`name` != ? \
+                            ORDER BY 1 DESC LIMIT ? OFFSET ? \
+                            -- binds: [\"Sean\", \"Sean\", 2, 1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replacen('?', "$1", 1)
+            .replacen('?', "$2", 1)
+            .replacen('?', "$3", 1)
+            .replacen('?', "$4", 1);
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let expected_data = vec!["Sean".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected_data), source.load(&connection));
+}
+
+#[test]
+fn combined_queries_can_be_combined_again() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let source = users
+        .select(name)
+        .filter(name.eq("Sean"))
+        .union(users.select(name).filter(name.eq("Tess")))
+        .union_all(users.select(name).filter(name.ne("Jim")))
+        .positional_order_by(1);
+
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` WHERE `users`.`name` = ? \
+                            UNION \
+                            SELECT `users`.`name` FROM `users` WHERE `users`.`name` = ? \
+                            UNION ALL \
+                            SELECT `users`.`name` FROM `users` WHERE `users`.`name` != ? \
+                            ORDER BY 1 \
+                            -- binds: [\"Sean\", \"Tess\", \"Jim\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replacen('?', "$1", 1)
+            .replacen('?', "$2", 1)
+            .replacen('?', "$3", 1);
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let expected_data = vec![
+        "Sean".to_string(),
+        "Sean".to_string(),
+        "Tess".to_string(),
+        "Tess".to_string(),
+    ];
+    assert_eq!(Ok(expected_data), source.load(&connection));
+}
@@ -42,3 +42,148 @@ fn return_deleted_records() {
     let num_users = users.count().first(&connection);
     assert_eq!(Ok(1), num_users);
 }
+
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn delete_can_return_entire_deleted_records() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+
+    let archived_users = delete(users)
+        .filter(name.eq("Sean"))
+        .get_results::<User>(&connection);
+    assert_eq!(Ok(vec![sean]), archived_users);
+
+    let remaining_users = users.load::<User>(&connection);
+    assert_eq!(Ok(vec![tess]), remaining_users);
+}
+
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn delete_returning_can_be_loaded_for_every_deleted_row() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+
+    let deleted_ids = delete(users)
+        .returning(id)
+        .load::<i32>(&connection)
+        .map(|mut ids| {
+            ids.sort();
+            ids
+        });
+    assert_eq!(Ok(vec![sean.id, tess.id]), deleted_ids);
+    assert_eq!(Ok(0), users.count().get_result(&connection));
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn delete_returning_on_sqlite() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+
+    let deleted_ids = delete(users.filter(name.eq("Sean")))
+        .returning(id)
+        .get_results::<i32>(&connection);
+
+    if sqlite_supports_returning(&connection) {
+        assert_eq!(Ok(vec![sean.id]), deleted_ids);
+        assert_eq!(Ok(1), users.count().get_result(&connection));
+    } else {
+        let message = deleted_ids.unwrap_err().to_string();
+        assert!(
+            message.contains("requires SQLite 3.35.0 or newer"),
+            "Unexpected error message: {}",
+            message
+        );
+        assert_eq!(Ok(2), users.count().get_result(&connection));
+    }
+}
+
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn delete_returning_records_with_no_matches_returns_empty_vec() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let archived_users = delete(users)
+        .filter(name.eq("Jim"))
+        .get_results::<User>(&connection);
+    assert_eq!(Ok(Vec::new()), archived_users);
+}
+
+#[test]
+fn delete_with_require_affected_errors_when_the_count_does_not_match() {
+    use diesel::result::Error::UnexpectedRowCount;
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let deleted_rows = delete(users.filter(name.eq("Jim")))
+        .require_affected(1)
+        .execute(&connection);
+    assert_eq!(
+        Err(UnexpectedRowCount {
+            expected: 1,
+            actual: 0,
+        }),
+        deleted_rows
+    );
+
+    let deleted_rows = delete(users.filter(name.eq("Sean")))
+        .require_affected(1)
+        .execute(&connection);
+    assert_eq!(Ok(1), deleted_rows);
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_supports_update_delete_limit(connection: &TestConnection) -> bool {
+    use diesel::dsl::sql;
+    use diesel::types::Bool;
+
+    select(sql::<Bool>(
+        "sqlite_compileoption_used('ENABLE_UPDATE_DELETE_LIMIT')",
+    )).get_result(connection)
+        .unwrap()
+}
+
+#[test]
+#[cfg(any(feature = "sqlite", feature = "mysql"))]
+fn delete_with_limit_deletes_at_most_that_many_rows() {
+    use schema::users::dsl::*;
+    let connection = connection();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", Some("black")),
+            NewUser::new("Jim", Some("black")),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let query = delete(users.filter(hair_color.eq("black"))).limit(2);
+    assert_eq!(
+        "DELETE FROM `users` WHERE `users`.`hair_color` = ? LIMIT ? -- binds: [\"black\", 2]",
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+
+    #[cfg(feature = "sqlite")]
+    let limit_is_supported = sqlite_supports_update_delete_limit(&connection);
+    #[cfg(not(feature = "sqlite"))]
+    let limit_is_supported = true;
+    if !limit_is_supported {
+        assert!(query.execute(&connection).is_err());
+        return;
+    }
+    assert_eq!(Ok(2), query.execute(&connection));
+    assert_eq!(Ok(1), users.count().get_result(&connection));
+
+    let updated_rows = update(users)
+        .set(hair_color.eq("brown"))
+        .limit(5)
+        .execute(&connection);
+    assert_eq!(Ok(1), updated_rows);
+}
@@ -22,3 +22,79 @@ fn generated_queryable_allows_lifetimes() {
         users.select((id, name)).first(&connection)
     );
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, DbEnum)]
+#[diesel(repr = "i32")]
+enum Status {
+    Draft = 1,
+    Published,
+    Archived = 10,
+}
+
+#[test]
+fn db_enum_round_trips_through_its_discriminant() {
+    use schema::numbers::dsl::*;
+    let connection = connection();
+
+    insert_into(numbers)
+        .values(&vec![
+            n.eq(Status::Draft),
+            n.eq(Status::Published),
+            n.eq(Status::Archived),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let raw_values = numbers.select(n).order(n).load::<i32>(&connection);
+    assert_eq!(Ok(vec![1, 2, 10]), raw_values);
+
+    let statuses = numbers.select(n).order(n).load::<Status>(&connection);
+    assert_eq!(
+        Ok(vec![Status::Draft, Status::Published, Status::Archived]),
+        statuses
+    );
+
+    let archived = numbers
+        .select(n)
+        .filter(n.eq(Status::Archived))
+        .first::<Status>(&connection);
+    assert_eq!(Ok(Status::Archived), archived);
+}
+
+#[test]
+fn db_enum_with_unknown_discriminant_is_an_error() {
+    use diesel::result::Error::DeserializationError;
+    use schema::numbers::dsl::*;
+    let connection = connection();
+
+    insert_into(numbers)
+        .values(&n.eq(7))
+        .execute(&connection)
+        .unwrap();
+
+    match numbers.select(n).first::<Status>(&connection) {
+        Err(DeserializationError(e)) => {
+            assert_eq!("Unrecognized discriminant 7 for `Status`", e.to_string());
+        }
+        other => panic!("Expected a deserialization error, got {:?}", other),
+    }
+}
+
+#[test]
+fn values_can_be_loaded_into_smart_pointers() {
+    use schema::users::dsl::*;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let names = users.select(name).order(id).load::<Arc<String>>(&connection);
+    let expected_names = vec![Arc::new("Sean".to_string()), Arc::new("Tess".to_string())];
+    assert_eq!(Ok(expected_names), names);
+
+    let data = users
+        .select((id, name, hair_color))
+        .order(id)
+        .first::<(Box<i32>, Rc<String>, Option<Arc<String>>)>(&connection);
+    let expected_data = (Box::new(1), Rc::new("Sean".to_string()), None);
+    assert_eq!(Ok(expected_data), data);
+}
@@ -154,6 +154,126 @@ fn date_uses_sql_function_date() {
     assert_eq!(Ok(expected_data), actual_data);
 }
 
+#[test]
+fn extract_returns_each_field_as_a_double() {
+    use diesel::types::Timestamp;
+
+    let connection = connection();
+    #[cfg(not(feature = "sqlite"))]
+    let timestamp = sql::<Timestamp>("TIMESTAMP '2015-11-15 06:07:41'");
+    #[cfg(feature = "sqlite")]
+    let timestamp = sql::<Timestamp>("'2015-11-15 06:07:41'");
+
+    let parts = select((
+        year(timestamp.clone()),
+        month(timestamp.clone()),
+        day(timestamp.clone()),
+        hour(timestamp.clone()),
+        minute(timestamp.clone()),
+        second(timestamp),
+    )).get_result::<(f64, f64, f64, f64, f64, f64)>(&connection);
+    assert_eq!(Ok((2015.0, 11.0, 15.0, 6.0, 7.0, 41.0)), parts);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn extract_can_be_used_to_bucket_timestamps() {
+    use self::has_timestamps::dsl::*;
+
+    let connection = connection();
+    setup_test_table(&connection);
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (created_at, updated_at) VALUES
+                       ('2015-11-15 06:07:41', '2015-11-15 20:07:41'),
+                       ('2015-12-16 06:07:41', '2015-12-17 20:07:41'),
+                       ('2016-11-16 18:07:41', '2016-11-16 02:07:41')
+                       ",
+        )
+        .unwrap();
+
+    let source = has_timestamps
+        .select((year(created_at), count_star()))
+        .filter(month(created_at).eq(11.0))
+        .group_by(year(created_at))
+        .order(year(created_at));
+    if cfg!(feature = "postgres") {
+        let expected_sql = "SELECT date_part('YEAR', \"has_timestamps\".\"created_at\"), COUNT(*) \
+                            FROM \"has_timestamps\" \
+                            WHERE date_part('MONTH', \"has_timestamps\".\"created_at\") = $1 \
+                            GROUP BY date_part('YEAR', \"has_timestamps\".\"created_at\") \
+                            ORDER BY date_part('YEAR', \"has_timestamps\".\"created_at\") \
+                            -- binds: [11.0]";
+        assert_eq!(
+            expected_sql,
+            debug_query::<::schema::TestBackend, _>(&source).to_string()
+        );
+    }
+    let expected_data = vec![(2015.0, 1), (2016.0, 1)];
+    assert_eq!(Ok(expected_data), source.load::<(f64, i64)>(&connection));
+
+    connection
+        .execute(
+            "INSERT INTO nullable_date_and_time (timestamp) VALUES
+                       (NULL), ('2017-01-02 03:04:05')",
+        )
+        .unwrap();
+    let years = nullable_date_and_time::table
+        .select(extract(date_part::Year, nullable_date_and_time::timestamp))
+        .order(nullable_date_and_time::id)
+        .load::<Option<f64>>(&connection);
+    assert_eq!(Ok(vec![None, Some(2017.0)]), years);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn date_trunc_can_be_used_to_bucket_timestamps() {
+    use self::has_timestamps::dsl::*;
+    use diesel::dsl::date_part::{Day, Hour};
+
+    let connection = connection();
+    setup_test_table(&connection);
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (created_at, updated_at) VALUES
+                       ('2015-11-15 06:07:41', '2015-11-15 20:07:41'),
+                       ('2015-11-15 06:59:59', '2015-11-17 20:07:41'),
+                       ('2015-11-15 07:00:00', '2015-11-16 02:07:41')
+                       ",
+        )
+        .unwrap();
+
+    let source = has_timestamps
+        .select((date_trunc(Hour, created_at), count_star()))
+        .group_by(date_trunc(Hour, created_at))
+        .order(date_trunc(Hour, created_at).desc());
+    let expected_sql = "SELECT date_trunc('hour', \"has_timestamps\".\"created_at\"), COUNT(*) \
+                        FROM \"has_timestamps\" \
+                        GROUP BY date_trunc('hour', \"has_timestamps\".\"created_at\") \
+                        ORDER BY date_trunc('hour', \"has_timestamps\".\"created_at\") DESC \
+                        -- binds: []";
+    assert_eq!(
+        expected_sql,
+        debug_query::<::schema::TestBackend, _>(&source).to_string()
+    );
+
+    let timestamp = |value: &str| {
+        select(sql::<types::Timestamp>(&format!("TIMESTAMP '{}'", value)))
+            .get_result::<PgTimestamp>(&connection)
+            .unwrap()
+    };
+    let seven = timestamp("2015-11-15 07:00:00");
+    let six = timestamp("2015-11-15 06:00:00");
+    let expected_data = vec![(seven, 1), (six, 2)];
+    assert_eq!(Ok(expected_data), source.load::<(PgTimestamp, i64)>(&connection));
+
+    let days = has_timestamps
+        .filter(date_trunc(Day, updated_at).eq(date_trunc(Day, created_at)))
+        .count()
+        .get_result(&connection);
+    assert_eq!(Ok(1), days);
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn time_is_deserialized_properly() {
@@ -1,7 +1,7 @@
 mod date_and_time;
 mod ops;
 
-use schema::{connection, NewUser, TestBackend};
+use schema::{connection, database_version_is_at_least, NewUser, TestBackend};
 use schema::users::dsl::*;
 use diesel::*;
 use diesel::backend::Backend;
@@ -41,6 +41,40 @@ fn test_count_star() {
     );
 }
 
+#[test]
+fn count_star_over_counts_all_rows_matched_before_limit() {
+    let connection = connection();
+    let data: &[_] = &[
+        NewUser::new("Sean", None),
+        NewUser::new("Tess", None),
+        NewUser::new("Jim", None),
+    ];
+    insert_into(users).values(data).execute(&connection).unwrap();
+    let source = users
+        .select((name, count_star().over()))
+        .filter(name.ne("Jim"))
+        .order(name)
+        .limit(1);
+
+    let mut expected_sql = "SELECT `users`.`name`, COUNT(*) OVER () FROM `users`".to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert!(
+        debug_query::<TestBackend, _>(&source)
+            .to_string()
+            .starts_with(&expected_sql)
+    );
+
+    // Window functions are only supported since SQLite 3.25 and MySQL 8.0
+    if (cfg!(feature = "sqlite") && !database_version_is_at_least(&connection, 3, 25))
+        || (cfg!(feature = "mysql") && !database_version_is_at_least(&connection, 8, 0))
+    {
+        return;
+    }
+    assert_eq!(Ok(vec![("Sean".to_string(), 2)]), source.load(&connection));
+}
+
 table! {
     numbers (n) {
         n -> Integer,
@@ -184,6 +218,181 @@ fn function_with_multiple_arguments() {
     assert_eq!(Ok(expected_data), data);
 }
 
+#[test]
+fn coalesce_can_be_used_in_select_and_filter() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", None),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users.select(dsl::coalesce(hair_color, name)).order(id);
+    let mut expected_sql = "SELECT COALESCE(`users`.`hair_color`, `users`.`name`) \
+                            FROM `users` ORDER BY `users`.`id` -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec!["black".to_string(), "Tess".to_string()];
+    assert_eq!(Ok(expected_data), source.load::<String>(&connection));
+
+    let data = users
+        .select(name)
+        .filter(dsl::coalesce(hair_color, "none").eq("none"))
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Tess".to_string()]), data);
+}
+
+#[test]
+fn coalesce_nullable_accepts_a_nullable_default() {
+    use schema::{posts, users};
+
+    let connection = connection();
+    connection
+        .execute(
+            "INSERT INTO users (id, name, hair_color) VALUES \
+             (1, 'Sean', NULL), (2, 'Tess', NULL), (3, 'Jim', 'black')",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO posts (user_id, title, body) VALUES \
+             (1, 'Hello', 'World')",
+        )
+        .unwrap();
+
+    let source = users::table
+        .left_outer_join(posts::table)
+        .select(dsl::coalesce_nullable(users::hair_color, posts::body.nullable()))
+        .order(users::id);
+    let mut expected_sql = "SELECT COALESCE(`users`.`hair_color`, `posts`.`body`) \
+                            FROM (`users` LEFT OUTER JOIN `posts` \
+                            ON `posts`.`user_id` = `users`.`id`) \
+                            ORDER BY `users`.`id` -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec![Some("World".to_string()), None, Some("black".to_string())];
+    assert_eq!(Ok(expected_data), source.load::<Option<String>>(&connection));
+}
+
+#[test]
+fn coalesce_many_returns_the_first_non_null_argument() {
+    use schema::{posts, users};
+
+    let connection = connection();
+    connection
+        .execute(
+            "INSERT INTO users (id, name, hair_color) VALUES \
+             (1, 'Sean', NULL), (2, 'Tess', NULL), (3, 'Jim', 'black')",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO posts (user_id, title, body) VALUES \
+             (1, 'Hello', NULL)",
+        )
+        .unwrap();
+
+    let source = users::table
+        .left_outer_join(posts::table)
+        .select(dsl::coalesce_many((
+            users::hair_color,
+            posts::body.nullable(),
+            posts::title.nullable(),
+            users::name,
+        )))
+        .order(users::id);
+    let mut expected_sql = "SELECT COALESCE(`users`.`hair_color`, `posts`.`body`, \
+                            `posts`.`title`, `users`.`name`) \
+                            FROM (`users` LEFT OUTER JOIN `posts` \
+                            ON `posts`.`user_id` = `users`.`id`) \
+                            ORDER BY `users`.`id` -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec![
+        "Hello".to_string(),
+        "Tess".to_string(),
+        "black".to_string(),
+    ];
+    assert_eq!(Ok(expected_data), source.load::<String>(&connection));
+
+    let all_nullable = users::table
+        .select(dsl::coalesce_many((users::hair_color, users::hair_color)))
+        .order(users::id);
+    let expected_data = vec![None, None, Some("black".to_string())];
+    assert_eq!(
+        Ok(expected_data),
+        all_nullable.load::<Option<String>>(&connection)
+    );
+}
+
+#[test]
+fn string_functions_can_be_used_in_select_and_filter() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("Black")),
+        NewUser::new("Tess", None),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users.select(id).filter(lower(name).eq("sean"));
+    let mut expected_sql = "SELECT `users`.`id` FROM `users` \
+                            WHERE LOWER(`users`.`name`) = ? -- binds: [\"sean\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(1, source.load::<i32>(&connection).unwrap().len());
+
+    let data = users
+        .select((upper(name), length(name)))
+        .order(id)
+        .load::<(String, i32)>(&connection);
+    let expected_data = vec![("SEAN".to_string(), 4), ("TESS".to_string(), 4)];
+    assert_eq!(Ok(expected_data), data);
+
+    let data = users
+        .select((lower(hair_color), length(hair_color)))
+        .order(id)
+        .load::<(Option<String>, Option<i32>)>(&connection);
+    let expected_data = vec![(Some("black".to_string()), Some(5)), (None, None)];
+    assert_eq!(Ok(expected_data), data);
+
+    let data = select((upper("tess"), length("tess"))).get_result::<(String, i32)>(&connection);
+    assert_eq!(Ok(("TESS".to_string(), 4)), data);
+}
+
 #[test]
 fn test_sum() {
     use self::numbers::columns::*;
@@ -354,3 +563,102 @@ fn test_avg_for_numeric() {
     };
     assert_eq!(Ok(Some(expected_result)), result);
 }
+
+#[test]
+fn abs_preserves_the_type_of_its_argument() {
+    use self::numbers::columns::*;
+    use self::numbers::table as numbers;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (-2), (3)")
+        .unwrap();
+    let source = numbers.select(abs(n)).order(n);
+
+    assert_eq!(Ok(vec![2, 3]), source.load::<i32>(&connection));
+}
+
+#[test]
+fn rounding_functions_preserve_the_type_of_their_argument() {
+    use self::precision_numbers::columns::*;
+    use self::precision_numbers::table as numbers;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO precision_numbers (n) VALUES (1.5), (-2.26)")
+        .unwrap();
+
+    let rounded = numbers.select((n, round(n))).order(n).load(&connection);
+    assert_eq!(Ok(vec![(-2.26, -2.0), (1.5, 2.0)]), rounded);
+
+    if cfg!(feature = "sqlite") {
+        return;
+    }
+    let ceil_and_floor = numbers
+        .select((ceil(n), floor(n)))
+        .order(n)
+        .load::<(f64, f64)>(&connection);
+    assert_eq!(Ok(vec![(-2.0, -3.0), (2.0, 1.0)]), ceil_and_floor);
+}
+
+#[test]
+fn rounding_functions_propagate_null() {
+    use self::nullable_doubles::columns::*;
+    use self::nullable_doubles::table as numbers;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO nullable_doubles (n) VALUES (null), (5.5)")
+        .unwrap();
+    let source = numbers.select((round(n), abs(n))).order(id);
+
+    let expected_data = vec![(None, None), (Some(6.0), Some(5.5))];
+    assert_eq!(Ok(expected_data), source.load::<(Option<f64>, Option<f64>)>(&connection));
+}
+
+#[test]
+#[cfg(not(feature = "postgres"))] // PostgreSQL can only round `Numeric` values to a precision
+fn round_to_composes_with_aggregates() {
+    use self::precision_numbers::columns::*;
+    use self::precision_numbers::table as numbers;
+
+    let source = numbers.select(round_to(sum(n), 1));
+    assert_eq!(
+        "SELECT ROUND(SUM(`precision_numbers`.`n`), ?) FROM `precision_numbers` -- binds: [1]",
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO precision_numbers (n) VALUES (1.5), (0.76)")
+        .unwrap();
+    let rounded = source.first::<Option<f64>>(&connection).unwrap().unwrap();
+    assert!((rounded - 2.3).abs() < 1e-9);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn round_to_rounds_numeric_sums() {
+    use self::numeric::columns::*;
+    use self::numeric::table as numeric;
+
+    let connection = connection();
+    connection
+        .execute("CREATE TABLE numeric (n NUMERIC(8,2))")
+        .unwrap();
+    connection
+        .execute("INSERT INTO numeric (n) VALUES (1.25), (1.01)")
+        .unwrap();
+    let source = numeric.select(round_to(sum(n), 1));
+    assert_eq!(
+        "SELECT ROUND(SUM(\"numeric\".\"n\"), $1) FROM \"numeric\" -- binds: [1]",
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let expected_result = data_types::PgNumeric::Positive {
+        digits: vec![2, 3000],
+        weight: 0,
+        scale: 1,
+    };
+    assert_eq!(Ok(Some(expected_result)), source.first(&connection));
+}
@@ -190,3 +190,46 @@ fn mix_and_match_all_numeric_ops() {
     let data = users.select(id * 3 / 2 + 4 - 1).load(&connection);
     assert_eq!(Ok(expected_data), data);
 }
+
+#[test]
+fn filter_by_bitmask() {
+    use super::numbers::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (1), (4), (5), (6), (8)")
+        .unwrap();
+
+    let with_third_bit_set = numbers
+        .select(n)
+        .filter(n.bit_and(4).ne(0))
+        .order(n)
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![4, 5, 6]), with_third_bit_set);
+
+    let with_third_bit_set = numbers
+        .select(n)
+        .filter(n.shift_right(2).bit_and(1).eq(1))
+        .order(n)
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![4, 5, 6]), with_third_bit_set);
+}
+
+#[test]
+fn bitwise_operators_return_the_integer_type() {
+    use super::numbers::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (6)")
+        .unwrap();
+
+    let query = numbers.select((
+        n.bit_and(3),
+        n.bit_or(3),
+        n.bit_xor(3),
+        n.shift_left(2),
+        n.shift_right(1),
+    ));
+    assert_eq!(Ok((2, 7, 5, 24, 3)), query.first(&connection));
+}
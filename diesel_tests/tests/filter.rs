@@ -114,6 +114,54 @@ fn filter_by_is_null_on_nullable_columns() {
     assert_eq!(vec![gordon], source.load(&connection).unwrap());
 }
 
+#[test]
+fn filter_by_eq_nullable_matches_null_values() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Derek", Some("red")),
+        NewUser::new("Gordon", None),
+    ];
+    insert_into(users)
+        .values(&data)
+        .execute(&connection)
+        .unwrap();
+    let data = users.order(id).load::<User>(&connection).unwrap();
+    let derek = data[0].clone();
+    let gordon = data[1].clone();
+
+    let no_color: Option<&str> = None;
+    let source = users.filter(hair_color.eq_nullable(no_color));
+    assert_eq!(vec![gordon], source.load(&connection).unwrap());
+    let source = users.filter(hair_color.eq(no_color));
+    assert_eq!(Vec::<User>::new(), source.load(&connection).unwrap());
+
+    let source = users.filter(hair_color.eq_nullable(Some("red")));
+    assert_eq!(vec![derek], source.load(&connection).unwrap());
+}
+
+#[test]
+fn eq_nullable_between_two_columns_never_returns_null() {
+    use schema::nullable_table::dsl::*;
+
+    let connection = connection_with_nullable_table_data();
+    let null = dsl::sql::<types::Nullable<types::Integer>>("NULL");
+    let source = nullable_table
+        .select(id)
+        .filter(value.eq_nullable(null))
+        .order(id);
+    assert_eq!(Ok(vec![1, 2]), source.load(&connection));
+
+    let source = nullable_table
+        .select(value.eq_nullable(value))
+        .order(id);
+    assert_eq!(
+        Ok(vec![true, true, true, true, true]),
+        source.load::<bool>(&connection)
+    );
+}
+
 #[test]
 fn filter_after_joining() {
     use schema::users::name;
@@ -366,6 +414,36 @@ fn not_affects_arguments_passed_when_they_contain_higher_operator_precedence() {
     assert_eq!(Ok(2), count);
 }
 
+#[test]
+fn filter_by_is_true_and_is_false_handles_null() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", Some("brown")),
+            NewUser::new("Jim", None),
+        ])
+        .execute(&connection)
+        .unwrap();
+    let names = users.select(name).order(id);
+
+    let black = names.filter(hair_color.eq("black").is_true());
+    assert_eq!(Ok(vec!["Sean".to_string()]), black.load(&connection));
+    let not_black = names.filter(hair_color.eq("black").is_false());
+    assert_eq!(Ok(vec!["Tess".to_string()]), not_black.load(&connection));
+    let not_black = names.filter(hair_color.eq("black").eq(false));
+    assert_eq!(Ok(vec!["Tess".to_string()]), not_black.load(&connection));
+
+    let black_or_unknown = names.filter(hair_color.eq("black").is_not_false());
+    let expected = vec!["Sean".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected), black_or_unknown.load(&connection));
+    let not_black_or_unknown = names.filter(hair_color.eq("black").is_not_true());
+    let expected = vec!["Tess".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected), not_black_or_unknown.load(&connection));
+}
+
 use diesel::types::VarChar;
 sql_function!(lower, lower_t, (x: VarChar) -> VarChar);
 
@@ -387,6 +465,97 @@ fn filter_by_boxed_predicate() {
     assert_eq!(Ok(tess), queried_tess);
 }
 
+#[test]
+fn filter_by_separately_built_boxed_predicates_combined_with_and() {
+    use diesel::dsl::BoxedPredicate;
+
+    fn name_isnt(name: &str) -> BoxedPredicate<users::table, TestBackend> {
+        users::name.ne(name).into_boxed_predicate()
+    }
+
+    fn has_hair_color() -> BoxedPredicate<'static, users::table, TestBackend> {
+        users::hair_color.is_not_null().into_boxed_predicate()
+    }
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert_into(users::table)
+        .values(&vec![
+            NewUser::new("Jim", Some("black")),
+            NewUser::new("Ruby", Some("red")),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let predicate = has_hair_color().and(name_isnt("Ruby"));
+    let names = users::table
+        .select(users::name)
+        .filter(predicate)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Jim".to_string()]), names);
+
+    let predicate = name_isnt("Jim").and(name_isnt("Ruby")).into_boxed_predicate();
+    let names = users::table
+        .select(users::name)
+        .filter(predicate)
+        .order(users::id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), names);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_function_call_can_use_functional_index() {
+    use schema::users::dsl::*;
+    use diesel::dsl::sql;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection
+        .execute("CREATE INDEX users_lower_name ON users (lower(name))")
+        .unwrap();
+    connection.execute("SET LOCAL enable_seqscan = off").unwrap();
+
+    let query = users.select(id).filter(lower(name).eq("sean"));
+    let debug_sql = debug_query::<TestBackend, _>(&query).to_string();
+    let expected_sql = "SELECT \"users\".\"id\" FROM \"users\" \
+                        WHERE lower(\"users\".\"name\") = $1";
+    assert!(debug_sql.starts_with(expected_sql), "{}", debug_sql);
+
+    let plan = sql::<types::Text>(&format!("EXPLAIN {}", expected_sql))
+        .bind::<types::VarChar, _>("sean")
+        .load::<String>(&connection)
+        .unwrap();
+    assert!(
+        plan.iter().any(|line| line.contains("users_lower_name")),
+        "Expected the functional index to be used, got {:?}",
+        plan
+    );
+    assert_eq!(Ok(vec![1]), query.load(&connection));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_array_index() {
+    use schema::users::dsl::*;
+    use diesel::dsl::sql;
+    use diesel::types::{Array, Integer};
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let tess = find_user_by_name("Tess", &connection);
+    let numbers = || sql::<Array<Integer>>("ARRAY[10, 20]");
+
+    let query = users.filter(numbers().index(id).eq(20));
+    assert_eq!(Ok(vec![tess]), query.load(&connection));
+
+    let in_bounds = users.select(numbers().index(id)).order(id).load(&connection);
+    assert_eq!(Ok(vec![Some(10), Some(20)]), in_bounds);
+
+    let out_of_bounds = users
+        .select(numbers().index(id + 1))
+        .order(id)
+        .load(&connection);
+    assert_eq!(Ok(vec![Some(20), None]), out_of_bounds);
+}
+
 #[test]
 fn filter_subselect_referencing_outer_table() {
     use diesel::dsl::exists;
@@ -420,6 +589,140 @@ fn filter_subselect_referencing_outer_table() {
     assert_eq!(expected, users_with_published_posts);
 }
 
+#[test]
+fn filter_by_not_exists_subquery_referencing_outer_table() {
+    use diesel::dsl::not_exists;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &conn);
+    let tess = find_user_by_name("Tess", &conn);
+
+    insert_into(posts::table)
+        .values(&vec![sean.new_post("Hello", None)])
+        .execute(&conn)
+        .unwrap();
+
+    let source = users::table
+        .select(users::name)
+        .filter(not_exists(posts::table.filter(posts::user_id.eq(users::id))));
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` WHERE NOT EXISTS (\
+                            SELECT `posts`.`id`, `posts`.`user_id`, `posts`.`title`, \
+                            `posts`.`body` FROM `posts` \
+                            WHERE `posts`.`user_id` = `users`.`id`) -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(Ok(vec![tess.name]), source.load::<String>(&conn));
+}
+
+#[test]
+fn filter_by_in_and_not_in_subquery() {
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &conn);
+    let tess = find_user_by_name("Tess", &conn);
+
+    insert_into(posts::table)
+        .values(&vec![sean.new_post("Hello", None)])
+        .execute(&conn)
+        .unwrap();
+
+    let authors = posts::table.select(posts::user_id);
+    let source = users::table.filter(users::id.eq_any(authors));
+    let mut expected_sql = "SELECT `users`.`id`, `users`.`name`, `users`.`hair_color` \
+                            FROM `users` WHERE `users`.`id` IN \
+                            (SELECT `posts`.`user_id` FROM `posts`) -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(Ok(vec![sean.clone()]), source.load(&conn));
+
+    let non_authors = users::table
+        .filter(users::id.ne_any(posts::table.select(posts::user_id)))
+        .load(&conn);
+    assert_eq!(Ok(vec![tess]), non_authors);
+
+    let boxed_authors = posts::table
+        .select(posts::user_id)
+        .into_boxed::<TestBackend>();
+    let authors = users::table
+        .filter(users::id.eq_any(boxed_authors))
+        .load(&conn);
+    assert_eq!(Ok(vec![sean]), authors);
+}
+
+#[test]
+fn filter_by_scalar_subquery() {
+    use diesel::dsl::max;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &conn);
+    let tess = find_user_by_name("Tess", &conn);
+
+    insert_into(posts::table)
+        .values(&vec![sean.new_post("Hello", None), tess.new_post("World", None)])
+        .execute(&conn)
+        .unwrap();
+
+    let latest_post_id = posts::table.select(max(posts::id)).single_value();
+    let latest_post_title = posts::table
+        .select(posts::title)
+        .filter(posts::id.nullable().eq(latest_post_id))
+        .load::<String>(&conn);
+    assert_eq!(Ok(vec!["World".to_string()]), latest_post_title);
+
+    let users_after_sean = users::table
+        .filter(
+            users::id
+                .nullable()
+                .gt(users::table.select(users::id).filter(users::name.eq("Sean")).single_value()),
+        )
+        .load(&conn);
+    assert_eq!(Ok(vec![tess]), users_after_sean);
+
+    let no_users = users::table
+        .filter(
+            users::id
+                .nullable()
+                .gt(users::table.select(users::id).filter(users::name.eq("Jim")).single_value()),
+        )
+        .load::<User>(&conn);
+    assert_eq!(Ok(Vec::new()), no_users);
+}
+
+#[test]
+fn one_predicate_can_be_reused_in_select_filter_and_order() {
+    use schema::users::dsl::*;
+
+    fn assert_copy<T: Copy>(_: &T) {}
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let is_sean = name.eq("Sean");
+    assert_copy(&is_sean);
+
+    let data = users
+        .select((name, is_sean))
+        .order(is_sean.desc())
+        .load::<(String, bool)>(&connection);
+    let expected_data = vec![("Sean".to_string(), true), ("Tess".to_string(), false)];
+    assert_eq!(Ok(expected_data), data);
+
+    let data = users
+        .select((name, is_sean))
+        .filter(is_sean)
+        .load::<(String, bool)>(&connection);
+    assert_eq!(Ok(vec![("Sean".to_string(), true)]), data);
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn filter_subselect_with_pg_any() {
@@ -447,3 +750,114 @@ fn filter_subselect_with_pg_any() {
         .load(&conn);
     assert_eq!(Ok(vec![sean]), users_with_published_posts);
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_with_pg_all_over_a_subquery() {
+    use diesel::pg::Pg;
+
+    table! {
+        readings {
+            id -> Integer,
+            value -> Integer,
+        }
+    }
+
+    table! {
+        limits {
+            id -> Integer,
+            threshold -> Integer,
+        }
+    }
+
+    let connection = connection();
+    connection
+        .execute("CREATE TABLE readings (id SERIAL PRIMARY KEY, value INTEGER NOT NULL)")
+        .unwrap();
+    connection
+        .execute("CREATE TABLE limits (id SERIAL PRIMARY KEY, threshold INTEGER NOT NULL)")
+        .unwrap();
+    connection
+        .execute("INSERT INTO readings (value) VALUES (5), (15), (25)")
+        .unwrap();
+    connection
+        .execute("INSERT INTO limits (threshold) VALUES (10), (20)")
+        .unwrap();
+
+    let thresholds = limits::table.select(limits::threshold);
+    let source = readings::table
+        .select(readings::value)
+        .filter(readings::value.gt_all_subquery(thresholds));
+    let expected_sql = "SELECT \"readings\".\"value\" FROM \"readings\" \
+                        WHERE \"readings\".\"value\" > \
+                        ALL(SELECT \"limits\".\"threshold\" FROM \"limits\") \
+                        -- binds: []";
+    assert_eq!(expected_sql, debug_query::<Pg, _>(&source).to_string());
+    assert_eq!(Ok(vec![25]), source.load::<i32>(&connection));
+
+    let above_any = readings::table
+        .select(readings::value)
+        .filter(readings::value.gt_any_subquery(thresholds))
+        .order(readings::value);
+    assert_eq!(Ok(vec![15, 25]), above_any.load::<i32>(&connection));
+
+    let at_a_limit = readings::table
+        .select(readings::value)
+        .filter(readings::value.eq_any_subquery(thresholds));
+    assert_eq!(Ok(Vec::<i32>::new()), at_a_limit.load::<i32>(&connection));
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn filter_on_generated_column() {
+    table! {
+        line_items {
+            id -> Integer,
+            quantity -> Integer,
+            unit_price -> Integer,
+            #[generated]
+            total -> Integer,
+        }
+    }
+
+    let connection = connection();
+    // Generated columns require PostgreSQL 12 or SQLite 3.31
+    let (major, minor) = if cfg!(feature = "postgres") {
+        (12, 0)
+    } else {
+        (3, 31)
+    };
+    if !database_version_is_at_least(&connection, major, minor) {
+        return;
+    }
+    let id_column = if cfg!(feature = "postgres") {
+        "id SERIAL PRIMARY KEY"
+    } else {
+        "id INTEGER PRIMARY KEY AUTOINCREMENT"
+    };
+    connection
+        .execute(&format!(
+            "CREATE TABLE line_items ({}, \
+             quantity INTEGER NOT NULL, \
+             unit_price INTEGER NOT NULL, \
+             total INTEGER GENERATED ALWAYS AS (quantity * unit_price) STORED)",
+            id_column
+        ))
+        .unwrap();
+
+    insert_into(line_items::table)
+        .values(&vec![
+            (line_items::quantity.eq(1), line_items::unit_price.eq(5)),
+            (line_items::quantity.eq(3), line_items::unit_price.eq(5)),
+            (line_items::quantity.eq(2), line_items::unit_price.eq(20)),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let large_orders = line_items::table
+        .select((line_items::quantity, line_items::total))
+        .filter(line_items::total.gt(10))
+        .order(line_items::total)
+        .load::<(i32, i32)>(&connection);
+    assert_eq!(Ok(vec![(3, 15), (2, 40)]), large_orders);
+}
@@ -173,6 +173,85 @@ fn filter_by_like() {
     );
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_trigram_similarity_respects_the_threshold() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Sean Griffin", None),
+        NewUser::new("Tess Griffin", None),
+        NewUser::new("Jim", None),
+    ];
+    insert_into(users)
+        .values(&data)
+        .execute(&connection)
+        .unwrap();
+    let similar_names = || {
+        users
+            .select(name)
+            .filter(name.trigram_similar("sean griffin"))
+            .order(id.asc())
+            .load::<String>(&connection)
+    };
+
+    connection.set_similarity_threshold(0.3).unwrap();
+    assert_eq!(
+        Ok(vec!["Sean Griffin".to_string(), "Tess Griffin".to_string()]),
+        similar_names()
+    );
+
+    connection.set_similarity_threshold(0.6).unwrap();
+    assert_eq!(Ok(vec!["Sean Griffin".to_string()]), similar_names());
+
+    assert!(connection.set_similarity_threshold(1.5).is_err());
+    assert!(connection.set_similarity_threshold(-0.1).is_err());
+    assert!(connection.set_similarity_threshold(::std::f32::NAN).is_err());
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_similar_to() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Sean Griffin", None),
+        NewUser::new("Tess Griffin", None),
+        NewUser::new("Jim", None),
+    ];
+    insert_into(users)
+        .values(&data)
+        .execute(&connection)
+        .unwrap();
+
+    assert_eq!(
+        Ok(vec!["Sean Griffin".to_string(), "Jim".to_string()]),
+        users
+            .select(name)
+            .filter(name.similar_to("(S|J)%"))
+            .order(id.asc())
+            .load::<String>(&connection)
+    );
+    assert_eq!(
+        Ok(vec!["Tess Griffin".to_string()]),
+        users
+            .select(name)
+            .filter(name.not_similar_to("(S|J)%"))
+            .order(id.asc())
+            .load::<String>(&connection)
+    );
+    // Patterns must match the whole string
+    assert_eq!(
+        Ok(vec!["Jim".to_string()]),
+        users
+            .select(name)
+            .filter(name.similar_to("(Sean|Jim)"))
+            .load::<String>(&connection)
+    );
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn filter_by_ilike() {
@@ -46,3 +46,255 @@ fn boxed_queries_have_group_by_method() {
 
     assert_eq!(expected_sql, debug_query(&source).to_string());
 }
+
+sql_aggregate_function!(median, median_t, (x: types::Integer) -> types::Nullable<types::Integer>);
+
+#[test]
+fn user_defined_aggregates_can_be_selected_from_grouped_queries() {
+    let source = users::table
+        .group_by(users::hair_color)
+        .select(median(users::id));
+    let mut expected_sql = "SELECT median(`users`.`id`) FROM `users` \
+                            GROUP BY `users`.`hair_color` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+fn grouped_queries_can_be_ordered_by_an_aggregate() {
+    use diesel::dsl::{count, count_star};
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        NewPost::new(tess.id, "Tess's first post", None),
+        NewPost::new(sean.id, "Sean's first post", None),
+        NewPost::new(sean.id, "Sean's second post", None),
+    ];
+    insert_into(posts::table)
+        .values(&new_posts)
+        .execute(&connection)
+        .unwrap();
+
+    let top_posters = posts::table
+        .group_by(posts::user_id)
+        .select(posts::user_id)
+        .order(count_star().desc())
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![sean.id, tess.id]), top_posters);
+
+    let top_poster_names = users::table
+        .inner_join(posts::table)
+        .group_by(users::id)
+        .select(users::name)
+        .order(count(posts::id).desc())
+        .limit(1)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string()]), top_poster_names);
+}
+
+#[test]
+fn aggregates_can_be_selected_alongside_grouped_columns() {
+    use diesel::dsl::{count, count_star, max, min, sum};
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let data = users
+        .select((hair_color, count(id), count_star(), min(name), max(name)))
+        .group_by(hair_color)
+        .order(hair_color)
+        .load::<(Option<String>, i64, i64, Option<String>, Option<String>)>(&connection);
+    let expected_data = vec![
+        (
+            Some("black".to_string()),
+            2,
+            2,
+            Some("Jim".to_string()),
+            Some("Sean".to_string()),
+        ),
+        (
+            Some("brown".to_string()),
+            1,
+            1,
+            Some("Tess".to_string()),
+            Some("Tess".to_string()),
+        ),
+    ];
+    assert_eq!(Ok(expected_data), data);
+
+    // Aggregates over no rows are `NULL`, except for `COUNT`
+    let no_users = users.filter(name.eq("Nobody"));
+    let empty_data = no_users
+        .select((count(id), sum(id), max(name)))
+        .get_result::<(i64, Option<i64>, Option<String>)>(&connection);
+    assert_eq!(Ok((0, None, None)), empty_data);
+}
+
+#[test]
+fn count_distinct_counts_unique_values_per_group() {
+    use diesel::dsl::{count, distinct};
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Jim", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select((hair_color, count(name), count(distinct(name))))
+        .group_by(hair_color);
+    let mut expected_sql = "SELECT `users`.`hair_color`, COUNT(`users`.`name`), \
+                            COUNT(DISTINCT `users`.`name`) FROM `users` \
+                            GROUP BY `users`.`hair_color` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let data = source
+        .order(hair_color)
+        .load::<(Option<String>, i64, i64)>(&connection);
+    let expected_data = vec![
+        (Some("black".to_string()), 3, 2),
+        (Some("brown".to_string()), 1, 1),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn group_by_accepts_a_tuple_of_columns() {
+    use diesel::dsl::count;
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Sean", Some("brown")),
+        NewUser::new("Tess", Some("brown")),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select((name, hair_color, count(id)))
+        .group_by((name, hair_color));
+    let mut expected_sql = "SELECT `users`.`name`, `users`.`hair_color`, COUNT(`users`.`id`) \
+                            FROM `users` \
+                            GROUP BY `users`.`name`, `users`.`hair_color` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let data = source
+        .order((name, hair_color))
+        .load::<(String, Option<String>, i64)>(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("black".to_string()), 2),
+        ("Sean".to_string(), Some("brown".to_string()), 1),
+        ("Tess".to_string(), Some("brown".to_string()), 1),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn having_filters_groups_by_an_aggregate() {
+    use diesel::dsl::count;
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Jim", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+    ];
+    insert_into(users)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select((hair_color, count(id)))
+        .group_by(hair_color)
+        .having(count(id).gt(1));
+    let mut expected_sql = "SELECT `users`.`hair_color`, COUNT(`users`.`id`) \
+                            FROM `users` \
+                            GROUP BY `users`.`hair_color` \
+                            HAVING COUNT(`users`.`id`) > ? \
+                            -- binds: [1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let data = source.load::<(Option<String>, i64)>(&connection);
+    assert_eq!(Ok(vec![(Some("black".to_string()), 2)]), data);
+}
+
+#[test]
+fn group_by_after_having_keeps_the_having_clause() {
+    use diesel::dsl::count;
+    use schema::users::dsl::*;
+
+    let source = users
+        .select((hair_color, count(id)))
+        .group_by(name)
+        .having(count(id).gt(1))
+        .group_by(hair_color);
+    let mut expected_sql = "SELECT `users`.`hair_color`, COUNT(`users`.`id`) \
+                            FROM `users` \
+                            GROUP BY `users`.`hair_color` \
+                            HAVING COUNT(`users`.`id`) > ? \
+                            -- binds: [1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
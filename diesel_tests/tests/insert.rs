@@ -1,3 +1,5 @@
+extern crate chrono;
+
 use super::schema::*;
 use diesel::*;
 
@@ -85,6 +87,77 @@ fn insert_records_with_custom_returning_clause() {
     assert_eq!(expected_users, inserted_users);
 }
 
+#[test]
+fn insert_tuple_of_assignments_by_value() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    insert_into(users)
+        .values((name.eq("Sean"), hair_color.eq("Black")))
+        .execute(&connection)
+        .unwrap();
+
+    let expected_data = vec![("Sean".to_string(), Some("Black".to_string()))];
+    let actual_data = users
+        .select((name, hair_color))
+        .load::<(String, Option<String>)>(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn insert_tuple_of_assignments_with_returning_and_on_conflict() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let inserted_user = insert_into(users)
+        .values((id.eq(1), name.eq("Sean"), hair_color.eq("Black")))
+        .returning((id, name, hair_color))
+        .get_result::<(i32, String, Option<String>)>(&connection);
+    assert_eq!(
+        Ok((1, "Sean".to_string(), Some("Black".to_string()))),
+        inserted_user
+    );
+
+    let updated_user = insert_into(users)
+        .values((id.eq(1), name.eq("Tess")))
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq("Tess"))
+        .returning((id, name, hair_color))
+        .get_result::<(i32, String, Option<String>)>(&connection);
+    assert_eq!(
+        Ok((1, "Tess".to_string(), Some("Black".to_string()))),
+        updated_user
+    );
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn insert_with_returning_clause_on_sqlite() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let inserted_name = insert_into(users)
+        .values(&NewUser::new("Sean", Some("Black")))
+        .returning(name)
+        .get_result::<String>(&connection);
+
+    if sqlite_supports_returning(&connection) {
+        assert_eq!(Ok("Sean".to_string()), inserted_name);
+        let deleted_user = delete(users.filter(name.eq("Sean"))).get_result::<User>(&connection);
+        assert_eq!(Ok("Black".to_string()), deleted_user.map(|u| u.hair_color.unwrap()));
+    } else {
+        let message = inserted_name.unwrap_err().to_string();
+        assert!(
+            message.contains("requires SQLite 3.35.0 or newer"),
+            "Unexpected error message: {}",
+            message
+        );
+        assert_eq!(Ok(0), users.count().get_result(&connection));
+    }
+}
+
 #[test]
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 fn batch_insert_with_defaults() {
@@ -129,6 +202,54 @@ fn batch_insert_with_defaults() {
     assert_eq!(expected_users, actual_users);
 }
 
+table! {
+    users_with_created_at {
+        id -> Integer,
+        name -> VarChar,
+        created_at -> Timestamp,
+    }
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn batch_insert_can_use_default_for_some_rows() {
+    use self::chrono::NaiveDate;
+    use self::users_with_created_at::dsl::*;
+    use schema_dsl::*;
+
+    let connection = connection();
+    create_table(
+        "users_with_created_at",
+        (
+            integer("id").primary_key().auto_increment(),
+            string("name").not_null(),
+            timestamp("created_at")
+                .not_null()
+                .default("CURRENT_TIMESTAMP"),
+        ),
+    ).execute(&connection)
+        .unwrap();
+
+    let long_ago = NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0);
+    let new_users = vec![
+        (name.eq("Sean"), Some(created_at.eq(long_ago))),
+        (name.eq("Tess"), None),
+        (name.eq("Jim"), Some(created_at.eq(long_ago))),
+        (name.eq("Ruby"), None),
+    ];
+    insert_into(users_with_created_at)
+        .values(&new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let defaulted = users_with_created_at
+        .select(name)
+        .filter(created_at.gt(long_ago))
+        .order(id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Tess".to_string(), "Ruby".to_string()]), defaulted);
+}
+
 #[test]
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 fn insert_with_defaults() {
@@ -232,6 +353,74 @@ fn insert_borrowed_content() {
     assert_eq!(expected_users, actual_users);
 }
 
+#[derive(Insertable)]
+#[table_name = "users"]
+struct BorrowedUserWithHairColor<'a> {
+    name: &'a str,
+    hair_color: Option<&'a str>,
+}
+
+#[test]
+fn insert_borrowed_optional_content() {
+    use schema::users::table as users;
+    let connection = connection();
+    let new_users: &[_] = &[
+        BorrowedUserWithHairColor {
+            name: "Sean",
+            hair_color: Some("Black"),
+        },
+        BorrowedUserWithHairColor {
+            name: "Tess",
+            hair_color: None,
+        },
+    ];
+    insert_into(users)
+        .values(new_users)
+        .execute(&connection)
+        .unwrap();
+
+    let actual_users = users.load::<User>(&connection).unwrap();
+    let expected_users = vec![
+        User::with_hair_color(actual_users[0].id, "Sean", "Black"),
+        User::new(actual_users[1].id, "Tess"),
+    ];
+
+    assert_eq!(expected_users, actual_users);
+}
+
+#[test]
+fn insert_records_from_iterator() {
+    use schema::users::table as users;
+    let connection = connection();
+    let ranked_users = vec![
+        (2, NewUser::new("Tess", None)),
+        (1, NewUser::new("Sean", Some("Black"))),
+    ];
+
+    let inserted_rows = insert_into(users)
+        .values_from_iter(ranked_users.iter().map(|&(_, ref user)| user))
+        .execute(&connection);
+    assert_eq!(Ok(2), inserted_rows);
+
+    let actual_users = users.load::<User>(&connection).unwrap();
+    let expected_users = vec![
+        User::new(actual_users[0].id, "Tess"),
+        User::with_hair_color(actual_users[1].id, "Sean", "Black"),
+    ];
+    assert_eq!(expected_users, actual_users);
+}
+
+#[test]
+fn insert_records_from_empty_iterator() {
+    let connection = connection();
+
+    let inserted_records = insert_into(users::table)
+        .values_from_iter(Vec::<NewUser>::new().iter())
+        .execute(&connection);
+
+    assert_eq!(Ok(0), inserted_records);
+}
+
 #[test]
 fn insert_empty_slice() {
     let connection = connection();
@@ -503,3 +692,98 @@ fn batch_insert_is_atomic_on_sqlite() {
 
     assert_eq!(Ok(0), users.count().get_result(&connection));
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn insert_with_unnest_binds_one_array_per_column() {
+    use schema::users::dsl::*;
+    let connection = connection();
+
+    let new_users = vec![
+        ("Sean".to_string(), Some("Black".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    let query = insert_into(users).values_unnest((name, hair_color), new_users);
+    assert_eq!(
+        "INSERT INTO \"users\" (\"name\", \"hair_color\") SELECT * FROM unnest($1, $2) \
+         -- binds: [[\"Sean\", \"Tess\"], [Some(\"Black\"), None]]",
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+    assert_eq!(Ok(2), query.execute(&connection));
+
+    let expected_data = vec![
+        ("Sean".to_string(), Some("Black".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    let actual_data = users
+        .select((name, hair_color))
+        .order(id)
+        .load(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn insert_with_unnest_and_no_rows_inserts_nothing() {
+    use schema::users::dsl::*;
+    let connection = connection();
+
+    let new_users: Vec<(String,)> = Vec::new();
+    let inserted_rows = insert_into(users)
+        .values_unnest((name,), new_users)
+        .execute(&connection);
+
+    assert_eq!(Ok(0), inserted_rows);
+    assert_eq!(Ok(0), users.count().get_result(&connection));
+}
+
+#[test]
+fn insert_from_select_copies_rows_without_loading_them() {
+    use schema::posts::dsl::{posts, title, user_id};
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(posts)
+        .values(users.select((id, name)).filter(name.eq("Sean")))
+        .into_columns((user_id, title));
+    let mut expected_sql = "INSERT INTO `posts` (`user_id`, `title`) \
+                            SELECT `users`.`id`, `users`.`name` FROM `users` \
+                            WHERE `users`.`name` = ? -- binds: [\"Sean\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let sean = find_user_by_name("Sean", &connection);
+    let expected_data = vec![(sean.id, "Sean".to_string())];
+    let actual_data = posts.select((user_id, title)).load(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+}
+
+#[test]
+fn insert_from_select_into_every_column_of_the_same_table() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+
+    insert_into(users)
+        .values(users.select((id + 10, name, hair_color)))
+        .into_columns((id, name, hair_color))
+        .execute(&connection)
+        .unwrap();
+
+    let expected_data = vec![
+        (sean.id, "Sean".to_string()),
+        (tess.id, "Tess".to_string()),
+        (sean.id + 10, "Sean".to_string()),
+        (tess.id + 10, "Tess".to_string()),
+    ];
+    let actual_data = users.select((id, name)).order(id).load(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+}
@@ -154,6 +154,32 @@ fn left_outer_joins() {
     assert_eq!(expected_data, actual_data);
 }
 
+#[test]
+fn left_outer_join_without_any_matching_rows_returns_none_for_every_row() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let source = users::table.left_outer_join(posts::table).order(users::id);
+
+    let expected_data = vec![(sean.clone(), None), (tess.clone(), None)];
+    let actual_data = source.clone().load::<(User, Option<Post>)>(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+
+    let expected_data = vec![(sean.id, None), (tess.id, None)];
+    let actual_data = source
+        .clone()
+        .select((users::id, posts::title.nullable()))
+        .load::<(i32, Option<String>)>(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+
+    let expected_data = vec![(sean, None), (tess, None)];
+    let actual_data = source
+        .select((users::all_columns, posts::all_columns.nullable()))
+        .load::<(User, Option<Post>)>(&connection);
+    assert_eq!(Ok(expected_data), actual_data);
+}
+
 #[test]
 fn columns_on_right_side_of_left_outer_joins_are_nullable() {
     let connection = connection_with_sean_and_tess_in_users_table();
@@ -412,6 +438,155 @@ fn join_with_explicit_on_clause() {
     assert_eq!(expected_data, data);
 }
 
+#[test]
+fn join_with_explicit_on_clause_not_based_on_a_foreign_key() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        NewPost::new(sean.id, "Tess", None),
+        NewPost::new(sean.id, "Hello", None),
+        NewPost::new(tess.id, "Sean", None),
+    ];
+    insert_into(posts::table)
+        .values(&new_posts)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users::table
+        .inner_join(posts::table.on(posts::title.eq(users::name)))
+        .select((users::name, posts::user_id))
+        .order(users::name);
+    let mut expected_sql = "SELECT `users`.`name`, `posts`.`user_id` FROM \
+                            (`users` INNER JOIN `posts` ON `posts`.`title` = `users`.`name`) \
+                            ORDER BY `users`.`name` -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec![("Sean".to_string(), tess.id), ("Tess".to_string(), sean.id)];
+    assert_eq!(Ok(expected_data), source.load(&connection));
+
+    let data = users::table
+        .inner_join(
+            posts::table.on(posts::user_id
+                .eq(users::id)
+                .and(posts::title.ne(users::name))),
+        )
+        .select((users::name, posts::title))
+        .order((users::name, posts::title))
+        .load::<(String, String)>(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), "Hello".to_string()),
+        ("Sean".to_string(), "Tess".to_string()),
+        ("Tess".to_string(), "Sean".to_string()),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn self_join_through_an_alias_loads_the_manager_of_each_employee() {
+    table! {
+        employees {
+            id -> Integer,
+            name -> VarChar,
+            manager_id -> Nullable<Integer>,
+        }
+    }
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE employees (id INTEGER PRIMARY KEY, \
+             name VARCHAR(255) NOT NULL, \
+             manager_id INTEGER)",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO employees (id, name, manager_id) VALUES \
+             (1, 'Alice', NULL), (2, 'Bob', 1), (3, 'Carol', 1), (4, 'Dave', 2)",
+        )
+        .unwrap();
+
+    let managers = alias!(employees as managers);
+    let source = employees::table
+        .inner_join(managers.on(
+            employees::manager_id.eq(managers.field(employees::id).nullable()),
+        ))
+        .select((employees::name, managers.field(employees::name)))
+        .order(employees::id);
+    let mut expected_sql = "SELECT `employees`.`name`, `managers`.`name` FROM \
+                            (`employees` INNER JOIN `employees` AS `managers` \
+                            ON `employees`.`manager_id` = `managers`.`id`) \
+                            ORDER BY `employees`.`id` -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let expected_data = vec![
+        ("Bob".to_string(), "Alice".to_string()),
+        ("Carol".to_string(), "Alice".to_string()),
+        ("Dave".to_string(), "Bob".to_string()),
+    ];
+    assert_eq!(Ok(expected_data), source.load(&connection));
+
+    let reports_of_alice = employees::table
+        .inner_join(managers.on(
+            employees::manager_id.eq(managers.field(employees::id).nullable()),
+        ))
+        .filter(managers.field(employees::name).eq("Alice"))
+        .select(employees::name)
+        .order(employees::name)
+        .load::<String>(&connection);
+    assert_eq!(
+        Ok(vec!["Bob".to_string(), "Carol".to_string()]),
+        reports_of_alice
+    );
+
+    let everyone = employees::table
+        .left_join(managers.on(
+            employees::manager_id.eq(managers.field(employees::id).nullable()),
+        ))
+        .select((
+            employees::name,
+            managers.field(employees::name).nullable(),
+        ))
+        .order(employees::id)
+        .load::<(String, Option<String>)>(&connection);
+    let expected_data = vec![
+        ("Alice".to_string(), None),
+        ("Bob".to_string(), Some("Alice".to_string())),
+        ("Carol".to_string(), Some("Alice".to_string())),
+        ("Dave".to_string(), Some("Bob".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), everyone);
+
+    let dave_and_manager = employees::table
+        .inner_join(managers.on(
+            employees::manager_id.eq(managers.field(employees::id).nullable()),
+        ))
+        .filter(employees::name.eq("Dave"))
+        .load::<((i32, String, Option<i32>), (i32, String, Option<i32>))>(&connection);
+    let expected_data = vec![
+        (
+            (4, "Dave".to_string(), Some(2)),
+            (2, "Bob".to_string(), Some(1)),
+        ),
+    ];
+    assert_eq!(Ok(expected_data), dave_and_manager);
+}
+
 #[test]
 fn selecting_parent_child_grandchild() {
     let (connection, test_data) = connection_with_fixture_data_for_multitable_joins();
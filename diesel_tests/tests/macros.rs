@@ -40,6 +40,64 @@ fn test_sql_function() {
     );
 }
 
+#[test]
+fn sql_functions_can_be_used_in_every_clause() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE FUNCTION my_lower(varchar) RETURNS varchar
+        AS $$ SELECT LOWER($1) $$
+        LANGUAGE SQL",
+        )
+        .unwrap();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", None),
+            NewUser::new("SEAN", None),
+            NewUser::new("Tess", None),
+            NewUser::new("Jim", None),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select((my_lower(name), dsl::count_star()))
+        .filter(my_lower(name).ne("jim"))
+        .group_by(my_lower(name))
+        .order(my_lower(name).desc());
+    let expected_sql = "SELECT my_lower(\"users\".\"name\"), COUNT(*) FROM \"users\" \
+                        WHERE my_lower(\"users\".\"name\") != $1 \
+                        GROUP BY my_lower(\"users\".\"name\") \
+                        ORDER BY my_lower(\"users\".\"name\") DESC -- binds: [\"jim\"]";
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+
+    let expected_data = vec![("tess".to_string(), 1), ("sean".to_string(), 2)];
+    assert_eq!(Ok(expected_data), source.load::<(String, i64)>(&connection));
+}
+
+no_arg_sql_function!(pg_backend_pid, types::Integer);
+
+#[test]
+fn no_arg_sql_functions_can_be_used_in_every_clause() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let source = users
+        .select(pg_backend_pid)
+        .filter(pg_backend_pid.gt(0))
+        .group_by(pg_backend_pid)
+        .order(pg_backend_pid);
+
+    let pids = source.clone().load::<i32>(&connection).unwrap();
+    assert_eq!(1, pids.len());
+    assert_eq!(Ok(pids), source.load::<i32>(&connection));
+}
+
 sql_function!(setval, setval_t, (x: VarChar, y: BigInt));
 sql_function!(currval, currval_t, (x: VarChar) -> BigInt);
 
@@ -53,3 +111,32 @@ fn sql_function_without_return_type() {
     let seq_val = select(currval("users_id_seq")).get_result::<i64>(&connection);
     assert_eq!(Ok(54), seq_val);
 }
+
+sql_function!(coalesce, coalesce_t, <ST> (x: ST, y: ST) -> ST);
+
+#[test]
+fn generic_sql_function_returns_the_argument_type() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", None),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let hair_colors = users
+        .select(coalesce(hair_color, "Unknown"))
+        .order(id)
+        .load::<Option<String>>(&connection);
+    let expected = vec![Some("black".to_string()), Some("Unknown".to_string())];
+    assert_eq!(Ok(expected), hair_colors);
+
+    let ids = users
+        .select(coalesce(id, 0))
+        .order(id)
+        .load::<i32>(&connection);
+    assert_eq!(2, ids.unwrap().len());
+}
@@ -78,3 +78,316 @@ fn order_by_descending_column() {
     let data: Vec<_> = users.order(name.desc()).load(&conn).unwrap();
     assert_eq!(expected_data, data);
 }
+
+#[test]
+#[cfg(not(feature = "mysql"))]
+fn order_by_multiple_columns_with_mixed_directions_and_nulls_last() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", None),
+        NewUser::new("Jim", Some("brown")),
+        NewUser::new("Aaron", Some("brown")),
+    ];
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    let query = users
+        .select(name)
+        .order((hair_color.desc().nulls_last(), name.asc()));
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            ORDER BY `users`.`hair_color` DESC NULLS LAST, `users`.`name` ASC \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    // SQLite only supports `NULLS FIRST` and `NULLS LAST` since 3.30.0
+    if cfg!(feature = "sqlite") && !database_version_is_at_least(&conn, 3, 30) {
+        return;
+    }
+    let names = query.load::<String>(&conn);
+    let expected_names = vec!["Aaron", "Jim", "Sean", "Tess"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(Ok(expected_names), names);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn limit_with_ties_includes_rows_tied_at_the_boundary() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("brown")),
+        NewUser::new("Ruby", Some("red")),
+    ];
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    let mut names = users
+        .select(name)
+        .order(hair_color.asc())
+        .limit_with_ties(2)
+        .load::<String>(&conn)
+        .unwrap();
+    names.sort();
+
+    assert_eq!(vec!["Jim", "Sean", "Tess"], names);
+}
+
+#[test]
+fn order_by_random_returns_every_row() {
+    use diesel::dsl::random;
+    use schema::users::dsl::*;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+
+    let mut names = users
+        .select(name)
+        .order(random())
+        .load::<String>(&conn)
+        .unwrap();
+    names.sort();
+    assert_eq!(vec!["Sean", "Tess"], names);
+
+    let sampled = users
+        .select(name)
+        .order(random())
+        .limit(1)
+        .load::<String>(&conn)
+        .unwrap();
+    assert_eq!(1, sampled.len());
+}
+
+#[test]
+fn random_returns_a_double_between_0_and_1() {
+    use diesel::dsl::random;
+
+    let conn = connection();
+    let values = (0..10)
+        .map(|_| select(random()).get_result::<f64>(&conn))
+        .collect::<QueryResult<Vec<_>>>()
+        .unwrap();
+    for value in values {
+        assert!(value >= 0.0 && value <= 1.0, "{} is out of range", value);
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn order_by_random_with_the_same_seed_returns_the_same_order() {
+    use diesel::dsl::random_seeded;
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = (0..20)
+        .map(|i| NewUser::new(&format!("User {}", i), None))
+        .collect::<Vec<_>>();
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    let query = users.select(name).order(random_seeded(0.5));
+    let first_run = query.load::<String>(&conn).unwrap();
+    let second_run = query.load::<String>(&conn).unwrap();
+    assert_eq!(20, first_run.len());
+    assert_eq!(first_run, second_run);
+
+    let other_seed = users
+        .select(name)
+        .order(random_seeded(-0.5))
+        .load::<String>(&conn)
+        .unwrap();
+    assert_ne!(first_run, other_seed);
+
+    assert_eq!(
+        "SELECT \"users\".\"name\" FROM \"users\" ORDER BY \
+         CASE WHEN (SELECT setseed($1)) IS NULL THEN NULL ELSE RANDOM() END -- binds: [0.5]",
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn nulls_last_default_applies_to_orderings_without_explicit_placement() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", None),
+        NewUser::new("Jim", Some("brown")),
+    ];
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    let names = users
+        .select(name)
+        .order(hair_color.desc())
+        .nulls_last_default()
+        .load::<String>(&conn);
+    assert_eq!(Ok(vec!["Jim".into(), "Sean".into(), "Tess".into()]), names);
+
+    let names = users
+        .select(name)
+        .order((hair_color.asc().nulls_first(), name.asc()))
+        .nulls_last_default()
+        .load::<String>(&conn);
+    assert_eq!(Ok(vec!["Tess".into(), "Sean".into(), "Jim".into()]), names);
+
+    let query = users
+        .select(name)
+        .order((hair_color.asc().nulls_first(), name.desc()))
+        .nulls_last_default();
+    assert_eq!(
+        "SELECT \"users\".\"name\" FROM \"users\" ORDER BY \"users\".\"hair_color\" ASC NULLS FIRST, \
+         \"users\".\"name\" DESC NULLS LAST -- binds: []",
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn distinct_on_uses_its_expression_as_the_leading_order_key() {
+    use schema::posts::dsl::*;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &conn);
+    let tess = find_user_by_name("Tess", &conn);
+    let data = vec![
+        NewPost::new(sean.id, "Sean's first post", None),
+        NewPost::new(tess.id, "Tess's first post", None),
+        NewPost::new(sean.id, "Sean's second post", None),
+        NewPost::new(tess.id, "Tess's second post", None),
+        NewPost::new(tess.id, "Tess's third post", None),
+    ];
+    insert_into(posts).values(&data).execute(&conn).unwrap();
+
+    let query = posts
+        .select(title)
+        .distinct_on(user_id)
+        .then_order_by(id.desc());
+    assert_eq!(
+        "SELECT DISTINCT ON (\"posts\".\"user_id\") \"posts\".\"title\" FROM \"posts\" \
+         ORDER BY \"posts\".\"user_id\", \"posts\".\"id\" DESC -- binds: []",
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+
+    let latest_titles = query.load::<String>(&conn);
+    let expected_titles = vec![
+        "Sean's second post".to_string(),
+        "Tess's third post".to_string(),
+    ];
+    assert_eq!(Ok(expected_titles), latest_titles);
+}
+
+#[test]
+fn then_order_by_appends_to_the_existing_order_clause() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    let names = users
+        .select(name)
+        .order(hair_color.desc())
+        .then_order_by(name.asc())
+        .load::<String>(&conn);
+    let expected_names = vec!["Tess".to_string(), "Jim".to_string(), "Sean".to_string()];
+    assert_eq!(Ok(expected_names), names);
+
+    let names = users
+        .select(name)
+        .then_order_by(name.desc())
+        .load::<String>(&conn);
+    let expected_names = vec!["Tess".to_string(), "Sean".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected_names), names);
+}
+
+#[test]
+fn order_by_columns_from_both_sides_of_a_join_qualifies_each_column() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection
+        .execute(
+            "INSERT INTO posts (id, user_id, title) VALUES
+        (1, 1, 'Hello'),
+        (2, 2, 'World'),
+        (3, 1, 'Again')
+    ",
+        )
+        .unwrap();
+
+    let query = posts::table
+        .inner_join(users::table)
+        .select((users::name, posts::title))
+        .order((users::id.desc(), posts::id.asc()));
+
+    let mut expected_order_by = "ORDER BY `users`.`id` DESC, `posts`.`id` ASC -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_order_by = expected_order_by.replace('`', "\"");
+    }
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert!(
+        sql.ends_with(&expected_order_by),
+        "Expected `{}` to end with `{}`",
+        sql,
+        expected_order_by
+    );
+
+    let expected_data = vec![
+        ("Tess".to_string(), "World".to_string()),
+        ("Sean".to_string(), "Hello".to_string()),
+        ("Sean".to_string(), "Again".to_string()),
+    ];
+    assert_eq!(Ok(expected_data), query.load(&connection));
+}
+
+#[test]
+fn order_by_custom_order_of_values() {
+    use diesel::dsl::custom_order;
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("red")),
+        NewUser::new("Ruby", None),
+    ];
+    insert_into(users).values(&data).execute(&conn).unwrap();
+
+    // Unlisted values, including `NULL`, sort after the listed ones
+    let names = users
+        .select(name)
+        .order((custom_order(hair_color, &["red", "brown"]), id))
+        .load::<String>(&conn);
+    let expected_names = vec![
+        "Jim".to_string(),
+        "Tess".to_string(),
+        "Sean".to_string(),
+        "Ruby".to_string(),
+    ];
+    assert_eq!(Ok(expected_names), names);
+
+    let names = users
+        .select(name)
+        .order((custom_order(hair_color, Vec::<String>::new()), id))
+        .load::<String>(&conn);
+    let expected_names = vec![
+        "Sean".to_string(),
+        "Tess".to_string(),
+        "Jim".to_string(),
+        "Ruby".to_string(),
+    ];
+    assert_eq!(Ok(expected_names), names);
+}
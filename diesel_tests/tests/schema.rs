@@ -294,6 +294,35 @@ fn ensure_primary_key_seq_greater_than(x: i64, connection: &TestConnection) {
     }
 }
 
+/// Returns whether the version of the database server, or of the linked
+/// SQLite library, is at least `major.minor`. Used to skip tests of features
+/// which the database versions we run CI against don't support.
+pub fn database_version_is_at_least(connection: &TestConnection, major: u32, minor: u32) -> bool {
+    use diesel::dsl::sql;
+    use diesel::types::Text;
+
+    let version_function = if cfg!(feature = "postgres") {
+        "current_setting('server_version')"
+    } else if cfg!(feature = "sqlite") {
+        "sqlite_version()"
+    } else {
+        "VERSION()"
+    };
+    let version = select(sql::<Text>(version_function))
+        .get_result::<String>(connection)
+        .unwrap();
+    let mut parts = version
+        .split(|c: char| !c.is_digit(10))
+        .map(|part| part.parse::<u32>().unwrap_or(0));
+    let actual_version = (parts.next().unwrap_or(0), parts.next().unwrap_or(0));
+    actual_version >= (major, minor)
+}
+
+#[cfg(feature = "sqlite")]
+pub fn sqlite_supports_returning(connection: &TestConnection) -> bool {
+    database_version_is_at_least(connection, 3, 35)
+}
+
 pub fn find_user_by_name(name: &str, connection: &TestConnection) -> User {
     users::table
         .filter(users::name.eq(name))
@@ -184,6 +184,133 @@ fn selection_using_subselect() {
     assert_eq!(vec!["Hello".to_string()], data);
 }
 
+#[test]
+fn single_scalar_values_can_be_loaded_without_a_tuple() {
+    use diesel::dsl::count_star;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let names = users.select(name).order(name).load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), names);
+
+    let count = users.select(count_star()).first::<i64>(&connection);
+    assert_eq!(Ok(2), count);
+    let count = users.count().get_result::<i64>(&connection);
+    assert_eq!(Ok(2), count);
+
+    let first_name = users.order(id).select(name).first::<String>(&connection);
+    assert_eq!(Ok("Sean".to_string()), first_name);
+
+    let colors = users.select(hair_color).load::<Option<String>>(&connection);
+    assert_eq!(Ok(vec![None, None]), colors);
+
+    let distinct_colors = users
+        .select(hair_color)
+        .distinct()
+        .load::<Option<String>>(&connection);
+    assert_eq!(Ok(vec![None]), distinct_colors);
+
+    let one = select(dsl::sql::<types::Integer>("1")).get_result::<i32>(&connection);
+    assert_eq!(Ok(1), one);
+}
+
+#[test]
+fn single_scalar_values_can_be_loaded_from_boxed_queries() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let names = users
+        .select(name)
+        .order(name)
+        .into_boxed()
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), names);
+
+    let count = users
+        .select(dsl::count_star())
+        .into_boxed()
+        .first::<i64>(&connection);
+    assert_eq!(Ok(2), count);
+}
+
+#[test]
+fn single_scalar_values_can_be_loaded_from_joins() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let new_posts = vec![
+        NewPost::new(1, "Post One", None),
+        NewPost::new(2, "Post Two", None),
+    ];
+    insert_into(posts::table)
+        .values(&new_posts)
+        .execute(&connection)
+        .unwrap();
+
+    let titles = users::table
+        .inner_join(posts::table)
+        .select(posts::title)
+        .order(posts::id)
+        .load::<String>(&connection);
+    assert_eq!(
+        Ok(vec!["Post One".to_string(), "Post Two".to_string()]),
+        titles
+    );
+
+    let names = users::table
+        .left_outer_join(posts::table)
+        .select(users::name)
+        .order(users::id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), names);
+}
+
+#[test]
+fn distinct_can_be_combined_with_aggregates_and_order() {
+    use diesel::dsl::{count, count_star};
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert_into(users)
+        .values(&data)
+        .execute(&connection)
+        .unwrap();
+
+    let colors = users
+        .select(hair_color)
+        .distinct()
+        .order(hair_color)
+        .load::<Option<String>>(&connection);
+    let expected_colors = vec![Some("black".to_string()), Some("brown".to_string())];
+    assert_eq!(Ok(expected_colors), colors);
+
+    let source = users.select(count(hair_color)).distinct();
+    let mut expected_sql = "SELECT DISTINCT COUNT(`users`.`hair_color`) FROM `users` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(Ok(3), source.get_result::<i64>(&connection));
+
+    let counts_per_color = users
+        .group_by(hair_color)
+        .select(count_star())
+        .distinct()
+        .order(count_star())
+        .load::<i64>(&connection);
+    assert_eq!(Ok(vec![1, 2]), counts_per_color);
+}
+
 table! {
     users_select_for_update {
         id -> Integer,
@@ -265,6 +392,82 @@ fn select_for_update_locks_selected_rows() {
     assert_eq!("Sean", next_selected_name);
 }
 
+table! {
+    jobs_skip_locked {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+#[cfg(not(feature = "sqlite"))]
+#[test]
+fn skip_locked_and_no_wait_do_not_wait_for_locked_rows() {
+    use self::jobs_skip_locked::dsl::*;
+
+    let conn_1 = connection_without_transaction();
+    // MySQL only supports `FOR SHARE`, `SKIP LOCKED` and `NOWAIT` since 8.0
+    if cfg!(feature = "mysql") && !database_version_is_at_least(&conn_1, 8, 0) {
+        return;
+    }
+    conn_1
+        .execute("DROP TABLE IF EXISTS jobs_skip_locked")
+        .unwrap();
+    create_table(
+        "jobs_skip_locked",
+        (
+            integer("id").primary_key().auto_increment(),
+            string("name").not_null(),
+        ),
+    ).execute(&conn_1)
+        .unwrap();
+    conn_1
+        .execute("INSERT INTO jobs_skip_locked (name) VALUES ('first'), ('second')")
+        .unwrap();
+    conn_1.begin_test_transaction().unwrap();
+
+    let next_job = jobs_skip_locked
+        .select(name)
+        .order(id)
+        .limit(1)
+        .for_update()
+        .skip_locked();
+    assert_eq!(Ok("first".to_string()), next_job.get_result(&conn_1));
+
+    let conn_2 = connection();
+    assert_eq!(Ok("second".to_string()), next_job.get_result(&conn_2));
+
+    let first_job = jobs_skip_locked
+        .select(name)
+        .filter(name.eq("first"))
+        .for_share()
+        .no_wait()
+        .load::<String>(&conn_2);
+    assert!(first_job.is_err());
+}
+
+#[cfg(not(feature = "sqlite"))]
+#[test]
+fn locking_clauses_are_rendered_at_the_end_of_the_statement() {
+    use schema::users::dsl::*;
+
+    let query = users.select(id).limit(1).for_update().skip_locked();
+    let mut expected_sql = "SELECT `users`.`id` FROM `users` LIMIT ? FOR UPDATE SKIP LOCKED \
+                            -- binds: [1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    let query = users.select(id).for_share().no_wait();
+    let mut expected_sql = "SELECT `users`.`id` FROM `users` FOR SHARE NOWAIT -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+}
+
 #[test]
 fn select_can_be_called_on_query_that_is_valid_subselect_but_invalid_query() {
     let connection = connection_with_sean_and_tess_in_users_table();
@@ -287,3 +490,231 @@ fn select_can_be_called_on_query_that_is_valid_subselect_but_invalid_query() {
 
     assert_eq!(Ok(vec![tess]), users_with_post_using_name_as_title);
 }
+
+#[test]
+fn execute_query_returns_the_row_count_without_deserializing_rows() {
+    use diesel::dsl::sql;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    // `NULL` can't be loaded into an `i32`, so this query fails if the rows
+    // are deserialized.
+    let query = users.select(sql::<types::Integer>("NULL"));
+    assert!(query.load::<i32>(&connection).is_err());
+
+    assert_eq!(Ok(2), connection.execute_query(query));
+    assert_eq!(
+        Ok(1),
+        connection.execute_query(users.filter(name.eq("Sean")))
+    );
+    assert_eq!(
+        Ok(0),
+        connection.execute_query(users.filter(name.eq("Jim")))
+    );
+}
+
+#[test]
+fn selecting_correlated_aggregate_subquery() {
+    use diesel::dsl::coalesce;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    insert_into(posts::table)
+        .values(&vec![
+            sean.new_post("Hello", None),
+            sean.new_post("World", None),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let post_count = posts::table
+        .filter(posts::user_id.eq(users::id))
+        .count()
+        .single_value();
+    let post_counts = users::table
+        .select((users::id, coalesce(post_count, 0)))
+        .order(users::id)
+        .load::<(i32, i64)>(&connection);
+
+    let expected_data = vec![(1, 2), (2, 0)];
+    assert_eq!(Ok(expected_data), post_counts);
+}
+
+#[test]
+fn load_grouped_by_groups_rows_by_key_in_query_order() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+        NewUser::new("Ruby", None),
+        NewUser::new("Pascal", Some("brown")),
+    ];
+    insert_into(users).values(&data).execute(&connection).unwrap();
+
+    let groups = users
+        .select((name, hair_color))
+        .order(name.desc())
+        .load_grouped_by(
+            |&(_, ref color): &(String, Option<String>)| color.clone(),
+            &connection,
+        )
+        .unwrap();
+    let names_in_group = |color: Option<&str>| {
+        groups[&color.map(String::from)]
+            .iter()
+            .map(|&(ref user_name, _)| user_name.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let expected_keys = vec![
+        None,
+        Some("black".to_string()),
+        Some("brown".to_string()),
+    ];
+    assert_eq!(expected_keys, groups.keys().cloned().collect::<Vec<_>>());
+    assert_eq!(vec!["Ruby"], names_in_group(None));
+    assert_eq!(vec!["Sean", "Jim"], names_in_group(Some("black")));
+    assert_eq!(vec!["Tess", "Pascal"], names_in_group(Some("brown")));
+}
+
+#[test]
+fn load_into_collects_rows_into_other_collections() {
+    use std::collections::{BTreeSet, HashSet};
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert_into(users).values(&data).execute(&connection).unwrap();
+
+    let colors: HashSet<Option<String>> = users
+        .select(hair_color)
+        .load_into(&connection)
+        .unwrap();
+    let expected_colors = vec![Some("black".to_string()), Some("brown".to_string())]
+        .into_iter()
+        .collect();
+    assert_eq!(expected_colors, colors);
+
+    let names = users
+        .select(name)
+        .load_into::<BTreeSet<String>, _>(&connection)
+        .unwrap();
+    let expected_names = vec!["Jim", "Sean", "Tess"];
+    assert_eq!(expected_names, names.into_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn load_map_transforms_each_row_while_loading() {
+    use schema::users::dsl::*;
+
+    #[derive(Debug, PartialEq)]
+    struct DisplayName(String);
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let display_names = users.order(name.desc()).load_map(&connection, |user: User| {
+        DisplayName(format!("{} ({})", user.name, user.id))
+    });
+
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let expected = vec![
+        DisplayName(format!("Tess ({})", tess.id)),
+        DisplayName(format!("Sean ({})", sean.id)),
+    ];
+    assert_eq!(Ok(expected), display_names);
+
+    let mut rows_seen = 0;
+    let lengths = users.select(name).load_map(&connection, |n: String| {
+        rows_seen += 1;
+        n.len()
+    });
+    assert_eq!(Ok(vec![4, 4]), lengths);
+    assert_eq!(2, rows_seen);
+}
+
+#[test]
+fn limit_and_offset_are_sent_as_bind_parameters() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let source = users.select(name).order(id).limit(1).offset(1);
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            ORDER BY `users`.`id` LIMIT ? OFFSET ? \
+                            -- binds: [1, 1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replacen('?', "$1", 1)
+            .replacen('?', "$2", 1);
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(Ok(vec!["Tess".to_string()]), source.load(&connection));
+}
+
+#[test]
+fn calling_limit_twice_replaces_the_first_limit() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let source = users.select(name).order(id).limit(1).limit(2);
+    let sql = debug_query::<TestBackend, _>(&source).to_string();
+    assert_eq!(1, sql.matches("LIMIT").count());
+    assert_eq!(
+        Ok(vec!["Sean".to_string(), "Tess".to_string()]),
+        source.load(&connection)
+    );
+}
+
+#[test]
+fn offset_can_be_used_without_limit() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+
+    let source = users.select(name).order(id).offset(1);
+    let expected_limit = if cfg!(feature = "postgres") {
+        ""
+    } else if cfg!(feature = "sqlite") {
+        " LIMIT -1"
+    } else {
+        " LIMIT 18446744073709551615"
+    };
+    let mut expected_sql = format!(
+        "SELECT `users`.`name` FROM `users` ORDER BY `users`.`id`{} OFFSET ? -- binds: [1]",
+        expected_limit
+    );
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec!["Tess".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected_data.clone()), source.load(&connection));
+
+    let boxed_source = users.select(name).order(id).into_boxed().offset(1);
+    assert_eq!(Ok(expected_data), boxed_source.load(&connection));
+}
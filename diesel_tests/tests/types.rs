@@ -27,6 +27,14 @@ table! {
     }
 }
 
+#[cfg(feature = "postgres")]
+table! {
+    has_fixed_length_codes {
+        id -> Integer,
+        code -> Bpchar,
+    }
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn errors_during_deserialization_do_not_panic() {
@@ -90,6 +98,36 @@ fn errors_during_deserialization_do_not_panic() {
     }
 }
 
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn filter_timestamps_by_half_open_day_range() {
+    use self::chrono::NaiveDate;
+    use self::has_timestamps::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("CREATE TABLE has_timestamps (id INTEGER PRIMARY KEY, ts TIMESTAMP NOT NULL)")
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (id, ts) VALUES \
+             (1, '2017-08-30 23:59:59'), (2, '2017-08-31 00:00:00'), \
+             (3, '2017-08-31 23:59:59'), (4, '2017-09-01 00:00:00')",
+        )
+        .unwrap();
+
+    let day = NaiveDate::from_ymd(2017, 8, 31);
+    let start = day.and_hms(0, 0, 0);
+    let end = day.succ().and_hms(0, 0, 0);
+
+    let on_day = has_timestamps
+        .select(id)
+        .filter(ts.in_range(start..end))
+        .order(id)
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![2, 3]), on_day);
+}
+
 #[test]
 #[cfg(feature = "sqlite")]
 fn test_chrono_types_sqlite() {
@@ -527,6 +565,61 @@ fn to_sql_array() {
     ));
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn pg_bpchar_round_trip() {
+    use diesel::pg::data_types::PgTrimmedChar;
+    use self::has_fixed_length_codes::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE has_fixed_length_codes (
+        id SERIAL PRIMARY KEY,
+        code CHAR(5) NOT NULL
+    )",
+        )
+        .unwrap();
+    insert_into(has_fixed_length_codes)
+        .values(&vec![code.eq("ab"), code.eq("abcde")])
+        .execute(&connection)
+        .unwrap();
+
+    let padded = has_fixed_length_codes
+        .select(code)
+        .order(id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["ab   ".to_string(), "abcde".to_string()]), padded);
+
+    let trimmed = has_fixed_length_codes
+        .select(code)
+        .order(id)
+        .load::<PgTrimmedChar>(&connection);
+    let expected = vec![
+        PgTrimmedChar("ab".to_string()),
+        PgTrimmedChar("abcde".to_string()),
+    ];
+    assert_eq!(Ok(expected), trimmed);
+
+    // Trailing spaces are not significant when comparing
+    let found = has_fixed_length_codes
+        .select(id)
+        .filter(code.eq("ab"))
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![1]), found);
+
+    let new_code = String::from("xyz");
+    update(has_fixed_length_codes.filter(code.eq(String::from("abcde"))))
+        .set(code.eq(&new_code))
+        .execute(&connection)
+        .unwrap();
+    let updated = has_fixed_length_codes
+        .select(code)
+        .filter(id.eq(2))
+        .first::<String>(&connection);
+    assert_eq!(Ok("xyz  ".to_string()), updated);
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn pg_array_containing_null() {
@@ -911,6 +1004,44 @@ fn pg_v6address_to_sql_v6address() {
 }
 
 
+#[test]
+#[cfg(feature = "postgres")]
+fn pg_network_address_from_sql_as_ipaddr_or_ipnetwork() {
+    extern crate ipnetwork;
+    use std::net::IpAddr;
+    use std::str::FromStr;
+
+    let query = "'192.168.1.5/24'::inet";
+    let expected_address = IpAddr::from_str("192.168.1.5").unwrap();
+    assert_eq!(expected_address, query_single_value::<Inet, IpAddr>(query));
+    let expected_network =
+        ipnetwork::IpNetwork::V4(ipnetwork::Ipv4Network::from_str("192.168.1.5/24").unwrap());
+    assert_eq!(
+        expected_network,
+        query_single_value::<Inet, ipnetwork::IpNetwork>(query)
+    );
+
+    let query = "'2001:4f8:3:ba::/64'::cidr";
+    let expected_address = IpAddr::from_str("2001:4f8:3:ba::").unwrap();
+    assert_eq!(expected_address, query_single_value::<Cidr, IpAddr>(query));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn pg_ipaddr_to_sql_is_a_single_host() {
+    use std::net::IpAddr;
+    use std::str::FromStr;
+
+    let value = IpAddr::from_str("192.168.1.5").unwrap();
+    assert!(query_to_sql_equality::<Inet, IpAddr>("'192.168.1.5/32'::inet", value));
+    assert!(query_to_sql_equality::<Cidr, IpAddr>("'192.168.1.5/32'::cidr", value));
+    assert!(!query_to_sql_equality::<Inet, IpAddr>("'192.168.1.5/24'::inet", value));
+
+    let value = IpAddr::from_str("2001:4f8:3:ba::1").unwrap();
+    assert!(query_to_sql_equality::<Inet, IpAddr>("'2001:4f8:3:ba::1/128'::inet", value));
+    assert!(query_to_sql_equality::<Cidr, IpAddr>("'2001:4f8:3:ba::1/128'::cidr", value));
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn pg_json_from_sql() {
@@ -112,6 +112,52 @@ fn update_with_custom_returning_clause() {
     assert_eq!(Ok(expected_result), user);
 }
 
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn update_returning_can_be_loaded_for_every_updated_row() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let updated_names = update(users)
+        .set(hair_color.eq("black"))
+        .returning(name)
+        .load::<String>(&connection)
+        .map(|mut names| {
+            names.sort();
+            names
+        });
+
+    let expected_names = vec!["Sean".to_string(), "Tess".to_string()];
+    assert_eq!(Ok(expected_names), updated_names);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn update_returning_on_sqlite() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let updated_names = update(users.filter(name.eq("Sean")))
+        .set(hair_color.eq("black"))
+        .returning(name)
+        .load::<String>(&connection);
+
+    if sqlite_supports_returning(&connection) {
+        assert_eq!(Ok(vec!["Sean".to_string()]), updated_names);
+        let sean = find_user_by_name("Sean", &connection);
+        assert_eq!(Some("black".to_string()), sean.hair_color);
+    } else {
+        let message = updated_names.unwrap_err().to_string();
+        assert!(
+            message.contains("requires SQLite 3.35.0 or newer"),
+            "Unexpected error message: {}",
+            message
+        );
+        let sean = find_user_by_name("Sean", &connection);
+        assert_eq!(None, sean.hair_color);
+    }
+}
+
 #[test]
 fn update_with_struct_as_changes() {
     use schema::users::dsl::*;
@@ -348,6 +394,356 @@ fn upsert_with_no_changes_executes_do_nothing() {
     assert_eq!(Ok(0), result);
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_do_update_with_tuple_of_assignments() {
+    use diesel::pg::Pg;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::new(1, "Sean"))
+        .on_conflict(id)
+        .do_update()
+        .set((name.eq("Jim"), hair_color.eq("brown")));
+    let expected_sql = "INSERT INTO \"users\" (\"id\", \"name\", \"hair_color\") \
+                        VALUES ($1, $2, DEFAULT) ON CONFLICT (\"id\") \
+                        DO UPDATE SET \"name\" = $3, \"hair_color\" = $4 \
+                        -- binds: [1, \"Sean\", \"Jim\", \"brown\"]";
+    assert_eq!(expected_sql, debug_query::<Pg, _>(&query).to_string());
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let new_users = [User::new(2, "Tess"), User::new(3, "Ruby")];
+    let inserted_rows = insert_into(users)
+        .values(&new_users[..])
+        .on_conflict(id)
+        .do_update()
+        .set((name.eq("Updated"), hair_color.eq("red")))
+        .execute(&connection);
+    assert_eq!(Ok(2), inserted_rows);
+
+    let data = users.select((name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        ("Jim".to_string(), Some("brown".to_string())),
+        ("Updated".to_string(), Some("red".to_string())),
+        ("Ruby".to_string(), None),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_with_excluded_values_of_several_types() {
+    use diesel::pg::Pg;
+    use diesel::pg::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let new_users = vec![
+        User::with_hair_color(1, "Jim", "black"),
+        User::with_hair_color(3, "Ruby", "red"),
+    ];
+    let query = insert_into(users)
+        .values(&new_users)
+        .on_conflict(id)
+        .do_update()
+        .set((name.eq(excluded(name)), hair_color.eq(excluded(hair_color))));
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(
+        sql.contains(
+            "DO UPDATE SET \"name\" = excluded.\"name\", \
+             \"hair_color\" = excluded.\"hair_color\""
+        ),
+        "{}",
+        sql
+    );
+    assert_eq!(Ok(2), query.execute(&connection));
+
+    let data = users.select((id, name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        (1, "Jim".to_string(), Some("black".to_string())),
+        (2, "Tess".to_string(), None),
+        (3, "Ruby".to_string(), Some("red".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_with_predicate_on_conflict_target_uses_partial_index() {
+    use diesel::pg::Pg;
+    use diesel::pg::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    // This index needs to happen before the insert or we'll get a deadlock
+    // with any transactions that are trying to get the row lock from insert
+    connection
+        .execute("CREATE UNIQUE INDEX ON users (name) WHERE hair_color IS NOT NULL")
+        .unwrap();
+    insert_into(users)
+        .values(&NewUser::new("Sean", Some("black")))
+        .execute(&connection)
+        .unwrap();
+
+    let query = insert_into(users)
+        .values(&NewUser::new("Sean", Some("brown")))
+        .on_conflict(name)
+        .filter_target(hair_color.is_not_null())
+        .do_update()
+        .set(hair_color.eq(excluded(hair_color)));
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(
+        sql.contains(
+            "ON CONFLICT (\"name\") WHERE \"users\".\"hair_color\" IS NOT NULL \
+             DO UPDATE SET"
+        ),
+        "{}",
+        sql
+    );
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let data = users.select((name, hair_color)).load(&connection);
+    let expected_data = vec![("Sean".to_string(), Some("brown".to_string()))];
+    assert_eq!(Ok(expected_data), data);
+
+    // Without the predicate, the partial index can't be used as the target
+    let without_predicate = insert_into(users)
+        .values(&NewUser::new("Sean", Some("red")))
+        .on_conflict(name)
+        .do_nothing()
+        .execute(&connection);
+    assert!(without_predicate.is_err());
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_do_update_with_filter_only_updates_matching_rows() {
+    use diesel::pg::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    update(users.find(2))
+        .set(hair_color.eq("black"))
+        .execute(&connection)
+        .unwrap();
+
+    let new_users = vec![
+        User::with_hair_color(1, "Sean", "brown"),
+        User::with_hair_color(2, "Tess", "brown"),
+    ];
+    let updated_rows = insert_into(users)
+        .values(&new_users)
+        .on_conflict(id)
+        .do_update()
+        .set(hair_color.eq(excluded(hair_color)))
+        .filter(hair_color.is_null())
+        .execute(&connection);
+    assert_eq!(Ok(1), updated_rows);
+
+    let data = users.select((id, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        (1, Some("brown".to_string())),
+        (2, Some("black".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_do_update_filter_is_placed_after_set_when_target_is_filtered() {
+    use diesel::pg::Pg;
+    use diesel::pg::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("CREATE UNIQUE INDEX ON users (name) WHERE hair_color IS NOT NULL")
+        .unwrap();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", Some("brown")),
+        ])
+        .execute(&connection)
+        .unwrap();
+
+    let query = insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("red")),
+            NewUser::new("Tess", Some("red")),
+        ])
+        .on_conflict(name)
+        .filter_target(hair_color.is_not_null())
+        .do_update()
+        .set(hair_color.eq(excluded(hair_color)))
+        .filter(hair_color.ne("brown"))
+        .filter(excluded(hair_color).is_not_null());
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(
+        sql.contains(
+            "ON CONFLICT (\"name\") WHERE \"users\".\"hair_color\" IS NOT NULL \
+             DO UPDATE SET \"hair_color\" = excluded.\"hair_color\" \
+             WHERE \"users\".\"hair_color\" != $5 \
+             AND excluded.\"hair_color\" IS NOT NULL"
+        ),
+        "{}",
+        sql
+    );
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let data = users.select((name, hair_color)).order(name).load(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("red".to_string())),
+        ("Tess".to_string(), Some("brown".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_with_returning_clause() {
+    use diesel::result::Error::NotFound;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let updated_row = insert_into(users)
+        .values(&User::new(1, "Jim"))
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq("Jim"))
+        .returning((id, name))
+        .get_result(&connection);
+    assert_eq!(Ok((1, "Jim".to_string())), updated_row);
+
+    let new_users = [User::new(2, "Ruby"), User::new(3, "Bob")];
+    let affected_rows = insert_into(users)
+        .values(&new_users[..])
+        .returning((id, name))
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq("Ruby"))
+        .get_results(&connection);
+    let expected_rows = vec![(2, "Ruby".to_string()), (3, "Bob".to_string())];
+    assert_eq!(Ok(expected_rows), affected_rows);
+
+    let skipped_row = insert_into(users)
+        .values(&User::new(1, "Sean"))
+        .on_conflict(id)
+        .do_nothing()
+        .returning(id)
+        .get_result::<i32>(&connection);
+    assert_eq!(Err(NotFound), skipped_row);
+
+    let skipped_rows = insert_into(users)
+        .values(&new_users[..])
+        .on_conflict_do_nothing()
+        .returning(id)
+        .get_results::<i32>(&connection);
+    assert_eq!(Ok(Vec::new()), skipped_rows);
+
+    let inserted_user = insert_into(users)
+        .values(&User::new(4, "Tess"))
+        .on_conflict_do_nothing()
+        .get_result(&connection);
+    assert_eq!(Ok(User::new(4, "Tess")), inserted_user);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_vec_of_records_in_a_single_statement() {
+    use diesel::pg::Pg;
+    use diesel::pg::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    update(users)
+        .set(hair_color.eq("black"))
+        .execute(&connection)
+        .unwrap();
+
+    let new_users = vec![
+        User::new(3, "Jim"),
+        User::new(2, "Ruby"),
+        User::new(4, "Bob"),
+    ];
+    let query = insert_into(users)
+        .values(&new_users)
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq(excluded(name)));
+    let expected_sql = "INSERT INTO \"users\" (\"id\", \"name\", \"hair_color\") \
+                        VALUES ($1, $2, DEFAULT), ($3, $4, DEFAULT), ($5, $6, DEFAULT) \
+                        ON CONFLICT (\"id\") DO UPDATE SET \"name\" = excluded.\"name\" \
+                        -- binds: [3, \"Jim\", 2, \"Ruby\", 4, \"Bob\"]";
+    assert_eq!(expected_sql, debug_query::<Pg, _>(&query).to_string());
+    assert_eq!(Ok(3), query.execute(&connection));
+
+    let data = users.order(id).load(&connection);
+    let expected_data = vec![
+        User::with_hair_color(1, "Sean", "black"),
+        User::with_hair_color(2, "Ruby", "black"),
+        User::new(3, "Jim"),
+        User::new(4, "Bob"),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_on_constraint_quotes_the_constraint_name() {
+    use diesel::pg::Pg;
+    use diesel::pg::upsert::*;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection
+        .execute("ALTER TABLE users ADD CONSTRAINT \"Users_Name\" UNIQUE (name)")
+        .unwrap();
+
+    let query = insert_into(users)
+        .values(&User::new(3, "Sean"))
+        .on_conflict(on_constraint("Users_Name"))
+        .do_update()
+        .set(hair_color.eq("black"));
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(
+        sql.contains("ON CONFLICT ON CONSTRAINT \"Users_Name\" DO UPDATE"),
+        "{}",
+        sql
+    );
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let query = insert_into(users)
+        .values(&User::new(3, "Jim"))
+        .on_conflict(on_constraint("odd \"name\""))
+        .do_nothing();
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(
+        sql.contains("ON CONFLICT ON CONSTRAINT \"odd \"\"name\"\"\" DO NOTHING"),
+        "{}",
+        sql
+    );
+
+    let data = users.select((name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("black".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    assert_eq!(Ok(expected_data), data);
+
+    // The name is case sensitive, so a lowercase name doesn't match
+    let lowercase_name = insert_into(users)
+        .values(&User::new(3, "Sean"))
+        .on_conflict(on_constraint("users_name"))
+        .do_nothing()
+        .execute(&connection);
+    assert!(lowercase_name.is_err());
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn upsert_with_sql_literal_for_target() {
@@ -385,6 +781,298 @@ fn upsert_with_sql_literal_for_target() {
     assert_eq!(Ok(expected_data), data);
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_using_primary_key_as_conflict_target() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let new_sean = User::with_hair_color(sean.id, "Sean", "black");
+
+    let query = insert_into(users)
+        .values(&new_sean)
+        .on_conflict_primary_key_do_update()
+        .set(hair_color.eq("black"));
+    assert_eq!(Ok(1), query.execute(&connection));
+
+    let data = users.select((name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("black".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_using_composite_primary_key_as_conflict_target() {
+    use diesel::pg::upsert::excluded;
+    use schema::followings::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    disable_foreign_keys(&connection);
+    let following = Following {
+        user_id: 1,
+        post_id: 1,
+        email_notifications: false,
+    };
+    insert_into(followings)
+        .values(&following)
+        .execute(&connection)
+        .unwrap();
+
+    let changed_following = Following {
+        email_notifications: true,
+        ..following
+    };
+    let other_following = Following {
+        post_id: 2,
+        ..following
+    };
+    insert_into(followings)
+        .values(&vec![changed_following, other_following])
+        .on_conflict_primary_key_do_update()
+        .set(email_notifications.eq(excluded(email_notifications)))
+        .execute(&connection)
+        .unwrap();
+
+    let data = followings.order((user_id, post_id)).load(&connection);
+    assert_eq!(Ok(vec![changed_following, other_following]), data);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn upsert_do_nothing_without_target_uses_insert_or_ignore_on_sqlite() {
+    use diesel::sqlite::Sqlite;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::new(1, "Jim"))
+        .on_conflict_do_nothing();
+    let expected_sql = "INSERT OR IGNORE INTO `users` (`id`, `name`) VALUES (?, ?) \
+                        -- binds: [1, \"Jim\"]";
+    assert_eq!(expected_sql, debug_query::<Sqlite, _>(&query).to_string());
+    assert_eq!(Ok(0), query.execute(&connection));
+
+    let inserted_rows = insert_into(users)
+        .values(&User::new(3, "Jim"))
+        .on_conflict_do_nothing()
+        .execute(&connection);
+    assert_eq!(Ok(1), inserted_rows);
+
+    let data = users.select(name).order(id).load::<String>(&connection);
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn upsert_with_conflict_target_uses_on_conflict_on_sqlite() {
+    use diesel::query_builder::upsert::excluded;
+    use diesel::sqlite::Sqlite;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::new(1, "Jim"))
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq(excluded(name)));
+    let expected_sql = "INSERT INTO `users` (`id`, `name`) VALUES (?, ?) \
+                        ON CONFLICT (`id`) DO UPDATE SET `name` = excluded.`name` \
+                        -- binds: [1, \"Jim\"]";
+    assert_eq!(expected_sql, debug_query::<Sqlite, _>(&query).to_string());
+
+    // `ON CONFLICT` with a target was added in SQLite 3.24
+    if !database_version_is_at_least(&connection, 3, 24) {
+        return;
+    }
+
+    assert_eq!(Ok(1), query.execute(&connection));
+    let data = users.select(name).order(id).load::<String>(&connection);
+    assert_eq!(Ok(vec!["Jim".to_string(), "Tess".to_string()]), data);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn upsert_vec_of_records_inserts_one_row_at_a_time_on_sqlite() {
+    use diesel::query_builder::upsert::excluded;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let inserted_rows = insert_into(users)
+        .values(&vec![User::new(1, "Jim"), User::new(3, "Ruby")])
+        .on_conflict_do_nothing()
+        .execute(&connection);
+    assert_eq!(Ok(1), inserted_rows);
+
+    let data = users.select(name).order(id).load::<String>(&connection);
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string(), "Ruby".to_string()];
+    assert_eq!(Ok(expected_data), data);
+
+    // `ON CONFLICT` with a target was added in SQLite 3.24
+    if !database_version_is_at_least(&connection, 3, 24) {
+        return;
+    }
+
+    let upserted_rows = insert_into(users)
+        .values(&vec![User::new(2, "Jim"), User::new(4, "Pascal")])
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq(excluded(name)))
+        .execute(&connection);
+    assert_eq!(Ok(2), upserted_rows);
+
+    let data = users.select(name).order(id).load::<String>(&connection);
+    let expected_data = vec![
+        "Sean".to_string(),
+        "Jim".to_string(),
+        "Ruby".to_string(),
+        "Pascal".to_string(),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn upsert_do_nothing_uses_insert_ignore_on_mysql() {
+    use diesel::mysql::Mysql;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::new(1, "Jim"))
+        .on_conflict_do_nothing();
+    let expected_sql = "INSERT IGNORE INTO `users` (`id`, `name`, `hair_color`) \
+                        VALUES (?, ?, DEFAULT) -- binds: [1, \"Jim\"]";
+    assert_eq!(expected_sql, debug_query::<Mysql, _>(&query).to_string());
+    assert_eq!(Ok(0), query.execute(&connection));
+
+    let data = users.select(name).order(id).load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), data);
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn upsert_do_nothing_with_conflict_target_uses_insert_ignore_on_mysql() {
+    use diesel::mysql::Mysql;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::new(1, "Jim"))
+        .on_conflict(id)
+        .do_nothing();
+    let expected_sql = "INSERT IGNORE INTO `users` (`id`, `name`, `hair_color`) \
+                        VALUES (?, ?, DEFAULT) -- binds: [1, \"Jim\"]";
+    assert_eq!(expected_sql, debug_query::<Mysql, _>(&query).to_string());
+    assert_eq!(Ok(0), query.execute(&connection));
+
+    let data = users.select(name).order(id).load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), data);
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn upsert_do_update_uses_on_duplicate_key_update_on_mysql() {
+    use diesel::mysql::Mysql;
+    use diesel::query_builder::upsert::{excluded, values};
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = insert_into(users)
+        .values(&User::with_hair_color(1, "Jim", "black"))
+        .on_conflict(id)
+        .do_update()
+        .set((name.eq(excluded(name)), hair_color.eq(values(hair_color))));
+    let expected_sql = "INSERT INTO `users` (`id`, `name`, `hair_color`) VALUES (?, ?, ?) \
+                        ON DUPLICATE KEY UPDATE `name` = VALUES(`name`), \
+                        `hair_color` = VALUES(`hair_color`) \
+                        -- binds: [1, \"Jim\", \"black\"]";
+    assert_eq!(expected_sql, debug_query::<Mysql, _>(&query).to_string());
+    // MySQL reports 2 affected rows when the existing row was updated
+    assert_eq!(Ok(2), query.execute(&connection));
+
+    let inserted_rows = insert_into(users)
+        .values(&User::new(3, "Ruby"))
+        .on_conflict(id)
+        .do_update()
+        .set(name.eq(excluded(name)))
+        .execute(&connection);
+    assert_eq!(Ok(1), inserted_rows);
+
+    let data = users.select((name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        ("Jim".to_string(), Some("black".to_string())),
+        ("Tess".to_string(), None),
+        ("Ruby".to_string(), None),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[cfg(feature = "postgres")]
+table! {
+    upsert_counters (name) {
+        name -> Text,
+        hits -> Integer,
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_increment_does_not_lose_concurrent_increments() {
+    use diesel::pg::upsert::upsert_increment;
+    use self::upsert_counters::dsl::*;
+    use std::thread;
+
+    let connection = connection_without_transaction();
+    connection
+        .batch_execute(
+            "DROP TABLE IF EXISTS upsert_counters;
+             CREATE TABLE upsert_counters (name TEXT PRIMARY KEY, hits INTEGER NOT NULL);",
+        )
+        .unwrap();
+
+    let first_hit = upsert_increment(upsert_counters, name.eq("home"), hits, 5)
+        .get_result(&connection);
+    assert_eq!(Ok(5), first_hit);
+
+    let threads = (0..4)
+        .map(|_| {
+            thread::spawn(|| {
+                let connection = connection_without_transaction();
+                for _ in 0..25 {
+                    upsert_increment(upsert_counters, name.eq("home"), hits, 1)
+                        .execute(&connection)
+                        .unwrap();
+                    upsert_increment(upsert_counters, name.eq("about"), hits, 2)
+                        .execute(&connection)
+                        .unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in threads {
+        handle.join().unwrap();
+    }
+
+    let counts = upsert_counters
+        .order(name)
+        .load::<(String, i32)>(&connection);
+    connection.execute("DROP TABLE upsert_counters").unwrap();
+
+    let expected_counts = vec![("about".to_string(), 200), ("home".to_string(), 105)];
+    assert_eq!(Ok(expected_counts), counts);
+}
+
 #[test]
 fn update_with_custom_pk() {
     #[derive(AsChangeset)]
@@ -434,3 +1122,128 @@ fn update_with_custom_composite_pk() {
     let expected_user = User::new(1, "Jim");
     assert_eq!(Ok(expected_user), user);
 }
+
+#[test]
+fn update_with_require_affected_errors_when_no_rows_match() {
+    use diesel::result::Error::UnexpectedRowCount;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let updated = update(users.filter(id.eq(1)).filter(name.eq("Sean")))
+        .set(name.eq("Jim"))
+        .require_affected(1)
+        .execute(&connection);
+    assert_eq!(Ok(1), updated);
+
+    let stale_update = update(users.filter(id.eq(1)).filter(name.eq("Sean")))
+        .set(name.eq("Ruby"))
+        .require_affected(1)
+        .execute(&connection);
+    assert_eq!(
+        Err(UnexpectedRowCount {
+            expected: 1,
+            actual: 0,
+        }),
+        stale_update
+    );
+    assert_eq!(Ok("Jim".to_string()), users.find(1).select(name).first(&connection));
+}
+
+#[test]
+fn update_with_version_detects_conflicting_concurrent_update() {
+    use diesel::result::Error::UnexpectedRowCount;
+    use schema::versioned_posts::dsl::*;
+
+    let connection = connection();
+    insert_into(versioned_posts)
+        .values((id.eq(1), title.eq("Draft")))
+        .execute(&connection)
+        .unwrap();
+
+    // Two writers both read the post before either of them saves it
+    let first_writer_version = versioned_posts
+        .find(1)
+        .select(version)
+        .first::<i32>(&connection)
+        .unwrap();
+    let second_writer_version = versioned_posts
+        .find(1)
+        .select(version)
+        .first::<i32>(&connection)
+        .unwrap();
+
+    let first_save = update(versioned_posts.find(1))
+        .set(title.eq("First edit"))
+        .with_version(version, first_writer_version)
+        .execute(&connection);
+    assert_eq!(Ok(1), first_save);
+
+    let second_save = update(versioned_posts.find(1))
+        .set(title.eq("Second edit"))
+        .with_version(version, second_writer_version)
+        .execute(&connection);
+    assert_eq!(
+        Err(UnexpectedRowCount {
+            expected: 1,
+            actual: 0,
+        }),
+        second_save
+    );
+
+    let post = versioned_posts.find(1).select((title, version)).first(&connection);
+    assert_eq!(Ok(("First edit".to_string(), 2)), post);
+}
+
+#[test]
+fn set_versioned_with_derived_changeset_detects_conflicting_concurrent_update() {
+    use diesel::result::Error::UnexpectedRowCount;
+
+    #[derive(Debug, Clone, PartialEq, Queryable, AsChangeset)]
+    #[table_name = "versioned_posts"]
+    struct VersionedPost {
+        id: i32,
+        title: String,
+        #[diesel(version)]
+        version: i32,
+    }
+
+    let connection = connection();
+    insert_into(versioned_posts::table)
+        .values((versioned_posts::id.eq(1), versioned_posts::title.eq("Draft")))
+        .execute(&connection)
+        .unwrap();
+
+    // Two writers both load the post before either of them saves it
+    let mut first_writer_post = versioned_posts::table
+        .find(1)
+        .first::<VersionedPost>(&connection)
+        .unwrap();
+    let mut second_writer_post = first_writer_post.clone();
+
+    first_writer_post.title = "First edit".into();
+    let first_save = update(versioned_posts::table.find(1))
+        .set_versioned(&first_writer_post)
+        .execute(&connection);
+    assert_eq!(Ok(1), first_save);
+
+    second_writer_post.title = "Second edit".into();
+    let second_save = update(versioned_posts::table.find(1))
+        .set_versioned(&second_writer_post)
+        .execute(&connection);
+    assert_eq!(
+        Err(UnexpectedRowCount {
+            expected: 1,
+            actual: 0,
+        }),
+        second_save
+    );
+
+    let post = versioned_posts::table.find(1).first(&connection);
+    let expected_post = VersionedPost {
+        id: 1,
+        title: "First edit".into(),
+        version: 2,
+    };
+    assert_eq!(Ok(expected_post), post);
+}
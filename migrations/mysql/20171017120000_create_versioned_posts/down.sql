@@ -0,0 +1 @@
+DROP TABLE versioned_posts;
@@ -0,0 +1,5 @@
+CREATE TABLE versioned_posts (
+  id INTEGER PRIMARY KEY AUTO_INCREMENT,
+  title TEXT NOT NULL,
+  version INTEGER NOT NULL DEFAULT 1
+) CHARACTER SET utf8mb4;
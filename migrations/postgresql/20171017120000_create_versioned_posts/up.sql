@@ -0,0 +1,5 @@
+CREATE TABLE versioned_posts (
+  id SERIAL PRIMARY KEY,
+  title VARCHAR NOT NULL,
+  version INTEGER NOT NULL DEFAULT 1
+);
@@ -0,0 +1,5 @@
+CREATE TABLE versioned_posts (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  title TEXT NOT NULL,
+  version INTEGER NOT NULL DEFAULT 1
+);